@@ -7,7 +7,7 @@
 // 边界:
 //   - ✅ 命令行参数定义和解析
 //   - ✅ 调用核心分析器执行分析
-//   - ✅ 结果格式化输出（表格/JSON）
+//   - ✅ 结果格式化输出（表格/JSON/依赖图格式）
 //   - ✅ 用户交互和提示信息
 //   - ❌ 不应包含依赖分析算法逻辑
 //   - ❌ 不应包含配置文件加载逻辑
@@ -19,9 +19,12 @@
 use anyhow::Result;
 use clap::Args;
 use serde_json;
+use std::path::PathBuf;
 
 use crate::core::DependencyAnalyzer;
 use crate::models::config::Config;
+use crate::models::package::{DependencyType, WorkspacePackage};
+use crate::ui::graph_export::{DependencyGraphView, GraphFormat};
 use crate::utils::constants::icons;
 use crate::utils::logger::Logger;
 use crate::{t, tf};
@@ -29,7 +32,7 @@ use crate::{t, tf};
 /// 分析工作区依赖关系
 #[derive(Debug, Args)]
 pub struct AnalyzeArgs {
-    /// 输出格式 (table, json)
+    /// 输出格式 (table, json, dot, mermaid, graphml, cytoscape-json)
     #[arg(short = 'f', long, default_value = "table")]
     pub format: String,
 
@@ -44,11 +47,13 @@ pub struct AnalyzeArgs {
     /// 分析指定的多个包（逗号分隔）
     #[arg(long, value_delimiter = ',')]
     pub packages: Option<Vec<String>>,
+
+    /// 允许循环依赖：同一循环中的包放在同一构建阶段
+    #[arg(long)]
+    pub allow_cycles: bool,
 }
 
 pub fn handle_analyze(args: AnalyzeArgs) -> Result<()> {
-    Logger::info(t!("cli.analyze.start"));
-
     // 获取工作区根目录（从全局配置中获取）
     let workspace_root = Config::get_workspace_root();
 
@@ -59,8 +64,24 @@ pub fn handle_analyze(args: AnalyzeArgs) -> Result<()> {
         anyhow::bail!(tf!("error.workspace_not_exist", workspace_root.display()));
     }
 
+    // 开始提示：JSON 输出时省略，依赖图格式写到标准错误，保证标准输出只有图内容
+    let graph_format = GraphFormat::parse(&args.format);
+    if graph_format.is_some() {
+        eprintln!("{} {}", Logger::get_prefix("INFO"), t!("cli.analyze.start"));
+    } else if args.format != "json" {
+        Logger::info(t!("cli.analyze.start"));
+    }
+
+    if let Some(format) = graph_format {
+        let scope: Vec<String> =
+            args.packages.unwrap_or_default().into_iter().chain(args.package).collect();
+        return export_graph(workspace_root, format, &scope);
+    }
+
     // 创建分析器并执行分析
-    let mut analyzer = DependencyAnalyzer::new(workspace_root).with_verbose(verbose);
+    let mut analyzer = DependencyAnalyzer::new(workspace_root)
+        .with_verbose(verbose)
+        .with_allow_cycles(args.allow_cycles);
 
     let result = if let Some(packages) = args.packages {
         // 多包分析
@@ -99,6 +120,17 @@ pub fn handle_analyze(args: AnalyzeArgs) -> Result<()> {
     Ok(())
 }
 
+/// 导出依赖图：基于全工作区分析结果按包范围裁剪，标准输出只包含图内容（graph 命令共用）
+pub fn export_graph(workspace_root: PathBuf, format: GraphFormat, scope: &[String]) -> Result<()> {
+    let mut analyzer = DependencyAnalyzer::new(workspace_root);
+    let result = analyzer.analyze_workspace()?;
+    let scope: Vec<String> =
+        scope.iter().map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect();
+    let graph = DependencyGraphView::from_analysis(&result, &scope)?;
+    print!("{}", graph.render(format));
+    Ok(())
+}
+
 fn print_table_format(
     result: &crate::models::DependencyAnalysisResult,
     verbose: bool,
@@ -131,12 +163,32 @@ fn print_table_format(
     if !result.circular_dependencies.is_empty() {
         Logger::info(format!("\n{} {}", icons::ERROR, t!("output.circular_dependencies")));
         Logger::info("───────────────────────────────────────");
-        for (i, cycle) in result.circular_dependencies.iter().enumerate() {
-            Logger::info(format!("{}. {}", i + 1, cycle.join(" → ")));
+        for (i, circular) in result.cycle_details.iter().enumerate() {
+            Logger::info(format!("{}. {}", i + 1, circular.packages.join(", ")));
+            for cycle in &circular.cycles {
+                Logger::info(format!("   {}", cycle.path()));
+            }
+            let break_edges: Vec<String> = circular
+                .break_edges
+                .iter()
+                .map(|edge| format!("{} → {} ({})", edge.from, edge.to, edge.type_label()))
+                .collect();
+            Logger::info(format!("   {}", tf!("output.break_edges", break_edges.join(", "))));
         }
     } else {
         Logger::info(format!("\n{} {}", icons::SUCCESS, t!("output.no_circular_dependencies")));
     }
+    if !result.ignored_circular_dependencies.is_empty() {
+        Logger::info(format!(
+            "\n{} {}",
+            icons::WARNING,
+            t!("output.ignored_circular_dependencies")
+        ));
+        Logger::info("───────────────────────────────────────");
+        for (i, cycle) in result.ignored_circular_dependencies.iter().enumerate() {
+            Logger::info(format!("{}. {}", i + 1, cycle.join(" → ")));
+        }
+    }
 
     // 构建阶段
     if !result.stages.is_empty() {
@@ -162,8 +214,15 @@ fn print_table_format(
                             package.name,
                             tf!("output.depends_on_count", package.workspace_dependencies.len())
                         ));
-                        for dep in &package.workspace_dependencies {
-                            Logger::info(format!("    {} {}", icons::DEPENDENCY, dep));
+                        let mut deps: Vec<&String> =
+                            package.workspace_dependencies.iter().collect();
+                        deps.sort();
+                        for dep in deps {
+                            Logger::info(format!(
+                                "    {} {}",
+                                icons::DEPENDENCY,
+                                dependency_label(package, dep)
+                            ));
                         }
                     }
                 } else {
@@ -219,3 +278,19 @@ fn print_table_format(
 
     Logger::info(format!("{} {}", icons::INFO, t!("output.usage_tip")));
 }
+
+/// 依赖的显示标签：非生产依赖时标注依赖类型，不决定构建顺序时再加说明
+fn dependency_label(package: &WorkspacePackage, dep: &str) -> String {
+    let types = package.dependency_types_of(dep);
+    let orders_build = package.build_dependencies.contains(dep);
+    if orders_build && types.iter().all(|dep_type| *dep_type == DependencyType::Prod) {
+        return dep.to_string();
+    }
+
+    let types: Vec<&str> = types.iter().map(|dep_type| dep_type.as_str()).collect();
+    if orders_build {
+        format!("{} ({})", dep, types.join(", "))
+    } else {
+        format!("{} ({}, {})", dep, types.join(", "), t!("output.not_build_order"))
+    }
+}
@@ -0,0 +1,166 @@
+// ============================================================================
+// MonoX - CLI Change 命令
+// ============================================================================
+//
+// 文件: src/cli/change.rs
+// 职责: 变更意图文件命令的 CLI 接口层
+// 边界:
+//   - ✅ 命令行参数定义和解析
+//   - ✅ 缺少参数时交互式选择包、升级类型和输入摘要
+//   - ✅ 待发布变更的列表显示
+//   - ❌ 不应包含变更文件格式的解析和生成
+//   - ❌ 不应包含版本计算逻辑
+//
+// ============================================================================
+
+use anyhow::Result;
+use clap::{Args, Subcommand};
+use std::io::{self, Write};
+
+use crate::core::changes::{ChangeRelease, ChangeStore, ChangeType};
+use crate::core::versioner::Versioner;
+use crate::models::config::Config;
+use crate::ui::picker;
+use crate::utils::colors::Colors;
+use crate::utils::logger::Logger;
+use crate::{t, tf};
+
+/// 管理待发布的变更
+#[derive(Debug, Args)]
+pub struct ChangeArgs {
+    #[command(subcommand)]
+    pub command: ChangeCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ChangeCommand {
+    /// Record a change for one or more packages
+    Add(ChangeAddArgs),
+    /// List pending changes
+    List(ChangeListArgs),
+}
+
+/// 记录一个变更
+#[derive(Debug, Args)]
+pub struct ChangeAddArgs {
+    /// 变更的包（支持通配符，逗号分隔），省略时交互式选择
+    #[arg(short = 'p', long, value_delimiter = ',')]
+    pub package: Vec<String>,
+
+    /// 升级类型 (patch, minor, major)，省略时交互式输入
+    #[arg(short = 'b', long)]
+    pub bump: Option<String>,
+
+    /// 变更摘要，省略时交互式输入
+    #[arg(short = 'm', long)]
+    pub message: Option<String>,
+}
+
+/// 列出待发布的变更
+#[derive(Debug, Args)]
+pub struct ChangeListArgs {
+    /// 输出格式 (table, json)
+    #[arg(short = 'f', long, default_value = "table")]
+    pub format: String,
+}
+
+pub fn handle_change(args: ChangeArgs) -> Result<()> {
+    let workspace_root = Config::get_workspace_root();
+    if !workspace_root.exists() {
+        anyhow::bail!(tf!("error.workspace_not_exist", workspace_root.display()));
+    }
+
+    let store = ChangeStore::new(&workspace_root);
+    match args.command {
+        ChangeCommand::Add(args) => {
+            let versioner = Versioner::new(workspace_root, Config::get_version_config())?;
+            add_change(args, &store, &versioner)
+        }
+        ChangeCommand::List(args) => list_changes(args, &store),
+    }
+}
+
+/// 记录变更文件
+fn add_change(args: ChangeAddArgs, store: &ChangeStore, versioner: &Versioner) -> Result<()> {
+    // 选择包
+    let packages = if args.package.is_empty() {
+        let names = versioner.select(&[])?;
+        let selected = picker::multi_select(&t!("change.select_packages"), &names)?;
+        selected.into_iter().map(|index| names[index].clone()).collect()
+    } else {
+        versioner.select(&args.package)?
+    };
+    if packages.is_empty() {
+        Logger::info(t!("change.cancelled"));
+        return Ok(());
+    }
+
+    // 升级类型
+    let bump = match args.bump {
+        Some(bump) => bump,
+        None => prompt(&t!("change.bump_prompt"))?,
+    };
+    let bump = if bump.is_empty() { "patch".to_string() } else { bump };
+    let Some(bump) = ChangeType::parse(&bump) else {
+        anyhow::bail!(tf!("change.invalid_bump", bump));
+    };
+
+    // 变更摘要
+    let summary = match args.message {
+        Some(message) => message,
+        None => prompt(&t!("change.summary_prompt"))?,
+    };
+    if summary.trim().is_empty() {
+        anyhow::bail!(t!("change.summary_required"));
+    }
+
+    let releases: Vec<ChangeRelease> =
+        packages.into_iter().map(|package| ChangeRelease { package, bump }).collect();
+    let path = store.add(&releases, &summary)?;
+
+    let names: Vec<&str> = releases.iter().map(|release| release.package.as_str()).collect();
+    Logger::success(tf!("change.added", path.display(), bump.as_str(), names.join(", ")));
+    Ok(())
+}
+
+/// 列出待发布的变更文件
+fn list_changes(args: ChangeListArgs, store: &ChangeStore) -> Result<()> {
+    let changes = store.load_all()?;
+
+    if args.format == "json" {
+        println!("{}", serde_json::to_string_pretty(&changes)?);
+        return Ok(());
+    }
+
+    if changes.is_empty() {
+        Logger::info(t!("change.none"));
+        return Ok(());
+    }
+
+    for change in &changes {
+        Logger::info(Colors::info(&change.id));
+        for release in &change.releases {
+            Logger::info(format!("  {}: {}", release.package, release.bump.as_str()));
+        }
+        for line in change.summary.lines() {
+            Logger::info(format!("    {}", line));
+        }
+    }
+    Logger::info("");
+    Logger::info(tf!("change.total", changes.len()));
+    Ok(())
+}
+
+/// 读取一行输入（非交互终端时报错）
+fn prompt(message: &str) -> Result<String> {
+    if !atty::is(atty::Stream::Stdin) {
+        anyhow::bail!(t!("picker.requires_tty"));
+    }
+
+    print!("{} ", message);
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    Ok(input.trim().to_string())
+}
@@ -21,8 +21,11 @@ use anyhow::Result;
 use clap::Args;
 use std::sync::{Arc, Mutex};
 
-use crate::core::checker::{HealthChecker, OutdatedDependency, ProgressCallback};
+use crate::core::checker::{
+    ConflictKind, HealthChecker, OutdatedDependency, ProgressCallback, UpdateLevel,
+};
 use crate::models::config::Config;
+use crate::models::deps_usage::DependencyUsageKind;
 use crate::ui::spinner::Spinner;
 use crate::ui::summary;
 use crate::utils::logger::Logger;
@@ -43,6 +46,34 @@ pub struct CheckArgs {
     #[arg(long)]
     pub outdated: bool,
 
+    /// 检查安装了多个版本的依赖（基于锁文件）
+    #[arg(long)]
+    pub duplicates: bool,
+
+    /// 检查工作区内部依赖的 workspace: 协议和版本范围
+    #[arg(long)]
+    pub workspace_protocol: bool,
+
+    /// 按 monox.toml 中 [boundaries] 的规则检查包之间的依赖
+    #[arg(long)]
+    pub boundaries: bool,
+
+    /// 扫描源码导入，检查未声明的依赖和未使用的依赖
+    #[arg(long)]
+    pub deps_usage: bool,
+
+    /// 按 monox.toml 中 [manifest] 的规则检查各包 package.json 的规范
+    #[arg(long)]
+    pub manifest: bool,
+
+    /// 过期依赖的最低显示级别 (patch, minor, major, prerelease)，默认显示全部
+    #[arg(long, value_parser = ["patch", "minor", "major", "prerelease"])]
+    pub level: Option<String>,
+
+    /// 离线模式，仅使用本地缓存的 registry 元数据
+    #[arg(long)]
+    pub offline: bool,
+
     /// 输出格式 (table, json)
     #[arg(short = 'f', long, default_value = "table")]
     pub format: String,
@@ -63,7 +94,8 @@ pub async fn handle_check(args: CheckArgs) -> Result<()> {
     }
 
     // 创建健康检查器
-    let checker = HealthChecker::new(workspace_root.clone()).with_verbose(verbose);
+    let checker =
+        HealthChecker::new(workspace_root.clone()).with_verbose(verbose).with_offline(args.offline);
 
     // 确定检查项目
     let check_items = determine_check_items(&args);
@@ -79,6 +111,21 @@ pub async fn handle_check(args: CheckArgs) -> Result<()> {
     if check_items.outdated {
         has_issues |= check_outdated_dependencies(&checker, verbose, &args).await?;
     }
+    if check_items.duplicates {
+        has_issues |= check_duplicates(&checker, verbose, &args)?;
+    }
+    if check_items.workspace_protocol {
+        has_issues |= check_workspace_protocol(&checker, verbose, &args)?;
+    }
+    if check_items.boundaries {
+        has_issues |= check_boundaries(&checker, verbose, &args)?;
+    }
+    if check_items.deps_usage {
+        has_issues |= check_deps_usage(&checker, verbose, &args)?;
+    }
+    if check_items.manifest {
+        has_issues |= check_manifest(&checker, verbose, &args)?;
+    }
 
     // 输出结果
     if has_issues {
@@ -95,14 +142,31 @@ struct CheckItems {
     circular: bool,
     versions: bool,
     outdated: bool,
+    duplicates: bool,
+    workspace_protocol: bool,
+    boundaries: bool,
+    deps_usage: bool,
+    manifest: bool,
 }
 
 /// 确定要执行的检查项目
 fn determine_check_items(args: &CheckArgs) -> CheckItems {
     CheckItems {
-        circular: args.circular || (!args.versions && !args.outdated),
+        circular: args.circular
+            || (!args.versions
+                && !args.outdated
+                && !args.duplicates
+                && !args.workspace_protocol
+                && !args.boundaries
+                && !args.deps_usage
+                && !args.manifest),
         versions: args.versions,
         outdated: args.outdated,
+        duplicates: args.duplicates,
+        workspace_protocol: args.workspace_protocol,
+        boundaries: args.boundaries,
+        deps_usage: args.deps_usage,
+        manifest: args.manifest,
     }
 }
 
@@ -116,7 +180,15 @@ fn check_circular_dependencies(
         Logger::info(t!("check.circular.start"));
     }
 
-    let circular_dependencies = checker.check_circular_dependencies()?;
+    let result = checker.check_circular_dependencies()?;
+    let circular_dependencies = result.cycle_details;
+    let ignored = result.ignored_circular_dependencies;
+
+    // 只经过不决定构建顺序的依赖类型的循环不影响构建，单独提示
+    if !ignored.is_empty() && args.format != "json" {
+        let cycles: Vec<String> = ignored.iter().map(|cycle| cycle.join(" → ")).collect();
+        Logger::warn(tf!("check.circular.ignored", ignored.len(), cycles.join("; ")));
+    }
 
     if circular_dependencies.is_empty() {
         Logger::success(t!("check.circular.none_found"));
@@ -165,7 +237,12 @@ fn check_version_conflicts(
                     dep_type: usage.dep_type,
                 })
                 .collect(),
+            kind: match c.kind {
+                ConflictKind::Incompatible => "incompatible".to_string(),
+                ConflictKind::Compatible => "compatible".to_string(),
+            },
             recommended_version: c.recommended_version,
+            satisfied_usages: c.satisfied_usages,
         })
         .collect();
 
@@ -185,6 +262,9 @@ async fn check_outdated_dependencies(
     if verbose {
         Logger::info(t!("check.outdated.start"));
     }
+    if args.offline || Config::get_registry_config().offline {
+        Logger::info(t!("check.outdated.offline"));
+    }
 
     // 创建进度显示和回调
     let spinner = if !verbose {
@@ -217,8 +297,7 @@ async fn check_outdated_dependencies(
     };
 
     // 执行检查
-    let (result, total_checked) =
-        checker.check_outdated_dependencies_with_progress(progress_callback).await?;
+    let outcome = checker.check_outdated_dependencies_with_progress(progress_callback).await;
 
     // 停止进度显示
     if let Some(spinner_arc) = spinner {
@@ -226,11 +305,35 @@ async fn check_outdated_dependencies(
             s.stop();
         }
     }
+    let outcome = outcome?;
+    let total_checked = outcome.total_checked;
+    let result = outcome.outdated;
+
+    // 查询失败的依赖无法判断是否过期，单独报告并视为检查失败
+    let lookup_failed = !outcome.failures.is_empty();
+    if lookup_failed {
+        Logger::error(tf!("check.outdated.lookup_failed", outcome.failures.len()));
+        for (name, error) in &outcome.failures {
+            let error = error.clone().unwrap_or_else(|| t!("check.outdated.lookup_timeout"));
+            Logger::error(tf!("check.outdated.lookup_failed_item", name, error));
+        }
+    }
+
+    // 默认列出全部过期依赖（包括范围内更新），指定 --level 时隐藏更低级别的更新
+    let min_level =
+        args.level.as_deref().and_then(UpdateLevel::parse).unwrap_or(UpdateLevel::InRange);
+    let below_level = result.iter().filter(|dep| dep.level < min_level).count();
+    let result: Vec<OutdatedDependency> =
+        result.into_iter().filter(|dep| dep.level >= min_level).collect();
+
+    if below_level > 0 {
+        Logger::info(tf!("check.outdated.below_level", below_level, min_level.as_str()));
+    }
 
     if result.is_empty() {
         // 即使没有过期依赖，也要显示统计信息
         log_outdated_found_message_with_total(total_checked, 0, 0);
-        return Ok(false);
+        return Ok(lookup_failed);
     }
 
     let unique_outdated_count = get_unique_outdated_count(&result);
@@ -240,8 +343,12 @@ async fn check_outdated_dependencies(
         .into_iter()
         .map(|dep| summary::OutdatedDependency {
             name: dep.name,
+            spec: dep.spec,
             current: dep.current,
+            wanted: dep.wanted,
             latest: dep.latest,
+            dist_tag: dep.dist_tag,
+            level: dep.level.as_str().to_string(),
             package: dep.package,
             dep_type: dep.dep_type,
         })
@@ -261,6 +368,168 @@ async fn check_outdated_dependencies(
     Ok(true)
 }
 
+/// 检查重复安装的依赖
+fn check_duplicates(checker: &HealthChecker, verbose: bool, args: &CheckArgs) -> Result<bool> {
+    if verbose {
+        Logger::info(t!("check.duplicates.start"));
+    }
+
+    let Some(report) = checker.check_duplicates()? else {
+        Logger::warn(t!("check.duplicates.no_lockfile"));
+        return Ok(false);
+    };
+
+    if report.duplicates.is_empty() {
+        Logger::success(tf!("check.duplicates.none_found", report.lockfile, report.total_packages));
+        return Ok(false);
+    }
+
+    Logger::error(tf!("check.duplicates.found", report.duplicates.len(), report.lockfile));
+
+    output_results(&args.format, &report, args.detail, |report, detail| {
+        summary::print_duplicates_table(report, detail)
+    })?;
+
+    Ok(true)
+}
+
+/// 检查工作区内部依赖写法
+fn check_workspace_protocol(
+    checker: &HealthChecker,
+    verbose: bool,
+    args: &CheckArgs,
+) -> Result<bool> {
+    if verbose {
+        Logger::info(t!("check.protocol.start"));
+    }
+
+    let issues = checker.check_workspace_protocol()?;
+    if issues.is_empty() {
+        Logger::success(t!("check.protocol.none_found"));
+        return Ok(false);
+    }
+
+    Logger::error(tf!("check.protocol.found", issues.len()));
+
+    // 转换为 summary 模块的类型
+    let summary_issues: Vec<summary::ProtocolIssue> = issues
+        .into_iter()
+        .map(|issue| summary::ProtocolIssue {
+            package: issue.package,
+            dependency: issue.dependency,
+            dep_type: issue.dep_type,
+            spec: issue.spec,
+            local_version: issue.local_version,
+            kind: issue.kind.as_str().to_string(),
+            suggested: issue.suggested,
+        })
+        .collect();
+
+    output_results(&args.format, &summary_issues, args.detail, |issues, detail| {
+        summary::print_protocol_issues_table(issues, detail)
+    })?;
+
+    Ok(true)
+}
+
+/// 检查架构边界规则
+fn check_boundaries(checker: &HealthChecker, verbose: bool, args: &CheckArgs) -> Result<bool> {
+    if verbose {
+        Logger::info(t!("check.boundaries.start"));
+    }
+
+    let report = checker.check_boundaries()?;
+    if report.rules == 0 {
+        Logger::warn(t!("check.boundaries.no_rules"));
+        return Ok(false);
+    }
+
+    // 引用了不存在的标签的规则不会生效，通常是拼写错误
+    if !report.unknown_tags.is_empty() && args.format != "json" {
+        Logger::warn(tf!("check.boundaries.unknown_tags", report.unknown_tags.join(", ")));
+    }
+
+    if report.violations.is_empty() {
+        Logger::success(tf!(
+            "check.boundaries.none_found",
+            report.dependencies_checked,
+            report.rules
+        ));
+        return Ok(false);
+    }
+
+    Logger::error(tf!("check.boundaries.found", report.violations.len()));
+
+    output_results(&args.format, &report, args.detail, |report, detail| {
+        summary::print_boundary_violations_table(&report.violations, detail)
+    })?;
+
+    Ok(true)
+}
+
+/// 检查未声明的依赖和未使用的依赖
+fn check_deps_usage(checker: &HealthChecker, verbose: bool, args: &CheckArgs) -> Result<bool> {
+    if verbose {
+        Logger::info(t!("check.deps_usage.start"));
+    }
+
+    let report = checker.check_deps_usage()?;
+    if report.issues.is_empty() {
+        Logger::success(tf!(
+            "check.deps_usage.none_found",
+            report.files_scanned,
+            report.packages_scanned
+        ));
+        return Ok(false);
+    }
+
+    let undeclared =
+        report.issues.iter().filter(|issue| issue.kind == DependencyUsageKind::Undeclared).count();
+    Logger::error(tf!("check.deps_usage.found", undeclared, report.issues.len() - undeclared));
+
+    output_results(&args.format, &report, args.detail, |report, detail| {
+        summary::print_deps_usage_table(&report.issues, detail)
+    })?;
+
+    Ok(true)
+}
+
+/// 检查 package.json 规范（只有 error 级别的问题会导致检查失败）
+fn check_manifest(checker: &HealthChecker, verbose: bool, args: &CheckArgs) -> Result<bool> {
+    if verbose {
+        Logger::info(t!("check.manifest.start"));
+    }
+
+    let report = checker.check_manifest()?;
+
+    // 配置了不存在的规则不会生效，通常是拼写错误
+    if !report.unknown_rules.is_empty() && args.format != "json" {
+        Logger::warn(tf!("check.manifest.unknown_rules", report.unknown_rules.join(", ")));
+    }
+
+    if report.issues.is_empty() {
+        Logger::success(tf!("check.manifest.none_found", report.packages_checked));
+        return Ok(false);
+    }
+
+    if report.errors > 0 {
+        Logger::error(tf!("check.manifest.found", report.errors, report.warnings));
+    } else {
+        Logger::warn(tf!("check.manifest.found", report.errors, report.warnings));
+    }
+
+    output_results(&args.format, &report, args.detail, |report, detail| {
+        summary::print_manifest_issues_table(&report.issues, detail)
+    })?;
+
+    let fixable = report.issues.iter().filter(|issue| issue.fix.is_some()).count();
+    if fixable > 0 && args.format != "json" {
+        Logger::info(tf!("check.manifest.fixable", fixable));
+    }
+
+    Ok(report.errors > 0)
+}
+
 /// 通用结果输出函数
 fn output_results<T, F>(format: &str, data: &T, detail: bool, print_table: F) -> Result<()>
 where
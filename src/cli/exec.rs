@@ -18,7 +18,9 @@
 
 use anyhow::Result;
 use clap::Args;
+use std::path::PathBuf;
 
+use crate::core::report::parse_report_targets;
 use crate::core::TaskExecutor;
 use crate::models::config::Config;
 use crate::utils::logger::Logger;
@@ -30,24 +32,57 @@ pub struct ExecArgs {
     /// 要执行的任务名称（在 monox.toml 中定义）
     #[arg(short = 't', long)]
     pub task: String,
+
+    /// 导出 Chrome Trace 格式的性能剖析文件 (如: out.json)
+    #[arg(long)]
+    pub profile: Option<PathBuf>,
+
+    /// 导出运行报告，可重复指定 (如: json=report.json, junit=junit.xml)
+    #[arg(long, value_name = "FORMAT=PATH")]
+    pub report: Vec<String>,
+
+    /// 试运行：只输出执行计划，不执行任何命令
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// 执行计划输出格式，配合 --dry-run 使用 (table, json)
+    #[arg(short = 'f', long, default_value = "table")]
+    pub format: String,
+
+    /// 允许循环依赖：同一循环中的包在同一阶段执行
+    #[arg(long)]
+    pub allow_cycles: bool,
 }
 
 /// 执行预定义任务
 pub async fn exec(args: ExecArgs) -> Result<()> {
-    Logger::info(tf!("exec.start", &args.task));
+    // JSON 执行计划只输出计划本身，保证输出可解析
+    let quiet = args.dry_run && args.format == "json";
+    let log = |msg: String| {
+        if !quiet {
+            Logger::info(msg)
+        }
+    };
+
+    log(tf!("exec.start", &args.task));
 
     // 从配置文件中获取任务定义
     let task_config = Config::get_task_config(&args.task)
         .map_err(|_| anyhow::anyhow!(tf!("exec.task_not_found", &args.task)))?;
 
-    Logger::info(tf!("exec.task_found", &task_config.name, &task_config.command));
+    log(tf!("exec.task_found", &task_config.name, &task_config.command));
 
     if let Some(desc) = &task_config.desc {
-        Logger::info(tf!("exec.task_description", desc));
+        log(tf!("exec.task_description", desc));
     }
 
     // 创建任务执行器
-    let executor = TaskExecutor::new_from_config()?;
+    let executor = TaskExecutor::new_from_config()?
+        .with_profile_output(args.profile)
+        .with_reports(parse_report_targets(&args.report)?)
+        .with_dry_run(args.dry_run, &args.format)
+        .with_allow_cycles(args.allow_cycles)
+        .with_task_env(task_config.env.clone());
 
     // 根据配置决定执行策略
     if let Some(packages) = &task_config.packages {
@@ -55,17 +90,17 @@ pub async fn exec(args: ExecArgs) -> Result<()> {
         if packages.is_empty() {
             anyhow::bail!(t!("exec.empty_packages_list"));
         }
-        Logger::info(tf!("exec.executing_packages", packages.join(", ")));
+        log(tf!("exec.executing_packages", packages.join(", ")));
         executor.execute_packages(packages, &task_config.command, &task_config.post_command).await
     } else if !task_config.pkg_name.is_empty() {
         // 如果有 pkg_name 且不为空，按原逻辑处理
         let is_all_packages = task_config.pkg_name == "*";
 
         if is_all_packages {
-            Logger::info(t!("exec.executing_all_packages"));
+            log(t!("exec.executing_all_packages"));
             executor.execute("*", &task_config.command, &task_config.post_command, Some(true)).await
         } else {
-            Logger::info(tf!("exec.executing_package", &task_config.pkg_name));
+            log(tf!("exec.executing_package", &task_config.pkg_name));
             executor
                 .execute(
                     &task_config.pkg_name,
@@ -7,6 +7,7 @@
 // 边界:
 //   - ✅ 命令行参数定义和解析
 //   - ✅ 调用版本冲突修复器执行修复
+//   - ✅ 应用 package.json 规范检查给出的安全修复
 //   - ✅ 修复结果格式化输出
 //   - ✅ 用户交互和确认提示
 //   - ❌ 不应包含具体修复逻辑
@@ -19,13 +20,18 @@
 use anyhow::Result;
 use clap::Args;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fs;
 use std::path::Path;
 
-use crate::core::checker::{HealthChecker, VersionConflict};
+use crate::core::checker::{HealthChecker, ProtocolIssue, VersionConflict};
+use crate::core::installer::{ManifestBackup, PostWriteRunner};
 use crate::models::config::Config;
+use crate::models::manifest::{ManifestFix, ManifestIssue};
+use crate::ui::summary;
 use crate::utils::colors::Colors;
+use crate::utils::json_edit::{self, JsonDocument};
 use crate::utils::logger::Logger;
 use crate::{t, tf};
 
@@ -62,9 +68,25 @@ pub struct FixArgs {
     /// 显示详细信息
     #[arg(short = 'd', long)]
     pub detail: bool,
+
+    /// 改写工作区内部依赖的写法（workspace:^ 或本地精确版本），而不是修复版本冲突
+    #[arg(long)]
+    pub workspace_protocol: bool,
+
+    /// 应用 package.json 规范检查（check --manifest）中可以安全自动修复的问题
+    #[arg(long, conflicts_with_all = ["workspace_protocol", "install"])]
+    pub manifest: bool,
+
+    /// 写入后运行包管理器安装，同步锁文件
+    #[arg(long)]
+    pub install: bool,
+
+    /// 安装后在受影响的包中运行的验证任务 (如: build, test)，失败时恢复原文件
+    #[arg(long, value_name = "TASK", requires = "install")]
+    pub verify: Option<String>,
 }
 
-pub fn handle_fix(args: FixArgs) -> Result<()> {
+pub async fn handle_fix(args: FixArgs) -> Result<()> {
     Logger::info(t!("cli.fix.start"));
 
     // 获取工作区根目录
@@ -78,6 +100,10 @@ pub fn handle_fix(args: FixArgs) -> Result<()> {
     // 创建健康检查器
     let checker = HealthChecker::new(workspace_root.clone()).with_verbose(verbose);
 
+    if args.manifest {
+        return fix_manifest(&checker, &workspace_root, &args, verbose);
+    }
+
     // 收集所有未被忽略的 package.json 文件
     let package_files = collect_package_files(&workspace_root, verbose)?;
 
@@ -86,18 +112,24 @@ pub fn handle_fix(args: FixArgs) -> Result<()> {
         return Ok(());
     }
 
-    // 收集版本冲突
-    let version_conflicts = checker.check_version_conflicts()?;
-
-    if version_conflicts.is_empty() {
-        Logger::success(t!("fix.no_conflicts_found"));
-        return Ok(());
-    }
-
-    Logger::info(tf!("fix.conflicts_found", version_conflicts.len()));
-
     // 计算修复方案
-    let fix_plan = calculate_fix_plan(&version_conflicts, &package_files)?;
+    let fix_plan = if args.workspace_protocol {
+        let issues = checker.check_workspace_protocol()?;
+        if issues.is_empty() {
+            Logger::success(t!("check.protocol.none_found"));
+            return Ok(());
+        }
+        Logger::info(tf!("check.protocol.found", issues.len()));
+        calculate_protocol_fix_plan(&issues)
+    } else {
+        let version_conflicts = checker.check_version_conflicts()?;
+        if version_conflicts.is_empty() {
+            Logger::success(t!("fix.no_conflicts_found"));
+            return Ok(());
+        }
+        Logger::info(tf!("fix.conflicts_found", version_conflicts.len()));
+        calculate_fix_plan(&version_conflicts, &package_files)?
+    };
 
     if fix_plan.is_empty() {
         Logger::info(t!("fix.no_fixes_needed"));
@@ -118,6 +150,16 @@ pub fn handle_fix(args: FixArgs) -> Result<()> {
         return Ok(());
     }
 
+    // 写入前备份 package.json 和锁文件，安装或验证失败时恢复
+    let post_write = PostWriteRunner::new(workspace_root.clone())
+        .with_install(args.install)
+        .with_verify(args.verify.clone());
+    let backup = if post_write.is_enabled() {
+        ManifestBackup::capture(&workspace_root, &package_files)?
+    } else {
+        ManifestBackup::default()
+    };
+
     // 执行修复
     let results = execute_fixes(&fix_plan, &package_files, verbose)?;
 
@@ -126,6 +168,11 @@ pub fn handle_fix(args: FixArgs) -> Result<()> {
 
     Logger::success(tf!("fix.completed", results.len()));
 
+    if post_write.is_enabled() && !results.is_empty() {
+        let changed: BTreeSet<String> = results.iter().map(|r| r.package.clone()).collect();
+        post_write.run(&backup, &changed.into_iter().collect::<Vec<_>>()).await?.into_result()?;
+    }
+
     Ok(())
 }
 
@@ -218,8 +265,42 @@ fn calculate_fix_plan(
     Ok(fixes)
 }
 
+/// 计算内部依赖写法的修复方案
+fn calculate_protocol_fix_plan(issues: &[ProtocolIssue]) -> Vec<FixResult> {
+    issues
+        .iter()
+        .map(|issue| FixResult {
+            package: issue.package.clone(),
+            dependency: issue.dependency.clone(),
+            old_version: issue.spec.clone(),
+            new_version: issue.suggested.clone(),
+            dep_type: issue.dep_type.clone(),
+        })
+        .collect()
+}
+
 /// 保持原有版本格式，只替换版本号
 fn preserve_version_format(original_spec: &str, new_version: &str) -> String {
+    // npm: 别名保留别名前缀，只替换范围部分
+    if let Some(aliased) = original_spec.strip_prefix("npm:") {
+        if let Some(at) = aliased.get(1..).and_then(|rest| rest.find('@')).map(|i| i + 1) {
+            let range = &aliased[at + 1..];
+            return format!(
+                "npm:{}@{}",
+                &aliased[..at],
+                preserve_version_format(range, new_version)
+            );
+        }
+    }
+
+    // 复合范围（交集、并集、连字符范围）和通配范围（1.x）无法逐段保留，统一改写为 ^ 范围
+    if original_spec.trim().contains(char::is_whitespace)
+        || original_spec.contains("||")
+        || original_spec.split('.').any(|part| matches!(part, "x" | "X" | "*"))
+    {
+        return format!("^{}", new_version);
+    }
+
     // 检测原有版本的前缀
     if original_spec.starts_with("^") {
         format!("^{}", new_version)
@@ -323,15 +404,13 @@ fn execute_fixes(
     verbose: bool,
 ) -> Result<Vec<FixResult>> {
     let mut executed_fixes = Vec::new();
-    let mut package_path_map: HashMap<String, &std::path::PathBuf> = HashMap::new();
+    let mut documents: HashMap<String, JsonDocument> = HashMap::new();
 
-    // 建立包名到文件路径的映射
+    // 建立包名到文件的映射
     for package_file in package_files {
-        let content = fs::read_to_string(package_file)?;
-        let package_json: serde_json::Value = serde_json::from_str(&content)?;
-
-        if let Some(name) = package_json["name"].as_str() {
-            package_path_map.insert(name.to_string(), package_file);
+        let document = JsonDocument::load(package_file)?;
+        if let Some(name) = document.value()?["name"].as_str() {
+            documents.insert(name.to_string(), document);
         }
     }
 
@@ -342,80 +421,42 @@ fn execute_fixes(
     }
 
     for (package_name, package_fixes) in packages {
-        if let Some(&package_file) = package_path_map.get(&package_name) {
+        if let Some(document) = documents.get_mut(&package_name) {
             if verbose {
                 Logger::info(tf!("fix.processing_package", package_name));
             }
 
-            // 读取原始文件内容
-            let mut content = fs::read_to_string(package_file)?;
-
-            // 验证文件格式
-            let package_json: serde_json::Value = serde_json::from_str(&content)?;
-
-            // 逐个应用修复
+            // 只修改对应依赖类型下的版本，保持文件格式
             for fix in &package_fixes {
-                // 检查依赖是否存在
-                if let Some(deps) = package_json[&fix.dep_type].as_object() {
-                    if deps.contains_key(&fix.dependency) {
-                        // 执行精确的文本替换
-                        if replace_dependency_version(
-                            &mut content,
-                            &fix.dep_type,
-                            &fix.dependency,
-                            &fix.old_version,
-                            &fix.new_version,
-                        )? {
-                            executed_fixes.push((*fix).clone());
-
-                            if verbose {
-                                Logger::info(tf!(
-                                    "fix.updated_dependency",
-                                    fix.dependency,
-                                    fix.old_version,
-                                    fix.new_version
-                                ));
-                            }
-                        }
+                if document.set_string(
+                    &[&fix.dep_type, &fix.dependency],
+                    &fix.old_version,
+                    &fix.new_version,
+                )? {
+                    executed_fixes.push((*fix).clone());
+
+                    if verbose {
+                        Logger::info(tf!(
+                            "fix.updated_dependency",
+                            fix.dependency,
+                            fix.old_version,
+                            fix.new_version
+                        ));
                     }
                 }
             }
 
-            // 写回文件
-            fs::write(package_file, content)?;
-
-            if verbose {
+            if verbose && document.is_modified() {
                 Logger::info(tf!("fix.updated_package", package_name));
             }
         }
     }
 
-    Ok(executed_fixes)
-}
+    // 所有文件一起写回，任一文件失败时全部保持原样
+    let documents: Vec<JsonDocument> = documents.into_values().collect();
+    json_edit::commit(&documents)?;
 
-/// 精确替换依赖版本，保持文件格式
-fn replace_dependency_version(
-    content: &mut String,
-    _dep_type: &str,
-    dependency: &str,
-    old_version: &str,
-    new_version: &str,
-) -> Result<bool> {
-    // 构建精确的搜索模式
-    // 匹配：  "dependency": "old_version"
-    let search_pattern = format!(r#""{}":\s*"{}""#, dependency, regex::escape(old_version));
-    let replacement = format!(r#""{}": "{}""#, dependency, new_version);
-
-    // 使用正则表达式进行替换
-    let regex = regex::Regex::new(&search_pattern)?;
-
-    if regex.is_match(content) {
-        let new_content = regex.replace(content, replacement.as_str());
-        *content = new_content.to_string();
-        return Ok(true);
-    }
-
-    Ok(false)
+    Ok(executed_fixes)
 }
 
 /// 显示修复结果
@@ -461,3 +502,200 @@ fn print_fix_results_table(results: &[FixResult]) -> Result<()> {
 
     Ok(())
 }
+
+/// 应用 package.json 规范检查的自动修复
+fn fix_manifest(
+    checker: &HealthChecker,
+    workspace_root: &Path,
+    args: &FixArgs,
+    verbose: bool,
+) -> Result<()> {
+    let report = checker.check_manifest()?;
+    let fixes: Vec<&ManifestIssue> =
+        report.issues.iter().filter(|issue| issue.fix.is_some()).collect();
+    let manual = report.issues.len() - fixes.len();
+
+    if fixes.is_empty() {
+        if report.issues.is_empty() {
+            Logger::success(tf!("check.manifest.none_found", report.packages_checked));
+        } else {
+            Logger::info(tf!("fix.manifest.none_fixable", manual));
+        }
+        return Ok(());
+    }
+
+    // 显示修复方案
+    match args.format.as_str() {
+        "json" => {
+            let json_output = serde_json::json!({
+                "fix_plan": fixes,
+                "count": fixes.len()
+            });
+            println!("{}", serde_json::to_string_pretty(&json_output)?);
+        }
+        _ => print_manifest_fix_plan(&fixes),
+    }
+
+    if args.dry_run {
+        Logger::info(t!("fix.dry_run_complete"));
+        return Ok(());
+    }
+
+    if !args.yes && !confirm_fix()? {
+        Logger::info(t!("fix.cancelled"));
+        return Ok(());
+    }
+
+    let applied = execute_manifest_fixes(&fixes, workspace_root, verbose)?;
+    Logger::success(tf!("fix.completed", applied));
+    if manual > 0 {
+        Logger::warn(tf!("fix.manifest.manual_remaining", manual));
+    }
+
+    Ok(())
+}
+
+/// 打印 package.json 修复方案（按包分组）
+fn print_manifest_fix_plan(fixes: &[&ManifestIssue]) {
+    Logger::info("");
+    Logger::info(t!("fix.plan_details"));
+    Logger::info("───────────────────────────────────────");
+
+    // 同名的包可能出现在多个目录中，按目录区分
+    let mut packages: BTreeMap<(&str, &str), Vec<&ManifestIssue>> = BTreeMap::new();
+    for issue in fixes {
+        packages.entry((&issue.folder, &issue.package)).or_default().push(issue);
+    }
+
+    for ((folder, package_name), package_fixes) in packages {
+        Logger::info(tf!("check.manifest.package_header", Colors::info(package_name), folder));
+        for issue in package_fixes {
+            if let Some(fix) = &issue.fix {
+                Logger::info(tf!(
+                    "fix.manifest.fix_line",
+                    issue.rule,
+                    summary::format_manifest_fix(fix)
+                ));
+            }
+        }
+        Logger::info("");
+    }
+
+    Logger::info(tf!("fix.total_fixes", fixes.len()));
+}
+
+/// 写回 package.json 修复，返回实际应用的修复数量
+fn execute_manifest_fixes(
+    fixes: &[&ManifestIssue],
+    workspace_root: &Path,
+    verbose: bool,
+) -> Result<usize> {
+    let mut documents: BTreeMap<&str, JsonDocument> = BTreeMap::new();
+    let mut applied = 0;
+
+    for issue in fixes {
+        let Some(fix) = &issue.fix else {
+            continue;
+        };
+        let document = match documents.entry(&issue.folder) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let path = workspace_root.join(&issue.folder).join("package.json");
+                entry.insert(JsonDocument::load(&path)?)
+            }
+        };
+
+        // 文件在检查后被修改时，对应的修改不会生效
+        let changed = match fix {
+            ManifestFix::Replace { key, from, to } => {
+                let key: Vec<&str> = key.iter().map(String::as_str).collect();
+                document.set_string(&key, from, to)?
+            }
+            ManifestFix::Insert { key, value } => document.insert(key, value)?,
+        };
+
+        if changed {
+            applied += 1;
+            if verbose {
+                Logger::info(tf!(
+                    "fix.manifest.applied",
+                    issue.package,
+                    summary::format_manifest_fix(fix)
+                ));
+            }
+        }
+    }
+
+    // 所有文件一起写回，任一文件失败时全部保持原样
+    let documents: Vec<JsonDocument> = documents.into_values().collect();
+    json_edit::commit(&documents)?;
+
+    Ok(applied)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::manifest::RuleSeverity;
+
+    fn issue(rule: &str, fix: Option<ManifestFix>) -> ManifestIssue {
+        ManifestIssue {
+            rule: rule.to_string(),
+            severity: RuleSeverity::Warn,
+            package: "core".to_string(),
+            folder: "packages/core".to_string(),
+            message: String::new(),
+            fix,
+        }
+    }
+
+    #[test]
+    fn manifest_fixes_apply_only_safe_and_current_changes() {
+        let root = tempfile::tempdir().unwrap();
+        let path = root.path().join("packages/core/package.json");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(
+            &path,
+            "{\n  \"name\": \"core\",\n  \"engines\": {\n    \"node\": \">=16\"\n  }\n}\n",
+        )
+        .unwrap();
+
+        let issues = [
+            // 没有自动修复的问题不会改动文件
+            issue("required-fields", None),
+            issue(
+                "engines-node",
+                Some(ManifestFix::Replace {
+                    key: vec!["engines".to_string(), "node".to_string()],
+                    from: ">=16".to_string(),
+                    to: ">=18".to_string(),
+                }),
+            ),
+            // 检查后文件已变化（当前值不是 from）时跳过
+            issue(
+                "engines-node",
+                Some(ManifestFix::Replace {
+                    key: vec!["engines".to_string(), "node".to_string()],
+                    from: ">=14".to_string(),
+                    to: ">=20".to_string(),
+                }),
+            ),
+            issue(
+                "required-fields",
+                Some(ManifestFix::Insert {
+                    key: "license".to_string(),
+                    value: serde_json::json!("MIT"),
+                }),
+            ),
+        ];
+        let fixes: Vec<&ManifestIssue> = issues.iter().collect();
+
+        assert_eq!(execute_manifest_fixes(&fixes, root.path(), false).unwrap(), 2);
+        let json: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "name": "core", "engines": { "node": ">=18" }, "license": "MIT" })
+        );
+    }
+}
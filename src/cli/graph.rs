@@ -0,0 +1,63 @@
+// ============================================================================
+// MonoX - CLI Graph 命令
+// ============================================================================
+//
+// 文件: src/cli/graph.rs
+// 职责: 依赖图命令的 CLI 接口层
+// 边界:
+//   - ✅ 命令行参数定义和解析
+//   - ✅ 启动交互式依赖图浏览器，或通过 analyze 的导出函数导出依赖图
+//   - ❌ 不应包含依赖分析算法逻辑
+//   - ❌ 不应包含界面绘制和图格式渲染逻辑
+//
+// ============================================================================
+
+use anyhow::Result;
+use clap::Args;
+
+use super::analyze;
+use crate::core::DependencyAnalyzer;
+use crate::models::config::Config;
+use crate::ui::graph_explorer;
+use crate::ui::graph_export::GraphFormat;
+use crate::utils::logger::Logger;
+use crate::{t, tf};
+
+/// 浏览或导出工作区依赖图
+#[derive(Debug, Args)]
+pub struct GraphArgs {
+    /// 打开交互式浏览器（方向键导航，/ 搜索，d/p 切换开发和对等依赖）
+    #[arg(short = 'i', long)]
+    pub interactive: bool,
+
+    /// 导出格式 (dot, mermaid, graphml, cytoscape-json)
+    #[arg(short = 'f', long, default_value = "dot", conflicts_with = "interactive")]
+    pub format: String,
+
+    /// 只导出指定的包及其依赖链（逗号分隔）
+    #[arg(short = 'p', long, value_delimiter = ',', conflicts_with = "interactive")]
+    pub package: Vec<String>,
+}
+
+pub fn handle_graph(args: GraphArgs) -> Result<()> {
+    let workspace_root = Config::get_workspace_root();
+    if !workspace_root.exists() {
+        anyhow::bail!(tf!("error.workspace_not_exist", workspace_root.display()));
+    }
+
+    if !args.interactive {
+        let Some(format) = GraphFormat::parse(&args.format) else {
+            anyhow::bail!(tf!("graph.invalid_format", args.format));
+        };
+        // 与 analyze -f <格式> 使用同一个导出函数
+        return analyze::export_graph(workspace_root, format, &args.package);
+    }
+
+    let mut analyzer = DependencyAnalyzer::new(workspace_root);
+    let result = analyzer.analyze_workspace()?;
+    if result.packages.is_empty() {
+        Logger::warn(t!("graph.no_packages"));
+        return Ok(());
+    }
+    graph_explorer::explore(&result)
+}
@@ -16,24 +16,34 @@
 // ============================================================================
 
 pub mod analyze;
+pub mod change;
 pub mod check;
 pub mod exec;
 pub mod fix;
+pub mod graph;
 pub mod init;
+pub mod publish;
 pub mod run;
+pub mod stats;
 pub mod update;
+pub mod version;
 
 use anyhow::Result;
 use clap::{Parser, Subcommand};
 
 use crate::models::config::{Config, RuntimeArgs};
 use analyze::{handle_analyze, AnalyzeArgs};
+use change::{handle_change, ChangeArgs};
 use check::{handle_check, CheckArgs};
 use exec::{exec, ExecArgs};
 use fix::{handle_fix, FixArgs};
+use graph::{handle_graph, GraphArgs};
 use init::{handle_init, InitArgs};
+use publish::{handle_publish, PublishArgs};
 use run::{run, RunArgs};
+use stats::{handle_stats, StatsArgs};
 use update::{handle_update, UpdateArgs};
+use version::{handle_version, VersionArgs};
 
 /// MonoX - Lightweight monorepo build tool
 #[derive(Debug, Parser)]
@@ -86,18 +96,28 @@ pub struct Cli {
 pub enum Commands {
     /// Analyze workspace dependency relationships
     Analyze(AnalyzeArgs),
+    /// Record and list pending release changes
+    Change(ChangeArgs),
     /// Check workspace health status
     Check(CheckArgs),
     /// Execute predefined tasks
     Exec(ExecArgs),
     /// Auto-fix version conflicts
     Fix(FixArgs),
+    /// Explore or export the workspace dependency graph
+    Graph(GraphArgs),
     /// Initialize configuration file
     Init(InitArgs),
+    /// Publish unpublished package versions in dependency order
+    Publish(PublishArgs),
     /// Run scripts
     Run(RunArgs),
+    /// Show execution history statistics
+    Stats(StatsArgs),
     /// Update dependencies to latest versions
     Update(UpdateArgs),
+    /// Bump package versions and update internal dependents
+    Version(VersionArgs),
 }
 
 pub async fn run_cli() -> Result<()> {
@@ -110,12 +130,17 @@ pub async fn run_cli() -> Result<()> {
 
     match cli.command {
         Commands::Analyze(args) => handle_analyze(args),
+        Commands::Change(args) => handle_change(args),
         Commands::Check(args) => handle_check(args).await,
         Commands::Exec(args) => exec(args).await,
-        Commands::Fix(args) => handle_fix(args),
+        Commands::Fix(args) => handle_fix(args).await,
+        Commands::Graph(args) => handle_graph(args),
         Commands::Init(args) => handle_init(args),
+        Commands::Publish(args) => handle_publish(args).await,
         Commands::Run(args) => run(args).await,
+        Commands::Stats(args) => handle_stats(args),
         Commands::Update(args) => handle_update(args).await,
+        Commands::Version(args) => handle_version(args),
     }
 }
 
@@ -0,0 +1,215 @@
+// ============================================================================
+// MonoX - CLI Publish 命令
+// ============================================================================
+//
+// 文件: src/cli/publish.rs
+// 职责: 发布命令的 CLI 接口层
+// 边界:
+//   - ✅ 命令行参数定义和解析
+//   - ✅ 发布方案显示和用户确认
+//   - ✅ 逐个发布并在失败时报告已发布的包
+//   - ❌ 不应包含打包和 workspace: 协议改写逻辑
+//   - ❌ 不应包含 registry 请求逻辑
+//
+// ============================================================================
+
+use anyhow::Result;
+use clap::Args;
+use serde::Serialize;
+use std::io::{self, Write};
+
+use crate::core::publisher::{PublishStatus, PublishTarget, Publisher};
+use crate::core::registry::RegistryClient;
+use crate::models::config::Config;
+use crate::utils::colors::Colors;
+use crate::utils::logger::Logger;
+use crate::{t, tf};
+
+/// 发布报告（JSON 输出）
+#[derive(Debug, Serialize)]
+struct PublishReport<'a> {
+    /// 是否为预演模式
+    dry_run: bool,
+    /// 所有包（按发布顺序）
+    packages: &'a [PublishTarget],
+    /// 本次发布成功的包
+    published: Vec<String>,
+    /// 发布失败的包
+    #[serde(skip_serializing_if = "Option::is_none")]
+    failed: Option<String>,
+    /// 因失败而未发布的包
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    skipped: Vec<String>,
+}
+
+/// 发布 registry 上尚不存在的包版本
+#[derive(Debug, Args)]
+pub struct PublishArgs {
+    /// 发布标签 (如 next, beta)，默认 latest
+    #[arg(long)]
+    pub tag: Option<String>,
+
+    /// 发布到的 registry（覆盖配置和 .npmrc 中的默认 registry）
+    #[arg(long)]
+    pub registry: Option<String>,
+
+    /// 自动确认
+    #[arg(short = 'y', long)]
+    pub yes: bool,
+
+    /// 输出格式 (table, json)
+    #[arg(short = 'f', long, default_value = "table")]
+    pub format: String,
+
+    /// 只显示待发布的包，不实际发布
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+pub async fn handle_publish(args: PublishArgs) -> Result<()> {
+    let workspace_root = Config::get_workspace_root();
+    if !workspace_root.exists() {
+        anyhow::bail!(tf!("error.workspace_not_exist", workspace_root.display()));
+    }
+
+    // JSON 输出无法交互确认，发布前必须显式指定 --yes 或 --dry-run
+    let json = args.format == "json";
+    if json && !args.yes && !args.dry_run {
+        anyhow::bail!(t!("publish.json_requires_yes"));
+    }
+
+    let registry =
+        RegistryClient::from_config(&workspace_root)?.online().with_registry(args.registry);
+    let publisher = Publisher::new(workspace_root, registry, args.tag);
+    let targets = publisher.plan().await?;
+    let pending: Vec<&PublishTarget> =
+        targets.iter().filter(|target| target.status == PublishStatus::Pending).collect();
+
+    let mut report = PublishReport {
+        dry_run: args.dry_run,
+        packages: &targets,
+        published: Vec::new(),
+        failed: None,
+        skipped: Vec::new(),
+    };
+
+    if !json {
+        display_publish_plan(&targets);
+    }
+    if pending.is_empty() || args.dry_run {
+        if json {
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        } else if pending.is_empty() {
+            Logger::success(t!("publish.nothing_to_publish"));
+        } else {
+            Logger::info(t!("publish.dry_run_complete"));
+        }
+        return Ok(());
+    }
+
+    if !args.yes && !confirm_publish()? {
+        Logger::info(t!("publish.cancelled"));
+        return Ok(());
+    }
+
+    // 按依赖顺序逐个发布，失败时停止
+    let mut error = None;
+    for (index, target) in pending.iter().enumerate() {
+        if !json {
+            Logger::info(tf!("publish.publishing", target.name, target.version));
+        }
+        match publisher.publish(target) {
+            Ok(()) => report.published.push(format!("{}@{}", target.name, target.version)),
+            Err(e) => {
+                report.failed = Some(format!("{}@{}", target.name, target.version));
+                report.skipped = pending[index + 1..]
+                    .iter()
+                    .map(|target| format!("{}@{}", target.name, target.version))
+                    .collect();
+                error = Some(e);
+                break;
+            }
+        }
+    }
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        display_publish_result(&report);
+    }
+
+    match error {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+/// 显示发布方案
+fn display_publish_plan(targets: &[PublishTarget]) {
+    Logger::info("");
+    Logger::info(t!("publish.plan_details"));
+    Logger::info("───────────────────────────────────────");
+
+    let name_width = targets.iter().map(|target| target.name.len()).max().unwrap_or(0);
+    for target in targets {
+        let status = match target.status {
+            PublishStatus::Pending => Colors::green(&t!("publish.status.pending")),
+            PublishStatus::Published => t!("publish.status.published"),
+            PublishStatus::Private => t!("publish.status.private"),
+        };
+        let name = format!("{:<name_width$}", target.name);
+        Logger::info(format!(
+            "  {}  {}  {}  {}",
+            tf!("publish.stage", target.stage),
+            Colors::info(&name),
+            target.version,
+            status
+        ));
+        if target.status == PublishStatus::Pending {
+            for rewrite in &target.rewrites {
+                Logger::info(tf!(
+                    "publish.rewrite_line",
+                    rewrite.dependency,
+                    rewrite.dep_type,
+                    rewrite.from,
+                    rewrite.to
+                ));
+            }
+        }
+    }
+
+    let pending = targets.iter().filter(|target| target.status == PublishStatus::Pending);
+    let registries: std::collections::BTreeSet<&str> =
+        pending.clone().map(|target| target.registry.as_str()).collect();
+    Logger::info("");
+    for registry in registries {
+        Logger::info(tf!("publish.registry", registry));
+    }
+    Logger::info(tf!("publish.total", pending.count(), targets.len()));
+}
+
+/// 显示发布结果
+fn display_publish_result(report: &PublishReport) {
+    Logger::info("");
+    if !report.published.is_empty() {
+        Logger::success(tf!("publish.published", report.published.join(", ")));
+    }
+    if let Some(failed) = &report.failed {
+        Logger::error(tf!("publish.failed", failed));
+        if !report.skipped.is_empty() {
+            Logger::warn(tf!("publish.skipped", report.skipped.join(", ")));
+        }
+    }
+}
+
+/// 确认发布操作
+fn confirm_publish() -> Result<bool> {
+    print!("{} ", t!("publish.confirm_prompt"));
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+
+    let input = input.trim().to_lowercase();
+    Ok(input == "y" || input == "yes" || input == "是" || input == "确认")
+}
@@ -14,7 +14,9 @@
 
 use anyhow::Result;
 use clap::Args;
+use std::path::PathBuf;
 
+use crate::core::report::parse_report_targets;
 use crate::core::TaskExecutor;
 use crate::utils::logger::Logger;
 use crate::{t, tf};
@@ -41,12 +43,39 @@ pub struct RunArgs {
     /// 是否运行所有包 - no must
     #[arg(short = 'a', long)]
     pub all: bool,
+
+    /// 导出 Chrome Trace 格式的性能剖析文件 (如: out.json)
+    #[arg(long)]
+    pub profile: Option<PathBuf>,
+
+    /// 导出运行报告，可重复指定 (如: json=report.json, junit=junit.xml)
+    #[arg(long, value_name = "FORMAT=PATH")]
+    pub report: Vec<String>,
+
+    /// 试运行：只输出执行计划，不执行任何命令
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// 执行计划输出格式，配合 --dry-run 使用 (table, json)
+    #[arg(short = 'f', long, default_value = "table")]
+    pub format: String,
+
+    /// 允许循环依赖：同一循环中的包在同一阶段执行
+    #[arg(long)]
+    pub allow_cycles: bool,
 }
 
 pub async fn run(args: RunArgs) -> Result<()> {
-    Logger::info(tf!("run.start", &args.command));
+    // JSON 执行计划只输出计划本身，保证输出可解析
+    if !(args.dry_run && args.format == "json") {
+        Logger::info(tf!("run.start", &args.command));
+    }
 
-    let executor = TaskExecutor::new_from_config()?;
+    let executor = TaskExecutor::new_from_config()?
+        .with_profile_output(args.profile)
+        .with_reports(parse_report_targets(&args.report)?)
+        .with_dry_run(args.dry_run, &args.format)
+        .with_allow_cycles(args.allow_cycles);
     match (args.all, args.package, args.packages) {
         // 优先级：all > packages > package
         (true, _, _) => executor.execute("*", &args.command, &args.post_command, Some(true)).await,
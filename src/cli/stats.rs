@@ -0,0 +1,83 @@
+// ============================================================================
+// MonoX - CLI Stats 命令
+// ============================================================================
+//
+// 文件: src/cli/stats.rs
+// 职责: 执行历史统计命令的 CLI 接口层
+// 边界:
+//   - ✅ 命令行参数定义和解析
+//   - ✅ 读取执行历史并生成统计报告
+//   - ✅ 统计结果格式化输出（表格/JSON）
+//   - ❌ 不应包含历史文件读写逻辑
+//   - ❌ 不应包含统计计算逻辑
+//   - ❌ 不应包含数据模型定义
+//
+// ============================================================================
+
+use anyhow::Result;
+use clap::Args;
+
+use crate::core::history::{build_report, HistoryStore};
+use crate::models::config::Config;
+use crate::ui::summary;
+use crate::utils::logger::Logger;
+use crate::{t, tf};
+
+/// 查看执行历史统计
+#[derive(Debug, Args)]
+pub struct StatsArgs {
+    /// 统计最近 N 次运行
+    #[arg(short = 'n', long, default_value_t = 20)]
+    pub last: usize,
+
+    /// 只统计指定脚本命令的运行 (如: build, test)
+    #[arg(short = 'c', long)]
+    pub command: Option<String>,
+
+    /// 最慢包列表显示数量
+    #[arg(long, default_value_t = 10)]
+    pub top: usize,
+
+    /// 输出格式 (table, json)
+    #[arg(short = 'f', long, default_value = "table")]
+    pub format: String,
+}
+
+pub fn handle_stats(args: StatsArgs) -> Result<()> {
+    let workspace_root = Config::get_workspace_root();
+
+    if !workspace_root.exists() {
+        anyhow::bail!(tf!("error.workspace_not_exist", workspace_root.display()));
+    }
+
+    let store = HistoryStore::new(&workspace_root);
+
+    // 先按命令过滤，再取最近 N 次
+    let mut runs = store.load_all()?;
+    if let Some(command) = &args.command {
+        runs.retain(|run| &run.command == command);
+    }
+    if runs.len() > args.last {
+        runs.drain(..runs.len() - args.last);
+    }
+
+    if runs.is_empty() {
+        Logger::info(tf!("stats.no_history", store.path().display()));
+        return Ok(());
+    }
+
+    let report = build_report(&runs, args.top);
+
+    match args.format.as_str() {
+        "json" => {
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        }
+        _ => {
+            Logger::info(tf!("stats.start", report.total_runs));
+            summary::print_history_report(&report);
+            Logger::info(t!("stats.usage_tip"));
+        }
+    }
+
+    Ok(())
+}
@@ -15,17 +15,30 @@
 
 use anyhow::Result;
 use clap::Args;
-use regex;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fs;
 use std::path::Path;
 
-use crate::core::checker::HealthChecker;
+use crate::core::checker::UpdateLevel;
+use crate::core::installer::{ManifestBackup, PostWriteOutcome, PostWriteRunner};
+use crate::core::registry::RegistryClient;
+use crate::core::updater::{
+    self, DependencyUpdater, HeldUpdate, HoldReason, ReleaseInfo, UpdateOptions, UpdatePlan,
+};
 use crate::models::config::Config;
+use crate::ui::picker;
+use crate::utils::json_edit::{self, JsonDocument};
 use crate::utils::logger::Logger;
+use crate::utils::semver_range::NpmVersionSpec;
 use crate::{t, tf};
 
+/// 发布信息中最多显示的跳过版本数
+const MAX_SKIPPED_SHOWN: usize = 8;
+
+/// 发布信息中最多显示的 CHANGELOG 条目数
+const MAX_CHANGELOG_SHOWN: usize = 3;
+
 /// 更新结果信息
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateResult {
@@ -39,6 +52,25 @@ pub struct UpdateResult {
     pub package: String,
     /// 依赖类型
     pub dep_type: String,
+    /// 更新级别 (patch, minor, major)
+    pub level: String,
+    /// 目标版本的发布信息
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release: Option<ReleaseInfo>,
+}
+
+/// 更新报告（JSON 输出）
+#[derive(Debug, Serialize)]
+struct UpdateReport<'a> {
+    /// 是否为预演模式
+    dry_run: bool,
+    /// 更新方案或已执行的更新
+    updates: &'a [UpdateResult],
+    /// 因策略未更新的依赖
+    held: &'a [HeldUpdate],
+    /// 安装、验证和回滚结果
+    #[serde(skip_serializing_if = "Option::is_none")]
+    install: Option<&'a PostWriteOutcome>,
 }
 
 /// 更新依赖命令
@@ -56,18 +88,63 @@ pub struct UpdateArgs {
     #[arg(long)]
     pub version: Option<String>,
 
+    /// 允许的最高更新级别 (patch, minor, major)
+    #[arg(long, value_parser = ["patch", "minor", "major"], conflicts_with = "version")]
+    pub target: Option<String>,
+
+    /// 只更新匹配的工作区包（支持通配符，逗号分隔）
+    #[arg(long, value_delimiter = ',')]
+    pub filter: Vec<String>,
+
+    /// 只更新指定类型的依赖 (prod, dev, peer)
+    #[arg(long, value_parser = ["prod", "dev", "peer"])]
+    pub dep_type: Option<String>,
+
+    /// 交互式选择要执行的更新
+    #[arg(short = 'i', long)]
+    pub interactive: bool,
+
+    /// 输出格式 (table, json)
+    #[arg(short = 'f', long, default_value = "table")]
+    pub format: String,
+
     /// 只检查，不实际更新（预演模式）
     #[arg(long)]
     pub dry_run: bool,
+
+    /// 离线模式，仅使用本地缓存的 registry 元数据
+    #[arg(long)]
+    pub offline: bool,
+
+    /// 写入后运行包管理器安装，同步锁文件
+    #[arg(long)]
+    pub install: bool,
+
+    /// 安装后在受影响的包中运行的验证任务 (如: build, test)，失败时恢复原文件
+    #[arg(long, value_name = "TASK", requires = "install")]
+    pub verify: Option<String>,
 }
 
 pub async fn handle_update(args: UpdateArgs) -> Result<()> {
-    Logger::info(t!("cli.update.start"));
+    // JSON 模式下标准输出只包含报告，安装和验证的过程日志写到标准错误
+    let json = args.format == "json";
+    Logger::set_stderr_output(json);
+    if !json {
+        Logger::info(t!("cli.update.start"));
+    }
 
-    // 验证参数
-    if !args.all && args.package.is_none() {
+    // 验证参数（交互模式默认检查所有依赖，JSON 输出无法交互选择）
+    if json && args.interactive {
+        anyhow::bail!(t!("update.interactive_json"));
+    }
+    if !args.all && args.package.is_none() && !args.interactive {
         anyhow::bail!(t!("update.missing_package_or_all"));
     }
+    if let Some(version) = &args.version {
+        if NpmVersionSpec::parse(version).and_then(|spec| spec.min_version()).is_none() {
+            anyhow::bail!(tf!("update.invalid_version", version));
+        }
+    }
 
     // 获取工作区根目录
     let workspace_root = Config::get_workspace_root();
@@ -81,22 +158,50 @@ pub async fn handle_update(args: UpdateArgs) -> Result<()> {
     let package_files = collect_package_files(&workspace_root, verbose)?;
 
     if package_files.is_empty() {
-        Logger::info(t!("update.no_packages_found"));
+        if json {
+            print_report(args.dry_run, &[], &[], None)?;
+        } else {
+            Logger::info(t!("update.no_packages_found"));
+        }
         return Ok(());
     }
 
-    let update_plan = if args.all {
-        // 更新所有过期依赖
-        create_update_plan_for_all(&package_files, verbose).await?
-    } else {
-        // 更新指定依赖
-        let dependency_name = args.package.unwrap();
-        create_update_plan_for_dependency(&package_files, &dependency_name, args.version.as_deref())
-            .await?
+    let policy = Config::get_update_config();
+    let target = args
+        .target
+        .as_deref()
+        .or(Some(policy.target.as_str()))
+        .and_then(UpdateLevel::parse)
+        .unwrap_or(UpdateLevel::Major);
+    let options = UpdateOptions {
+        dependency: if args.all { None } else { args.package.clone() },
+        version: args.version.clone(),
+        target,
+        packages: args.filter.clone(),
+        dep_fields: updater::dep_fields(args.dep_type.as_deref()),
     };
 
+    if !json {
+        match &options.dependency {
+            Some(dependency) => Logger::info(tf!("update.checking_dependency", dependency)),
+            None => Logger::info(t!("update.checking_outdated")),
+        }
+    }
+
+    let registry = RegistryClient::from_config(&workspace_root)?.with_offline(args.offline);
+    let updater = DependencyUpdater::new(registry, Config::get_registry_config(), policy);
+    let plan = updater.plan(&package_files, &options).await?;
+
+    let held = plan.held.clone();
+    if !json {
+        display_held_updates(&held);
+    }
+    let mut update_plan = to_update_results(plan);
+
     if update_plan.is_empty() {
-        if args.all {
+        if json {
+            print_report(args.dry_run, &update_plan, &held, None)?;
+        } else if options.dependency.is_none() {
             Logger::success(t!("update.no_outdated_found"));
         } else {
             Logger::info(t!("update.dependency_not_found"));
@@ -104,22 +209,58 @@ pub async fn handle_update(args: UpdateArgs) -> Result<()> {
         return Ok(());
     }
 
+    if args.interactive {
+        update_plan = select_updates(update_plan)?;
+        if update_plan.is_empty() {
+            Logger::info(t!("update.nothing_selected"));
+            return Ok(());
+        }
+    }
+
     if args.dry_run {
         // 预演模式：显示更新方案
-        display_update_plan(&update_plan)?;
-        Logger::info(t!("update.dry_run_complete"));
+        if json {
+            print_report(true, &update_plan, &held, None)?;
+        } else {
+            display_update_plan(&update_plan)?;
+            Logger::info(t!("update.dry_run_complete"));
+        }
         return Ok(());
     }
 
+    // 写入前备份 package.json 和锁文件，安装或验证失败时恢复
+    let post_write = PostWriteRunner::new(workspace_root.clone())
+        .with_install(args.install)
+        .with_verify(args.verify.clone());
+    let backup = if post_write.is_enabled() {
+        ManifestBackup::capture(&workspace_root, &package_files)?
+    } else {
+        ManifestBackup::default()
+    };
+
     // 执行更新
     let results = execute_updates(&update_plan, &package_files, verbose)?;
 
-    // 显示更新结果
-    display_update_results(&results)?;
+    // 显示更新结果（JSON 报告在安装和验证之后输出，包含它们的结果）
+    if !json {
+        display_update_results(&results)?;
+        Logger::success(tf!("update.completed", results.len()));
+    }
 
-    Logger::success(tf!("update.completed", results.len()));
+    let outcome = if post_write.is_enabled() && !results.is_empty() {
+        let changed: BTreeSet<String> = results.iter().map(|r| r.package.clone()).collect();
+        Some(post_write.run(&backup, &changed.into_iter().collect::<Vec<_>>()).await?)
+    } else {
+        None
+    };
 
-    Ok(())
+    if json {
+        print_report(false, &results, &held, outcome.as_ref())?;
+    }
+    match outcome {
+        Some(outcome) => outcome.into_result(),
+        None => Ok(()),
+    }
 }
 
 /// 收集 package.json 文件
@@ -167,148 +308,84 @@ fn collect_package_files(workspace_root: &Path, verbose: bool) -> Result<Vec<std
     Ok(package_files)
 }
 
-/// 为所有过期依赖创建更新方案
-async fn create_update_plan_for_all(
-    _package_files: &[std::path::PathBuf],
-    verbose: bool,
-) -> Result<Vec<UpdateResult>> {
-    Logger::info(t!("update.checking_outdated"));
-
-    // 使用 HealthChecker 检查过期依赖
-    let workspace_root = Config::get_workspace_root();
-    let checker = HealthChecker::new(workspace_root).with_verbose(verbose);
-
-    let (outdated_deps, _) = checker.check_outdated_dependencies_with_progress(None).await?;
-
-    let mut updates = Vec::new();
-    for outdated_dep in outdated_deps {
-        updates.push(UpdateResult {
-            dependency: outdated_dep.name,
-            old_version: outdated_dep.current,
-            new_version: outdated_dep.latest,
-            package: outdated_dep.package,
-            dep_type: outdated_dep.dep_type,
-        });
-    }
-
-    Ok(updates)
+/// 转换为更新结果（按包名和依赖名排序）
+fn to_update_results(plan: UpdatePlan) -> Vec<UpdateResult> {
+    let mut updates: Vec<UpdateResult> = plan
+        .updates
+        .into_iter()
+        .map(|candidate| UpdateResult {
+            dependency: candidate.dependency,
+            old_version: candidate.old_spec,
+            new_version: candidate.new_spec,
+            package: candidate.package,
+            dep_type: candidate.dep_type,
+            level: candidate.level.as_str().to_string(),
+            release: candidate.release,
+        })
+        .collect();
+    updates.sort_by(|a, b| (&a.package, &a.dependency).cmp(&(&b.package, &b.dependency)));
+    updates
 }
 
-/// 为指定依赖创建更新方案
-async fn create_update_plan_for_dependency(
-    package_files: &[std::path::PathBuf],
-    dependency_name: &str,
-    target_version: Option<&str>,
-) -> Result<Vec<UpdateResult>> {
-    Logger::info(tf!("update.checking_dependency", dependency_name));
-
-    let mut updates = Vec::new();
-
-    // 确定目标版本
-    let new_version = if let Some(version) = target_version {
-        version.to_string()
-    } else {
-        // 获取最新版本
-        match get_latest_version_async(dependency_name).await? {
-            Some(version) => version,
-            None => {
-                Logger::warn(tf!("update.version_fetch_failed", dependency_name));
-                return Ok(updates);
-            }
-        }
-    };
-
-    // 在所有 package.json 中查找该依赖
-    for package_file in package_files {
-        let content = fs::read_to_string(package_file)?;
-        let package_json: serde_json::Value = serde_json::from_str(&content)?;
-
-        let package_name = package_json["name"].as_str().unwrap_or("unknown").to_string();
-
-        // 检查所有依赖类型
-        let dep_types = ["dependencies", "devDependencies", "peerDependencies"];
-
-        for dep_type in &dep_types {
-            if let Some(deps) = package_json[dep_type].as_object() {
-                if let Some(current_version_value) = deps.get(dependency_name) {
-                    let current_version = current_version_value.as_str().unwrap_or("").to_string();
-
-                    // 跳过工作区依赖、文件依赖等
-                    if should_skip_dependency(&current_version) {
-                        continue;
-                    }
-
-                    // 提取当前版本号
-                    let clean_current = extract_version_from_spec(&current_version);
-
-                    // 如果版本不同，添加到更新列表
-                    if clean_current != new_version {
-                        updates.push(UpdateResult {
-                            dependency: dependency_name.to_string(),
-                            old_version: current_version,
-                            new_version: new_version.clone(),
-                            package: package_name.clone(),
-                            dep_type: dep_type.to_string(),
-                        });
-                    }
-                }
-            }
-        }
-    }
-
-    Ok(updates)
+/// 以 JSON 格式输出更新报告
+fn print_report(
+    dry_run: bool,
+    updates: &[UpdateResult],
+    held: &[HeldUpdate],
+    install: Option<&PostWriteOutcome>,
+) -> Result<()> {
+    let report = UpdateReport { dry_run, updates, held, install };
+    println!("{}", serde_json::to_string_pretty(&report)?);
+    Ok(())
 }
 
-/// 异步获取最新版本
-async fn get_latest_version_async(package_name: &str) -> Result<Option<String>> {
-    use tokio::process::Command;
-
-    let output =
-        Command::new("npm").args(&["view", package_name, "version", "--json"]).output().await?;
-
-    if !output.status.success() {
-        return Ok(None);
-    }
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let trimmed = stdout.trim();
-
-    if trimmed.is_empty() {
-        return Ok(None);
-    }
-
-    // 解析响应
-    if let Ok(response) = serde_json::from_str::<serde_json::Value>(trimmed) {
-        if let Some(version) = response.as_str() {
-            return Ok(Some(version.to_string()));
+/// 显示因策略未更新的依赖
+fn display_held_updates(held: &[HeldUpdate]) {
+    for held in held {
+        match &held.reason {
+            HoldReason::Pinned => Logger::warn(tf!(
+                "update.held_pinned",
+                held.dependency,
+                held.package,
+                held.spec,
+                held.available
+            )),
+            HoldReason::NotAllowed(range) => Logger::warn(tf!(
+                "update.held_not_allowed",
+                held.dependency,
+                held.package,
+                held.available,
+                range
+            )),
         }
     }
-
-    // 后备处理：直接使用去引号的字符串
-    let version = trimmed.trim_matches('"');
-    Ok(Some(version.to_string()))
 }
 
-/// 检查是否应该跳过依赖检查
-fn should_skip_dependency(version_spec: &str) -> bool {
-    version_spec.starts_with("workspace:")
-        || version_spec.starts_with("file:")
-        || version_spec.starts_with("link:")
-        || version_spec.contains("git+")
-        || version_spec.contains("github:")
-}
+/// 交互式选择要执行的更新
+fn select_updates(updates: Vec<UpdateResult>) -> Result<Vec<UpdateResult>> {
+    use crate::utils::colors::Colors;
 
-/// 从版本规范中提取版本号
-fn extract_version_from_spec(version_spec: &str) -> String {
-    version_spec
-        .trim_start_matches('^')
-        .trim_start_matches('~')
-        .trim_start_matches(">=")
-        .trim_start_matches("<=")
-        .trim_start_matches('>')
-        .trim_start_matches('<')
-        .trim_start_matches('=')
-        .to_string()
+    let items: Vec<String> = updates
+        .iter()
+        .map(|update| {
+            tf!(
+                "update.select_item",
+                update.package,
+                Colors::info(&update.dependency),
+                Colors::red(&update.old_version),
+                Colors::green(&update.new_version),
+                t!(&format!("check.outdated.level.{}", update.level))
+            )
+        })
+        .collect();
+
+    let selected = picker::multi_select(&t!("update.select_title"), &items)?;
+    Ok(updates
+        .into_iter()
+        .enumerate()
+        .filter(|(index, _)| selected.contains(index))
+        .map(|(_, update)| update)
+        .collect())
 }
 
 /// 显示更新方案
@@ -319,7 +396,7 @@ fn display_update_plan(updates: &[UpdateResult]) -> Result<()> {
     Logger::info("═══════════════════════════════════════");
 
     // 按包分组显示
-    let mut packages: HashMap<String, Vec<&UpdateResult>> = HashMap::new();
+    let mut packages: BTreeMap<String, Vec<&UpdateResult>> = BTreeMap::new();
     for update in updates {
         packages.entry(update.package.clone()).or_default().push(update);
     }
@@ -329,7 +406,17 @@ fn display_update_plan(updates: &[UpdateResult]) -> Result<()> {
         for update in package_updates {
             let old_version = Colors::red(&update.old_version);
             let new_version = Colors::green(&update.new_version);
-            Logger::info(tf!("update.update_simple", update.dependency, old_version, new_version));
+            let level = t!(&format!("check.outdated.level.{}", update.level));
+            Logger::info(tf!(
+                "update.update_with_level",
+                update.dependency,
+                old_version,
+                new_version,
+                level
+            ));
+            if let Some(release) = &update.release {
+                display_release_info(release);
+            }
         }
         Logger::info("");
     }
@@ -338,6 +425,74 @@ fn display_update_plan(updates: &[UpdateResult]) -> Result<()> {
     Ok(())
 }
 
+/// 显示目标版本的发布信息
+fn display_release_info(release: &ReleaseInfo) {
+    use crate::utils::colors::Colors;
+    use crate::utils::constants::icons;
+
+    if release.breaking {
+        Logger::info(format!(
+            "      {}",
+            Colors::yellow(&format!("{} {}", icons::WARNING, t!("update.release.breaking")))
+        ));
+    }
+    if let Some(message) = &release.deprecated {
+        Logger::info(format!("      {}", Colors::red(&tf!("update.release.deprecated", message))));
+    }
+    if let Some(published) = &release.published {
+        Logger::info(tf!("update.release.published", format_date(published)));
+    }
+
+    if !release.skipped.is_empty() {
+        let mut versions: Vec<String> = release
+            .skipped
+            .iter()
+            .take(MAX_SKIPPED_SHOWN)
+            .map(|skipped| {
+                if skipped.deprecated {
+                    tf!("update.release.deprecated_version", skipped.version)
+                } else {
+                    skipped.version.clone()
+                }
+            })
+            .collect();
+        if release.skipped.len() > MAX_SKIPPED_SHOWN {
+            versions.push(tf!("update.release.more", release.skipped.len() - MAX_SKIPPED_SHOWN));
+        }
+        Logger::info(tf!("update.release.skipped", release.skipped.len(), versions.join(", ")));
+    }
+
+    for entry in release.changelog.iter().take(MAX_CHANGELOG_SHOWN) {
+        let version = if entry.breaking {
+            Colors::yellow(&entry.version)
+        } else {
+            Colors::info(&entry.version)
+        };
+        Logger::info(tf!("update.release.changelog_version", version));
+        for line in &entry.lines {
+            Logger::info(format!("          {}", line));
+        }
+    }
+    if release.changelog.len() > MAX_CHANGELOG_SHOWN {
+        Logger::info(format!(
+            "        {}",
+            tf!("update.release.more", release.changelog.len() - MAX_CHANGELOG_SHOWN)
+        ));
+    }
+
+    // 本地没有对应条目时给出仓库地址
+    if release.changelog.is_empty() {
+        if let Some(repository) = &release.repository {
+            Logger::info(tf!("update.release.repository", repository));
+        }
+    }
+}
+
+/// 取 ISO 时间的日期部分
+fn format_date(timestamp: &str) -> &str {
+    timestamp.get(..10).unwrap_or(timestamp)
+}
+
 /// 执行更新
 fn execute_updates(
     updates: &[UpdateResult],
@@ -345,15 +500,13 @@ fn execute_updates(
     verbose: bool,
 ) -> Result<Vec<UpdateResult>> {
     let mut executed_updates = Vec::new();
-    let mut package_path_map: HashMap<String, &std::path::PathBuf> = HashMap::new();
+    let mut documents: HashMap<String, JsonDocument> = HashMap::new();
 
-    // 建立包名到文件路径的映射
+    // 建立包名到文件的映射
     for package_file in package_files {
-        let content = fs::read_to_string(package_file)?;
-        let package_json: serde_json::Value = serde_json::from_str(&content)?;
-
-        if let Some(name) = package_json["name"].as_str() {
-            package_path_map.insert(name.to_string(), package_file);
+        let document = JsonDocument::load(package_file)?;
+        if let Some(name) = document.value()?["name"].as_str() {
+            documents.insert(name.to_string(), document);
         }
     }
 
@@ -364,22 +517,18 @@ fn execute_updates(
     }
 
     for (package_name, package_updates) in packages {
-        if let Some(&package_file) = package_path_map.get(&package_name) {
+        if let Some(document) = documents.get_mut(&package_name) {
             if verbose {
                 Logger::info(tf!("update.processing_package", package_name));
             }
 
-            let mut content = fs::read_to_string(package_file)?;
-            let mut updated_count = 0;
-
+            // 只修改对应依赖类型下的版本，保持文件格式
             for update in &package_updates {
-                if replace_dependency_version(
-                    &mut content,
-                    &update.dependency,
+                if document.set_string(
+                    &[&update.dep_type, &update.dependency],
                     &update.old_version,
                     &update.new_version,
                 )? {
-                    updated_count += 1;
                     executed_updates.push((*update).clone());
 
                     if verbose {
@@ -393,40 +542,17 @@ fn execute_updates(
                 }
             }
 
-            if updated_count > 0 {
-                fs::write(package_file, content)?;
-                if verbose {
-                    Logger::info(tf!("update.updated_package", package_name));
-                }
+            if verbose && document.is_modified() {
+                Logger::info(tf!("update.updated_package", package_name));
             }
         }
     }
 
-    Ok(executed_updates)
-}
+    // 所有文件一起写回，任一文件失败时全部保持原样
+    let documents: Vec<JsonDocument> = documents.into_values().collect();
+    json_edit::commit(&documents)?;
 
-/// 替换依赖版本
-fn replace_dependency_version(
-    content: &mut String,
-    dependency: &str,
-    old_version: &str,
-    new_version: &str,
-) -> Result<bool> {
-    // 构建精确的搜索模式
-    // 匹配：  "dependency": "old_version"
-    let search_pattern = format!(r#""{}":\s*"{}""#, dependency, regex::escape(old_version));
-    let replacement = format!(r#""{}": "{}""#, dependency, new_version);
-
-    // 使用正则表达式进行替换
-    let regex = regex::Regex::new(&search_pattern)?;
-
-    if regex.is_match(content) {
-        let new_content = regex.replace(content, replacement.as_str());
-        *content = new_content.to_string();
-        return Ok(true);
-    }
-
-    Ok(false)
+    Ok(executed_updates)
 }
 
 /// 显示更新结果
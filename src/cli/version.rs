@@ -0,0 +1,296 @@
+// ============================================================================
+// MonoX - CLI Version 命令
+// ============================================================================
+//
+// 文件: src/cli/version.rs
+// 职责: 版本发布命令的 CLI 接口层
+// 边界:
+//   - ✅ 命令行参数定义和解析
+//   - ✅ 调用版本发布器计算和写入版本
+//   - ✅ 未指定升级方式时消费变更文件并生成 CHANGELOG
+//   - ✅ 发布方案显示和用户确认
+//   - ✅ 发布提交和标签的创建
+//   - ❌ 不应包含版本计算逻辑
+//   - ❌ 不应包含 package.json 修改逻辑
+//
+// ============================================================================
+
+use anyhow::Result;
+use clap::Args;
+use serde::Serialize;
+use std::io::{self, Write};
+
+use crate::core::changelog::{self, ChangelogSection};
+use crate::core::changes::{ChangeFile, ChangeStore};
+use crate::core::git::GitRepository;
+use crate::core::versioner::{BumpKind, BumpReason, VersionPlan, Versioner};
+use crate::models::config::Config;
+use crate::utils::colors::Colors;
+use crate::utils::logger::Logger;
+use crate::{t, tf};
+
+/// 发布报告（JSON 输出）
+#[derive(Debug, Serialize)]
+struct VersionReport<'a> {
+    /// 是否为预演模式
+    dry_run: bool,
+    /// 发布方案
+    #[serde(flatten)]
+    plan: &'a VersionPlan,
+    /// 消费的变更文件
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    changes: &'a [ChangeFile],
+    /// 新增的 CHANGELOG 段落
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    changelogs: &'a [ChangelogSection],
+}
+
+/// 升级工作区包的版本
+#[derive(Debug, Args)]
+pub struct VersionArgs {
+    /// 升级方式 (patch, minor, major, prerelease) 或指定版本号，省略时使用变更文件
+    #[arg(value_name = "BUMP")]
+    pub bump: Option<String>,
+
+    /// 要升级的包（支持通配符，逗号分隔）
+    #[arg(short = 'p', long, value_delimiter = ',', requires = "bump")]
+    pub package: Vec<String>,
+
+    /// 升级所有包
+    #[arg(short = 'a', long, conflicts_with = "package", requires = "bump")]
+    pub all: bool,
+
+    /// 预发布标识 (如 alpha, beta, rc)
+    #[arg(long, requires = "bump")]
+    pub preid: Option<String>,
+
+    /// 创建发布提交
+    #[arg(long)]
+    pub commit: bool,
+
+    /// 为升级的包创建 git 标签（同时创建发布提交）
+    #[arg(long)]
+    pub tag: bool,
+
+    /// 自动确认
+    #[arg(short = 'y', long)]
+    pub yes: bool,
+
+    /// 输出格式 (table, json)
+    #[arg(short = 'f', long, default_value = "table")]
+    pub format: String,
+
+    /// 只显示版本变更，不写入文件
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+pub fn handle_version(args: VersionArgs) -> Result<()> {
+    let workspace_root = Config::get_workspace_root();
+    if !workspace_root.exists() {
+        anyhow::bail!(tf!("error.workspace_not_exist", workspace_root.display()));
+    }
+
+    let kind = match &args.bump {
+        Some(bump) => {
+            let Some(kind) = BumpKind::parse(bump) else {
+                anyhow::bail!(tf!("version.invalid_bump", bump));
+            };
+            if args.package.is_empty() && !args.all {
+                anyhow::bail!(t!("version.no_packages_selected"));
+            }
+            Some(kind)
+        }
+        None => None,
+    };
+
+    // JSON 输出无法交互确认，写入前必须显式指定 --yes 或 --dry-run
+    let json = args.format == "json";
+    if json && !args.yes && !args.dry_run {
+        anyhow::bail!(t!("version.json_requires_yes"));
+    }
+
+    let config = Config::get_version_config();
+    let store = ChangeStore::new(&workspace_root);
+
+    // 计算发布方案（未指定升级方式时由变更文件决定）
+    let versioner = Versioner::new(workspace_root.clone(), config.clone())?;
+    let (plan, changes) = match &kind {
+        Some(kind) => {
+            let preid =
+                args.preid.clone().or_else(|| Some(config.preid.clone()).filter(|p| !p.is_empty()));
+            let selected = versioner.select(&args.package)?;
+            (versioner.plan(kind, &selected, preid.as_deref())?, Vec::new())
+        }
+        None => {
+            let changes = store.load_all()?;
+            if changes.is_empty() {
+                anyhow::bail!(t!("version.no_changes"));
+            }
+            (versioner.plan_changes(&changes)?, changes)
+        }
+    };
+    let changelogs =
+        if changes.is_empty() { Vec::new() } else { changelog::build_sections(&plan, &changes) };
+
+    // 提交和标签需要在写入前确认仓库状态
+    let with_commit = args.commit || args.tag;
+    let git = if with_commit { Some(GitRepository::open(&workspace_root)?) } else { None };
+    if let (Some(git), true) = (&git, args.tag) {
+        if let Some(existing) = plan.tags.iter().find(|tag| git.tag_exists(tag)) {
+            anyhow::bail!(tf!("version.tag_exists", existing));
+        }
+    }
+
+    if json {
+        print_report(VersionReport {
+            dry_run: args.dry_run,
+            plan: &plan,
+            changes: &changes,
+            changelogs: &changelogs,
+        })?;
+    } else {
+        display_version_plan(&plan, &changes, with_commit, args.tag);
+    }
+
+    if args.dry_run {
+        if !json {
+            Logger::info(t!("version.dry_run_complete"));
+        }
+        return Ok(());
+    }
+
+    if !args.yes && !confirm_version()? {
+        Logger::info(t!("version.cancelled"));
+        return Ok(());
+    }
+
+    // 版本号、版本范围和 CHANGELOG 一起写入，任一文件失败时全部保持原样
+    let changelog_files = changelog::stage_sections(&changelogs)?;
+    let mut changed_files = versioner.apply(&plan, &changelog_files)?;
+    if !json {
+        let manifests = changed_files.len() - changelog_files.len();
+        Logger::success(tf!("version.completed", plan.bumps.len(), manifests));
+    }
+
+    // 全部写入成功后才删除已消费的变更文件（已跟踪的变更文件随发布提交一起删除）
+    if !changes.is_empty() {
+        if let Some(git) = &git {
+            changed_files.extend(
+                changes
+                    .iter()
+                    .filter(|change| git.is_tracked(&change.path))
+                    .map(|change| change.path.clone()),
+            );
+        }
+        store.remove(&changes)?;
+        if !json {
+            Logger::success(tf!("version.changes_consumed", changes.len(), changelogs.len()));
+        }
+    }
+
+    // 创建发布提交和标签
+    if let Some(git) = git {
+        let released: Vec<String> =
+            plan.bumps.iter().map(|bump| format!(" - {}@{}", bump.name, bump.next)).collect();
+        let message = format!("{}\n\n{}", config.commit_message(), released.join("\n"));
+
+        git.add(&changed_files)?;
+        git.commit(&message, &changed_files)?;
+        if !json {
+            Logger::success(t!("version.committed"));
+        }
+
+        if args.tag {
+            for tag in &plan.tags {
+                git.tag(tag)?;
+            }
+            if !json {
+                Logger::success(tf!("version.tagged", plan.tags.join(", ")));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// 输出 JSON 格式的发布方案
+fn print_report(report: VersionReport) -> Result<()> {
+    println!("{}", serde_json::to_string_pretty(&report)?);
+    Ok(())
+}
+
+/// 显示发布方案
+fn display_version_plan(
+    plan: &VersionPlan,
+    changes: &[ChangeFile],
+    with_commit: bool,
+    with_tag: bool,
+) {
+    Logger::info("");
+    Logger::info(t!("version.plan_details"));
+    Logger::info("───────────────────────────────────────");
+
+    let name_width = plan.bumps.iter().map(|bump| bump.name.len()).max().unwrap_or(0);
+    let version_width = plan.bumps.iter().map(|bump| bump.current.len()).max().unwrap_or(0);
+    for bump in &plan.bumps {
+        let reason = match &bump.reason {
+            BumpReason::Selected => t!("version.reason.selected"),
+            BumpReason::Group(source) => tf!("version.reason.group", source),
+            BumpReason::Dependency(source) => tf!("version.reason.dependency", source),
+        };
+        let name = format!("{:<name_width$}", bump.name);
+        Logger::info(format!(
+            "  {}  {:>version_width$} → {}  {}",
+            Colors::info(&name),
+            bump.current,
+            Colors::green(&bump.next),
+            reason
+        ));
+    }
+
+    if !plan.ranges.is_empty() {
+        Logger::info("");
+        Logger::info(t!("version.ranges_header"));
+        for range in &plan.ranges {
+            Logger::info(tf!(
+                "version.range_line",
+                range.package,
+                range.dependency,
+                range.dep_type,
+                Colors::red(&range.old_spec),
+                Colors::green(&range.new_spec)
+            ));
+        }
+    }
+
+    if !changes.is_empty() {
+        Logger::info("");
+        Logger::info(t!("version.changes_header"));
+        for change in changes {
+            let summary = change.summary.lines().next().unwrap_or_default();
+            Logger::info(format!("  {}  {}", Colors::info(&change.id), summary));
+        }
+    }
+
+    Logger::info("");
+    if with_commit {
+        Logger::info(t!("version.will_commit"));
+    }
+    if with_tag {
+        Logger::info(tf!("version.will_tag", plan.tags.join(", ")));
+    }
+    Logger::info(tf!("version.total", plan.bumps.len(), plan.ranges.len()));
+}
+
+/// 确认发布操作
+fn confirm_version() -> Result<bool> {
+    print!("{} ", t!("version.confirm_prompt"));
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+
+    let input = input.trim().to_lowercase();
+    Ok(input == "y" || input == "yes" || input == "是" || input == "确认")
+}
@@ -19,7 +19,7 @@
 // 1. 扫描 monorepo 目录下所有 package.json 文件
 // 2. 解析包信息和依赖关系，构建工作区包字典
 // 3. 使用拓扑排序计算构建阶段
-// 4. 使用 Tarjan 算法检测强连通分量（循环依赖）
+// 4. 使用 Tarjan 算法检测强连通分量（循环依赖），在分量内枚举基本环并计算最少断开的依赖
 //
 // ============================================================================
 
@@ -29,15 +29,17 @@ use anyhow::{Context, Result};
 use petgraph::algo::tarjan_scc;
 use petgraph::graph::{DiGraph, NodeIndex};
 use serde_json;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::time::Instant;
 use walkdir::WalkDir;
 
-use crate::models::config::Config;
+use crate::core::cycles;
+use crate::models::config::{matches_any, Config};
 use crate::models::package::{
-    AnalysisStatistics, DependencyAnalysisResult, PackageJson, WorkspacePackage,
+    AnalysisStatistics, CircularDependency, DependencyAnalysisResult, DependencyType, PackageJson,
+    WorkspacePackage,
 };
 use crate::utils::logger::Logger;
 use crate::{t, tf};
@@ -48,12 +50,28 @@ pub struct DependencyAnalyzer {
     workspace_root: PathBuf,
     /// 是否启用详细日志
     verbose: bool,
+    /// 决定构建顺序的依赖类型（为空表示全部）
+    build_types: Vec<DependencyType>,
+    /// 不参与构建排序的依赖边（依赖方 -> 被依赖的包）
+    ignore_edges: BTreeMap<String, Vec<String>>,
+    /// 是否允许循环依赖（同一循环中的包放在同一阶段）
+    allow_cycles: bool,
+    /// 配置中的包标签（包名通配符 -> 标签）
+    package_tags: BTreeMap<String, Vec<String>>,
 }
 
 impl DependencyAnalyzer {
     /// 创建新的依赖分析器
     pub fn new(workspace_root: PathBuf) -> Self {
-        Self { workspace_root, verbose: false }
+        let workspace = Config::get_workspace_config();
+        Self {
+            workspace_root,
+            verbose: false,
+            build_types: workspace.build_dependency_types,
+            ignore_edges: workspace.ignore_edges,
+            allow_cycles: false,
+            package_tags: Config::get_boundaries_config().tags,
+        }
     }
 
     /// 启用详细日志
@@ -62,6 +80,12 @@ impl DependencyAnalyzer {
         self
     }
 
+    /// 允许循环依赖：同一循环中的包作为一个整体排入同一阶段，而不是不计算构建阶段
+    pub fn with_allow_cycles(mut self, allow_cycles: bool) -> Self {
+        self.allow_cycles = allow_cycles;
+        self
+    }
+
     /// 分析工作区依赖关系
     pub fn analyze_workspace(&mut self) -> Result<DependencyAnalysisResult> {
         let start_time = Instant::now();
@@ -80,21 +104,18 @@ impl DependencyAnalyzer {
         // 2. 分析工作区依赖关系
         self.analyze_workspace_dependencies(&mut packages);
 
-        // 3. 构建依赖图
-        let (graph, node_map) = self.build_dependency_graph(&packages)?;
+        // 3. 构建依赖图（只包含决定构建顺序的依赖）
+        let (graph, node_map) = self.build_dependency_graph(&packages, true)?;
 
         // 4. 检测循环依赖
-        let circular_dependencies = self.detect_circular_dependencies(&graph, &node_map);
+        let cycle_details = self.detect_circular_dependencies(&packages, &graph, &node_map);
+        let circular_dependencies: Vec<Vec<String>> =
+            cycle_details.iter().map(|cycle| cycle.packages.clone()).collect();
+        let ignored_circular_dependencies =
+            self.detect_ignored_circular_dependencies(&packages, &circular_dependencies)?;
 
         // 5. 计算构建阶段
-        let stages = if circular_dependencies.is_empty() {
-            self.calculate_build_stages(&packages)
-        } else {
-            if self.verbose {
-                Logger::info(t!("analyze.circular_detected"));
-            }
-            Vec::new()
-        };
+        let stages = self.schedule(&packages, &circular_dependencies)?;
 
         let analysis_duration = start_time.elapsed().as_millis() as u64;
 
@@ -114,7 +135,14 @@ impl DependencyAnalyzer {
             Logger::info(tf!("analyze.completed", analysis_duration, stages.len()));
         }
 
-        Ok(DependencyAnalysisResult { packages, stages, circular_dependencies, statistics })
+        Ok(DependencyAnalysisResult {
+            packages,
+            stages,
+            circular_dependencies,
+            ignored_circular_dependencies,
+            cycle_details,
+            statistics,
+        })
     }
 
     /// 扫描工作区中的所有包
@@ -187,24 +215,42 @@ impl DependencyAnalyzer {
         // 使用目录名作为后备包名
         let fallback_name = package_dir.file_name().and_then(|n| n.to_str()).unwrap_or("unknown");
 
-        let package = WorkspacePackage::new(
+        let mut package = WorkspacePackage::new(
             package_json.get_name(fallback_name),
             relative_path,
             package_dir.to_path_buf(),
             package_json.get_version(),
             package_json.get_all_dependencies(),
-            package_json.scripts,
+            package_json.scripts.clone(),
         );
+        package.dependency_types = package_json.get_dependency_types();
+        let tags = package_json.get_monox_tags().unwrap_or_else(|| {
+            Logger::warn(tf!("analyze.invalid_monox_tags", package_json_path.display()));
+            Vec::new()
+        });
+        package.tags = self.tags_of(&package.name, tags);
 
         Ok(package)
     }
 
+    /// 合并 package.json 中的标签和配置中匹配包名的标签（去重排序）
+    fn tags_of(&self, name: &str, mut tags: Vec<String>) -> Vec<String> {
+        for (pattern, pattern_tags) in &self.package_tags {
+            if matches_any(std::slice::from_ref(pattern), name) {
+                tags.extend(pattern_tags.iter().cloned());
+            }
+        }
+        tags.sort();
+        tags.dedup();
+        tags
+    }
+
     /// 分析工作区内的依赖关系
     fn analyze_workspace_dependencies(&self, packages: &mut [WorkspacePackage]) {
         // 创建包名到索引的映射
         let package_names: HashSet<String> = packages.iter().map(|p| p.name.clone()).collect();
 
-        // 为每个包标记工作区依赖
+        // 为每个包标记工作区依赖，并按依赖类型区分是否决定构建顺序
         for package in packages.iter_mut() {
             let workspace_deps: Vec<String> = package
                 .dependencies
@@ -214,15 +260,49 @@ impl DependencyAnalyzer {
                 .collect();
 
             for dep_name in workspace_deps {
+                if self.orders_builds(package.dependency_types_of(&dep_name))
+                    && !self.is_ignored_edge(&package.name, &dep_name)
+                {
+                    package.build_dependencies.insert(dep_name.clone());
+                }
                 package.add_workspace_dependency(dep_name);
             }
         }
+
+        // 配置中不存在的依赖边多半是拼写错误，始终提示（写到标准错误，不影响 JSON 输出）
+        for (from, deps) in &self.ignore_edges {
+            for to in deps {
+                let exists = packages
+                    .iter()
+                    .any(|p| p.name == *from && p.workspace_dependencies.contains(to));
+                if !exists {
+                    eprintln!(
+                        "{} {}",
+                        Logger::get_prefix("WARN"),
+                        tf!("analyze.unknown_ignore_edge", from, to)
+                    );
+                }
+            }
+        }
     }
 
-    /// 构建依赖图
+    /// 依赖边是否在 [workspace.ignore_edges] 中
+    fn is_ignored_edge(&self, from: &str, to: &str) -> bool {
+        self.ignore_edges.get(from).is_some_and(|deps| deps.iter().any(|dep| dep == to))
+    }
+
+    /// 依赖类型是否决定构建顺序（同一依赖出现在多个字段时任一类型匹配即可）
+    fn orders_builds(&self, types: &[DependencyType]) -> bool {
+        self.build_types.is_empty()
+            || types.is_empty()
+            || types.iter().any(|dep_type| self.build_types.contains(dep_type))
+    }
+
+    /// 构建依赖图（build_only 为 true 时只包含决定构建顺序的依赖）
     fn build_dependency_graph(
         &self,
         packages: &[WorkspacePackage],
+        build_only: bool,
     ) -> Result<(DiGraph<String, ()>, HashMap<String, NodeIndex>)> {
         let mut graph = DiGraph::new();
         let mut node_map = HashMap::new();
@@ -236,8 +316,13 @@ impl DependencyAnalyzer {
         // 添加依赖边
         for package in packages {
             let package_node = node_map[&package.name];
+            let deps = if build_only {
+                &package.build_dependencies
+            } else {
+                &package.workspace_dependencies
+            };
 
-            for dep_name in &package.workspace_dependencies {
+            for dep_name in deps {
                 if let Some(&dep_node) = node_map.get(dep_name) {
                     // 添加从被依赖包到依赖包的边（dep_name -> package.name）
                     graph.add_edge(dep_node, package_node, ());
@@ -248,40 +333,87 @@ impl DependencyAnalyzer {
         Ok((graph, node_map))
     }
 
-    /// 检测循环依赖
+    /// 检测循环依赖，并在每组互相依赖的包中找出基本环
     fn detect_circular_dependencies(
         &self,
+        packages: &[WorkspacePackage],
         graph: &DiGraph<String, ()>,
         _node_map: &HashMap<String, NodeIndex>,
-    ) -> Vec<Vec<String>> {
-        let mut circular_deps = Vec::new();
-
-        // 使用 Tarjan's 强连通分量算法
-        let sccs = tarjan_scc(graph);
-
-        for scc in sccs {
-            // 只有包含多个节点的强连通分量才是循环依赖
-            if scc.len() > 1 {
-                let cycle: Vec<String> =
-                    scc.iter().map(|&node_idx| graph[node_idx].clone()).collect();
-                circular_deps.push(cycle);
-            }
-        }
+    ) -> Vec<CircularDependency> {
+        let circular_deps =
+            cycles::describe_cycles(packages, &strongly_connected_components(graph));
 
         if self.verbose && !circular_deps.is_empty() {
             Logger::info(tf!("analyze.circular_found", circular_deps.len()));
-            for (i, cycle) in circular_deps.iter().enumerate() {
-                Logger::info(tf!("analyze.circular_detail", i + 1, cycle.join(" -> ")));
+            for (i, circular) in circular_deps.iter().enumerate() {
+                for cycle in &circular.cycles {
+                    Logger::info(tf!("analyze.circular_detail", i + 1, cycle.path()));
+                }
             }
         }
 
         circular_deps
     }
 
-    /// 计算构建阶段（基于拓扑排序）
-    fn calculate_build_stages(&self, packages: &[WorkspacePackage]) -> Vec<Vec<WorkspacePackage>> {
+    /// 检测只经过不决定构建顺序的依赖的循环依赖（与构建循环没有共同的包）
+    fn detect_ignored_circular_dependencies(
+        &self,
+        packages: &[WorkspacePackage],
+        circular_dependencies: &[Vec<String>],
+    ) -> Result<Vec<Vec<String>>> {
+        if self.build_types.is_empty() && self.ignore_edges.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let blocking: HashSet<&String> = circular_dependencies.iter().flatten().collect();
+        let (graph, _) = self.build_dependency_graph(packages, false)?;
+        Ok(strongly_connected_components(&graph)
+            .into_iter()
+            .filter(|cycle| cycle.iter().all(|name| !blocking.contains(name)))
+            .collect())
+    }
+
+    /// 计算包的构建阶段：包中存在循环依赖且未允许时不计算（返回空）
+    fn schedule(
+        &self,
+        packages: &[WorkspacePackage],
+        circular_dependencies: &[Vec<String>],
+    ) -> Result<Vec<Vec<WorkspacePackage>>> {
+        let names: HashSet<&str> = packages.iter().map(|p| p.name.as_str()).collect();
+        let cycles: Vec<Vec<String>> = circular_dependencies
+            .iter()
+            .map(|cycle| cycle.iter().filter(|name| names.contains(name.as_str())).cloned())
+            .map(|cycle| cycle.collect::<Vec<_>>())
+            .filter(|cycle| cycle.len() > 1)
+            .collect();
+
+        if !cycles.is_empty() && !self.allow_cycles {
+            if self.verbose {
+                Logger::info(t!("analyze.circular_detected"));
+            }
+            return Ok(Vec::new());
+        }
+        self.calculate_build_stages(packages, &cycles)
+    }
+
+    /// 计算构建阶段（基于拓扑排序，同一循环依赖中的包作为整体放入同一阶段）
+    ///
+    /// 剩余的包都无法安排时返回错误并列出这些包，而不是只返回已安排的阶段。
+    fn calculate_build_stages(
+        &self,
+        packages: &[WorkspacePackage],
+        cycles: &[Vec<String>],
+    ) -> Result<Vec<Vec<WorkspacePackage>>> {
         let mut stages = Vec::new();
 
+        // 包名到所在循环依赖的映射
+        let cycle_of: HashMap<&str, usize> = cycles
+            .iter()
+            .enumerate()
+            .flat_map(|(index, cycle)| cycle.iter().map(move |name| (name.as_str(), index)))
+            .collect();
+        let same_cycle = |a: &str, b: &str| matches!((cycle_of.get(a), cycle_of.get(b)), (Some(x), Some(y)) if x == y);
+
         // 创建包名到包的映射
         let package_map: HashMap<String, WorkspacePackage> =
             packages.iter().map(|p| (p.name.clone(), p.clone())).collect();
@@ -294,30 +426,32 @@ impl DependencyAnalyzer {
             let mut current_stage = Vec::new();
             let mut packages_to_remove = Vec::new();
 
-            // 寻找可以在当前阶段构建的包
-            for package_name in &unstaged_packages {
-                let package = &package_map[package_name];
-
-                // 检查是否所有工作区依赖都已在前面的阶段中
-                let can_build_now = package
-                    .workspace_dependencies
-                    .iter()
-                    .all(|dep| !unstaged_packages.contains(dep));
+            // 寻找可以在当前阶段构建的包（同一循环中的依赖不需要等待）
+            let ready: HashSet<&String> = unstaged_packages
+                .iter()
+                .filter(|package_name| {
+                    package_map[*package_name].build_dependencies.iter().all(|dep| {
+                        !unstaged_packages.contains(dep) || same_cycle(package_name, dep)
+                    })
+                })
+                .collect();
 
-                if can_build_now {
-                    current_stage.push(package.clone());
-                    packages_to_remove.push(package_name.clone());
+            // 循环中的包只有全部就绪时才一起构建
+            for package_name in &ready {
+                let cycle_ready = cycle_of
+                    .get(package_name.as_str())
+                    .is_none_or(|&index| cycles[index].iter().all(|member| ready.contains(member)));
+                if cycle_ready {
+                    current_stage.push(package_map[*package_name].clone());
+                    packages_to_remove.push((*package_name).clone());
                 }
             }
 
             if current_stage.is_empty() {
-                // 如果没有包可以构建，说明存在循环依赖
-                if self.verbose {
-                    let remaining_packages: Vec<String> =
-                        unstaged_packages.iter().cloned().collect();
-                    Logger::info(tf!("analyze.circular_warning", remaining_packages.join(", ")));
-                }
-                break;
+                // 没有包可以构建，说明存在未被识别的循环依赖（如包依赖自身）
+                let mut remaining_packages: Vec<String> = unstaged_packages.into_iter().collect();
+                remaining_packages.sort();
+                anyhow::bail!(tf!("analyze.unschedulable", remaining_packages.join(", ")));
             }
 
             // 从未分配列表中移除当前阶段的包
@@ -337,7 +471,7 @@ impl DependencyAnalyzer {
             stages.push(current_stage);
         }
 
-        stages
+        Ok(stages)
     }
 
     /// 分析单个包（通过包名）
@@ -374,24 +508,7 @@ impl DependencyAnalyzer {
         let related_packages = self.get_related_packages(&target_package, &full_result.packages);
 
         // 4. 重新计算相关包的构建阶段
-        let stages = if full_result.circular_dependencies.is_empty() {
-            self.calculate_build_stages(&related_packages)
-        } else {
-            // 检查循环依赖是否涉及目标包
-            let target_in_cycle = full_result
-                .circular_dependencies
-                .iter()
-                .any(|cycle| cycle.contains(&target_package.name));
-
-            if target_in_cycle {
-                if self.verbose {
-                    Logger::info(t!("analyze.circular_detected"));
-                }
-                Vec::new()
-            } else {
-                self.calculate_build_stages(&related_packages)
-            }
-        };
+        let stages = self.schedule(&related_packages, &full_result.circular_dependencies)?;
 
         let analysis_duration = start_time.elapsed().as_millis() as u64;
 
@@ -417,6 +534,8 @@ impl DependencyAnalyzer {
             packages: vec![target_package],
             stages,
             circular_dependencies: full_result.circular_dependencies,
+            ignored_circular_dependencies: full_result.ignored_circular_dependencies,
+            cycle_details: full_result.cycle_details,
             statistics,
         })
     }
@@ -455,7 +574,7 @@ impl DependencyAnalyzer {
         related_packages: &mut Vec<WorkspacePackage>,
         visited: &mut HashSet<String>,
     ) {
-        for dep_name in &package.workspace_dependencies {
+        for dep_name in &package.build_dependencies {
             if !visited.contains(dep_name) {
                 if let Some(dep_package) = all_packages.iter().find(|p| p.name == *dep_name) {
                     visited.insert(dep_name.clone());
@@ -530,23 +649,7 @@ impl DependencyAnalyzer {
         }
 
         // 5. 重新计算相关包的构建阶段
-        let stages = if full_result.circular_dependencies.is_empty() {
-            self.calculate_build_stages(&related_packages)
-        } else {
-            // 检查循环依赖是否涉及任何目标包
-            let targets_in_cycle = target_packages.iter().any(|target| {
-                full_result.circular_dependencies.iter().any(|cycle| cycle.contains(&target.name))
-            });
-
-            if targets_in_cycle {
-                if self.verbose {
-                    Logger::info(t!("analyze.circular_detected"));
-                }
-                Vec::new()
-            } else {
-                self.calculate_build_stages(&related_packages)
-            }
-        };
+        let stages = self.schedule(&related_packages, &full_result.circular_dependencies)?;
 
         let analysis_duration = start_time.elapsed().as_millis() as u64;
 
@@ -575,7 +678,49 @@ impl DependencyAnalyzer {
             packages: target_packages,
             stages,
             circular_dependencies: full_result.circular_dependencies,
+            ignored_circular_dependencies: full_result.ignored_circular_dependencies,
+            cycle_details: full_result.cycle_details,
             statistics,
         })
     }
 }
+
+/// 包含多个节点的强连通分量（Tarjan 算法），即循环依赖
+fn strongly_connected_components(graph: &DiGraph<String, ()>) -> Vec<Vec<String>> {
+    tarjan_scc(graph)
+        .into_iter()
+        .filter(|scc| scc.len() > 1)
+        .map(|scc| scc.iter().map(|&node_idx| graph[node_idx].clone()).collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_package(root: &Path, name: &str, dependencies: serde_json::Value) {
+        let dir = root.join("packages").join(name);
+        fs::create_dir_all(&dir).unwrap();
+        let manifest =
+            serde_json::json!({ "name": name, "version": "1.0.0", "dependencies": dependencies });
+        fs::write(dir.join("package.json"), manifest.to_string()).unwrap();
+    }
+
+    #[test]
+    fn reports_packages_that_cannot_be_scheduled() {
+        let root = tempfile::tempdir().unwrap();
+        let manifest =
+            serde_json::json!({ "name": "root", "private": true, "workspaces": ["packages/*"] });
+        fs::write(root.path().join("package.json"), manifest.to_string()).unwrap();
+        write_package(root.path(), "core", serde_json::json!({}));
+        write_package(root.path(), "self-ref", serde_json::json!({ "self-ref": "workspace:*" }));
+        write_package(root.path(), "app", serde_json::json!({ "self-ref": "workspace:*" }));
+
+        let error = DependencyAnalyzer::new(root.path().to_path_buf())
+            .analyze_workspace()
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("app, self-ref"), "{}", error);
+        assert!(!error.contains("core"), "{}", error);
+    }
+}
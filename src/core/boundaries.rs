@@ -0,0 +1,377 @@
+// ============================================================================
+// MonoX - 架构边界检查
+// ============================================================================
+//
+// 文件: src/core/boundaries.rs
+// 职责: 按 [boundaries] 中的规则检查工作区内部依赖
+// 边界:
+//   - ✅ 选择器解析（标签、path:、name:，支持通配符和 ! 取反）
+//   - ✅ 规则匹配和违规收集（每条依赖、每条规则一条记录）
+//   - ✅ 规则中未被任何包使用的标签
+//   - ❌ 不应包含包扫描和标签合并逻辑
+//   - ❌ 不应包含输出格式化
+//
+// ============================================================================
+
+use anyhow::Result;
+use glob::{MatchOptions, Pattern};
+use std::collections::{BTreeSet, HashMap};
+
+use crate::models::boundary::{BoundaryReport, BoundaryViolation, BoundaryViolationKind};
+use crate::models::config::{BoundariesConfig, BoundaryRule};
+use crate::models::package::{DependencyType, WorkspacePackage};
+use crate::{t, tf};
+
+/// 选择器匹配的包属性
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SelectorTarget {
+    /// 包标签
+    Tag,
+    /// 包的相对路径
+    Path,
+    /// 包名
+    Name,
+}
+
+/// 包选择器
+struct Selector {
+    /// 配置中的原始写法
+    source: String,
+    /// 匹配的属性
+    target: SelectorTarget,
+    /// 通配符模式
+    pattern: Pattern,
+    /// 是否取反
+    negated: bool,
+}
+
+/// 解析后的规则
+struct CompiledRule {
+    /// 规则名称
+    name: String,
+    /// 适用的依赖方
+    from: Vec<Selector>,
+    /// 适用的被依赖包
+    to: Vec<Selector>,
+    /// 适用的依赖类型
+    types: Vec<DependencyType>,
+    /// 允许依赖的包
+    allow: Vec<Selector>,
+    /// 禁止依赖的包
+    deny: Vec<Selector>,
+}
+
+/// 检查所有工作区内部依赖是否符合架构边界规则
+pub fn check_boundaries(
+    packages: &[WorkspacePackage],
+    config: &BoundariesConfig,
+) -> Result<BoundaryReport> {
+    let rules = config
+        .rules
+        .iter()
+        .enumerate()
+        .map(|(index, rule)| CompiledRule::parse(index, rule))
+        .collect::<Result<Vec<_>>>()?;
+
+    let by_name: HashMap<&str, &WorkspacePackage> =
+        packages.iter().map(|p| (p.name.as_str(), p)).collect();
+    let mut sorted: Vec<&WorkspacePackage> = packages.iter().collect();
+    sorted.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut violations = Vec::new();
+    let mut dependencies_checked = 0;
+    for package in sorted {
+        let mut deps: Vec<&String> = package.workspace_dependencies.iter().collect();
+        deps.sort();
+        for dep in deps {
+            let Some(dependency) = by_name.get(dep.as_str()) else {
+                continue;
+            };
+            dependencies_checked += 1;
+            let types = package.dependency_types_of(dep);
+            for rule in &rules {
+                if let Some(violation) = rule.check(package, dependency, types) {
+                    violations.push(violation);
+                }
+            }
+        }
+    }
+
+    Ok(BoundaryReport {
+        rules: rules.len(),
+        dependencies_checked,
+        unknown_tags: unknown_tags(&rules, packages),
+        violations,
+    })
+}
+
+/// 规则中引用了但没有任何包使用的标签
+fn unknown_tags(rules: &[CompiledRule], packages: &[WorkspacePackage]) -> Vec<String> {
+    let selectors = rules
+        .iter()
+        .flat_map(|rule| rule.from.iter().chain(&rule.to).chain(&rule.allow).chain(&rule.deny));
+
+    let mut unknown = BTreeSet::new();
+    for selector in selectors {
+        if selector.target == SelectorTarget::Tag
+            && !packages.iter().any(|p| p.tags.iter().any(|tag| selector.pattern.matches(tag)))
+        {
+            unknown.insert(selector.pattern.as_str().to_string());
+        }
+    }
+    unknown.into_iter().collect()
+}
+
+impl CompiledRule {
+    /// 解析配置中的规则（第 index 条）
+    fn parse(index: usize, rule: &BoundaryRule) -> Result<Self> {
+        let name = if rule.name.is_empty() {
+            tf!("check.boundaries.rule_number", index + 1).to_string()
+        } else {
+            rule.name.clone()
+        };
+        if rule.allow.is_empty() && rule.deny.is_empty() {
+            anyhow::bail!(tf!("check.boundaries.empty_rule", name));
+        }
+
+        let parse_all = |sources: &[String]| -> Result<Vec<Selector>> {
+            sources.iter().map(|source| Selector::parse(source, &name)).collect()
+        };
+        Ok(Self {
+            from: parse_all(&rule.from)?,
+            to: parse_all(&rule.to)?,
+            types: rule.types.clone(),
+            allow: parse_all(&rule.allow)?,
+            deny: parse_all(&rule.deny)?,
+            name,
+        })
+    }
+
+    /// 检查一条依赖，违反规则时返回违规记录
+    fn check(
+        &self,
+        package: &WorkspacePackage,
+        dependency: &WorkspacePackage,
+        types: &[DependencyType],
+    ) -> Option<BoundaryViolation> {
+        if !matches_or_empty(&self.from, package) || !matches_or_empty(&self.to, dependency) {
+            return None;
+        }
+        if !self.types.is_empty() && !types.iter().any(|t| self.types.contains(t)) {
+            return None;
+        }
+
+        let denied: Vec<String> = self
+            .deny
+            .iter()
+            .filter(|selector| selector.matches(dependency))
+            .map(|selector| selector.source.clone())
+            .collect();
+        let (kind, selectors) = if !denied.is_empty() {
+            (BoundaryViolationKind::Denied, denied)
+        } else if !self.allow.is_empty() && !self.allow.iter().any(|s| s.matches(dependency)) {
+            let allowed = self.allow.iter().map(|selector| selector.source.clone()).collect();
+            (BoundaryViolationKind::NotAllowed, allowed)
+        } else {
+            return None;
+        };
+
+        Some(BoundaryViolation {
+            package: package.name.clone(),
+            package_tags: package.tags.clone(),
+            dependency: dependency.name.clone(),
+            dependency_tags: dependency.tags.clone(),
+            types: types.to_vec(),
+            rule: self.name.clone(),
+            kind,
+            selectors,
+        })
+    }
+}
+
+/// 选择器列表为空或任一选择器匹配
+fn matches_or_empty(selectors: &[Selector], package: &WorkspacePackage) -> bool {
+    selectors.is_empty() || selectors.iter().any(|selector| selector.matches(package))
+}
+
+impl Selector {
+    /// 解析选择器（rule 为所在规则的名称，用于错误提示）
+    fn parse(source: &str, rule: &str) -> Result<Self> {
+        let trimmed = source.trim();
+        let (negated, body) = match trimmed.strip_prefix('!') {
+            Some(body) => (true, body.trim()),
+            None => (false, trimmed),
+        };
+        let (target, pattern) = if let Some(path) = body.strip_prefix("path:") {
+            (SelectorTarget::Path, path.trim().trim_start_matches("./").trim_end_matches('/'))
+        } else if let Some(name) = body.strip_prefix("name:") {
+            (SelectorTarget::Name, name.trim())
+        } else {
+            (SelectorTarget::Tag, body)
+        };
+
+        if pattern.is_empty() {
+            anyhow::bail!(tf!(
+                "check.boundaries.invalid_selector",
+                source,
+                rule,
+                t!("check.boundaries.empty_pattern")
+            ));
+        }
+        let pattern = Pattern::new(pattern).map_err(|e| {
+            anyhow::anyhow!(tf!("check.boundaries.invalid_selector", source, rule, e))
+        })?;
+
+        Ok(Self { source: trimmed.to_string(), target, pattern, negated })
+    }
+
+    /// 判断包是否匹配
+    fn matches(&self, package: &WorkspacePackage) -> bool {
+        let matched = match self.target {
+            SelectorTarget::Tag => package.tags.iter().any(|tag| self.pattern.matches(tag)),
+            SelectorTarget::Name => self.pattern.matches(&package.name),
+            SelectorTarget::Path => {
+                let options =
+                    MatchOptions { require_literal_separator: true, ..Default::default() };
+                let folder = package.folder.to_string_lossy().replace('\\', "/");
+                // "packages/core/**" 同时匹配 packages/core 本身
+                self.pattern.matches_with(&folder, options)
+                    || self.pattern.matches_with(&format!("{}/", folder), options)
+            }
+        };
+        matched != self.negated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn package(name: &str, folder: &str, tags: &[&str]) -> WorkspacePackage {
+        WorkspacePackage {
+            name: name.to_string(),
+            folder: folder.into(),
+            absolute_path: folder.into(),
+            version: "1.0.0".to_string(),
+            dependencies: HashMap::new(),
+            dependency_types: HashMap::new(),
+            workspace_dependencies: Default::default(),
+            build_dependencies: Default::default(),
+            scripts: HashMap::new(),
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+        }
+    }
+
+    fn selector(source: &str) -> Selector {
+        Selector::parse(source, "test").unwrap()
+    }
+
+    fn rule(allow: &[&str], deny: &[&str], types: &[DependencyType]) -> CompiledRule {
+        let strings = |values: &[&str]| values.iter().map(|v| v.to_string()).collect();
+        CompiledRule::parse(
+            0,
+            &BoundaryRule {
+                name: "ui".to_string(),
+                from: vec!["ui".to_string()],
+                to: Vec::new(),
+                types: types.to_vec(),
+                allow: strings(allow),
+                deny: strings(deny),
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn parses_selector_targets() {
+        let tag = selector(" scope:* ");
+        assert_eq!(
+            (tag.target, tag.pattern.as_str(), tag.negated),
+            (SelectorTarget::Tag, "scope:*", false)
+        );
+
+        let path = selector("path:./packages/core/");
+        assert_eq!((path.target, path.pattern.as_str()), (SelectorTarget::Path, "packages/core"));
+
+        let name = selector("! name:@acme/*");
+        assert_eq!(
+            (name.target, name.pattern.as_str(), name.negated),
+            (SelectorTarget::Name, "@acme/*", true)
+        );
+        assert_eq!(name.source, "! name:@acme/*");
+
+        assert!(Selector::parse("path:", "test").is_err());
+        assert!(Selector::parse("!", "test").is_err());
+        assert!(Selector::parse("name:[", "test").is_err());
+    }
+
+    #[test]
+    fn matches_tags_names_and_negation() {
+        let core = package("@acme/core", "packages/core", &["scope:shared", "type:lib"]);
+        let app = package("web", "apps/web", &["type:app"]);
+
+        assert!(selector("scope:*").matches(&core));
+        assert!(!selector("scope:*").matches(&app));
+        assert!(selector("name:@acme/*").matches(&core));
+        assert!(!selector("name:@acme/*").matches(&app));
+        assert!(!selector("!type:lib").matches(&core));
+        assert!(selector("!type:lib").matches(&app));
+    }
+
+    #[test]
+    fn matches_paths_including_the_folder_itself() {
+        let core = package("core", "packages/core", &[]);
+        let nested = package("core-utils", "packages/core/utils", &[]);
+        let other = package("core-next", "packages/core-next", &[]);
+
+        let recursive = selector("path:packages/core/**");
+        assert!(recursive.matches(&core));
+        assert!(recursive.matches(&nested));
+        assert!(!recursive.matches(&other));
+
+        // * 不跨越路径分隔符
+        let single = selector("path:packages/*");
+        assert!(single.matches(&core));
+        assert!(!single.matches(&nested));
+
+        // Windows 路径分隔符统一为 /
+        let windows = package("core", "packages\\core", &[]);
+        assert!(selector("path:packages/core").matches(&windows));
+    }
+
+    #[test]
+    fn deny_takes_precedence_over_allow() {
+        let ui = package("button", "packages/button", &["ui"]);
+        let data = package("store", "packages/store", &["data", "ui"]);
+        let prod = [DependencyType::Prod];
+
+        let violation = rule(&["ui"], &["data"], &[]).check(&ui, &data, &prod).unwrap();
+        assert_eq!(violation.kind, BoundaryViolationKind::Denied);
+        assert_eq!(violation.selectors, ["data"]);
+        assert_eq!(violation.rule, "ui");
+
+        let util = package("fmt", "packages/fmt", &["util"]);
+        let violation = rule(&["ui", "shared"], &[], &[]).check(&ui, &util, &prod).unwrap();
+        assert_eq!(violation.kind, BoundaryViolationKind::NotAllowed);
+        assert_eq!(violation.selectors, ["ui", "shared"]);
+
+        let button = package("icon", "packages/icon", &["ui"]);
+        assert!(rule(&["ui"], &["data"], &[]).check(&ui, &button, &prod).is_none());
+    }
+
+    #[test]
+    fn skips_dependencies_outside_the_rule_scope() {
+        let ui = package("button", "packages/button", &["ui"]);
+        let app = package("web", "apps/web", &["app"]);
+        let data = package("store", "packages/store", &["data"]);
+
+        // 依赖方不匹配 from
+        assert!(rule(&[], &["data"], &[]).check(&app, &data, &[DependencyType::Prod]).is_none());
+        // 依赖类型不在 types 中
+        let prod_only = rule(&[], &["data"], &[DependencyType::Prod]);
+        assert!(prod_only.check(&ui, &data, &[DependencyType::Dev]).is_none());
+        assert!(prod_only
+            .check(&ui, &data, &[DependencyType::Dev, DependencyType::Prod])
+            .is_some());
+    }
+}
@@ -0,0 +1,179 @@
+// ============================================================================
+// MonoX - CHANGELOG 生成
+// ============================================================================
+//
+// 文件: src/core/changelog.rs
+// 职责: 根据版本发布方案和变更文件生成各包 CHANGELOG.md 的新段落
+// 边界:
+//   - ✅ 按升级类型归类变更摘要，列出更新的工作区依赖
+//   - ✅ 将新段落插入到已有 CHANGELOG.md 的标题之后（由调用方与 package.json 一起写入）
+//   - ❌ 不应包含版本计算逻辑
+//   - ❌ 不应包含变更文件的读写
+//   - ❌ 不应包含 CLI 输出逻辑
+//
+// ============================================================================
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use crate::core::changes::{ChangeFile, ChangeType};
+use crate::core::versioner::{BumpReason, VersionPlan};
+use crate::tf;
+use crate::utils::json_edit::TextFile;
+
+/// CHANGELOG 文件名
+const CHANGELOG_FILE: &str = "CHANGELOG.md";
+
+/// 各升级类型的小节标题（按显示顺序）
+const SECTION_TITLES: &[(ChangeType, &str)] = &[
+    (ChangeType::Major, "Major Changes"),
+    (ChangeType::Minor, "Minor Changes"),
+    (ChangeType::Patch, "Patch Changes"),
+];
+
+/// 一个包的 CHANGELOG 新段落
+#[derive(Debug, Clone, Serialize)]
+pub struct ChangelogSection {
+    /// 包名
+    pub package: String,
+    /// 新版本
+    pub version: String,
+    /// 段落内容（Markdown）
+    pub content: String,
+    /// CHANGELOG.md 路径
+    #[serde(skip)]
+    pub path: PathBuf,
+}
+
+/// 为方案中每个升级的包生成 CHANGELOG 段落
+pub fn build_sections(plan: &VersionPlan, changes: &[ChangeFile]) -> Vec<ChangelogSection> {
+    plan.bumps
+        .iter()
+        .map(|bump| {
+            // 变更文件中声明了该包的摘要
+            let mut entries: Vec<(ChangeType, String)> = changes
+                .iter()
+                .filter_map(|change| {
+                    let release = change.releases.iter().find(|r| r.package == bump.name)?;
+                    Some((release.bump, change.summary.clone()))
+                })
+                .filter(|(_, summary)| !summary.is_empty())
+                .collect();
+
+            // 版本范围被改写或因依赖升级而发布时，列出更新的依赖
+            let mut updated: BTreeSet<&str> = plan
+                .ranges
+                .iter()
+                .filter(|range| range.package == bump.name)
+                .map(|range| range.dependency.as_str())
+                .collect();
+            if let BumpReason::Dependency(source) = &bump.reason {
+                updated.insert(source);
+            }
+            let updated: Vec<String> = updated
+                .into_iter()
+                .filter_map(|dep| plan.bumps.iter().find(|b| b.name == dep))
+                .map(|dep| format!("- {}@{}", dep.name, dep.next))
+                .collect();
+            if !updated.is_empty() {
+                entries.push((
+                    ChangeType::Patch,
+                    format!("Updated dependencies\n{}", updated.join("\n")),
+                ));
+            }
+            if entries.is_empty() {
+                entries.push((ChangeType::Patch, "Version bump only".to_string()));
+            }
+
+            ChangelogSection {
+                package: bump.name.clone(),
+                version: bump.next.clone(),
+                content: render_section(&bump.next, &entries),
+                path: bump
+                    .manifest()
+                    .parent()
+                    .map(|dir| dir.join(CHANGELOG_FILE))
+                    .unwrap_or_else(|| PathBuf::from(CHANGELOG_FILE)),
+            }
+        })
+        .collect()
+}
+
+/// 生成插入新段落后的 CHANGELOG 文件（只在内存中修改，不写入磁盘）
+pub fn stage_sections(sections: &[ChangelogSection]) -> Result<Vec<TextFile>> {
+    let mut files = Vec::new();
+    for section in sections {
+        let mut file = TextFile::load(&section.path)
+            .with_context(|| tf!("changelog.read_failed", section.path.display()))?;
+        file.set_content(insert_section(file.content(), &section.package, &section.content));
+        files.push(file);
+    }
+    Ok(files)
+}
+
+/// 生成一个版本的段落
+fn render_section(version: &str, entries: &[(ChangeType, String)]) -> String {
+    let mut content = format!("## {}\n", version);
+    for (change_type, title) in SECTION_TITLES {
+        let items: Vec<String> = entries
+            .iter()
+            .filter(|(entry_type, _)| entry_type == change_type)
+            .map(|(_, summary)| format!("- {}", summary.lines().collect::<Vec<_>>().join("\n  ")))
+            .collect();
+        if !items.is_empty() {
+            content.push_str(&format!("\n### {}\n\n{}\n", title, items.join("\n")));
+        }
+    }
+    content
+}
+
+/// 将段落插入到一级标题之后，没有标题时以包名作为标题
+fn insert_section(existing: &str, package: &str, section: &str) -> String {
+    let trimmed = existing.trim_start();
+    if trimmed.starts_with("# ") {
+        let (title, rest) = trimmed.split_once('\n').unwrap_or((trimmed, ""));
+        let rest = rest.trim_start_matches(['\r', '\n']);
+        if rest.is_empty() {
+            return format!("{}\n\n{}", title.trim_end(), section);
+        }
+        return format!("{}\n\n{}\n{}", title.trim_end(), section, rest);
+    }
+    if trimmed.is_empty() {
+        return format!("# {}\n\n{}", package, section);
+    }
+    format!("# {}\n\n{}\n{}", package, section, trimmed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECTION: &str = "## 1.1.0\n\n### Minor Changes\n\n- Add retries\n";
+
+    #[test]
+    fn inserts_below_existing_title() {
+        let existing = "# core\n\n## 1.0.0\n\n- Initial release\n";
+        assert_eq!(
+            insert_section(existing, "core", SECTION),
+            "# core\n\n## 1.1.0\n\n### Minor Changes\n\n- Add retries\n\n## 1.0.0\n\n- Initial release\n"
+        );
+
+        // 只有标题（含 CRLF）时段落紧跟标题
+        assert_eq!(
+            insert_section("# Changelog\r\n\r\n", "core", SECTION),
+            format!("# Changelog\n\n{}", SECTION)
+        );
+    }
+
+    #[test]
+    fn adds_package_title_when_missing() {
+        assert_eq!(insert_section("", "core", SECTION), format!("# core\n\n{}", SECTION));
+        assert_eq!(insert_section("\n\n", "core", SECTION), format!("# core\n\n{}", SECTION));
+        assert_eq!(
+            insert_section("## 1.0.0\n\n- Initial release\n", "core", SECTION),
+            format!("# core\n\n{}\n## 1.0.0\n\n- Initial release\n", SECTION)
+        );
+    }
+}
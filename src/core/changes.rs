@@ -0,0 +1,275 @@
+// ============================================================================
+// MonoX - 变更意图文件
+// ============================================================================
+//
+// 文件: src/core/changes.rs
+// 职责: 读写 .monox/changes/*.md 中记录的发布意图
+// 边界:
+//   - ✅ 变更文件的格式解析和生成（front matter 列出包和升级类型，正文为摘要）
+//   - ✅ 变更文件的创建、读取和删除
+//   - ❌ 不应包含版本计算逻辑
+//   - ❌ 不应包含 CHANGELOG 生成逻辑
+//   - ❌ 不应包含用户交互
+//
+// ============================================================================
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::core::history::MONOX_DIR;
+use crate::core::versioner::BumpKind;
+use crate::tf;
+
+/// 变更文件目录名（位于 .monox 下）
+const CHANGES_DIR: &str = "changes";
+
+/// front matter 分隔线
+const FRONT_MATTER_FENCE: &str = "---";
+
+/// 变更的升级类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeType {
+    /// 补丁版本
+    Patch,
+    /// 次版本
+    Minor,
+    /// 主版本
+    Major,
+}
+
+impl ChangeType {
+    /// 解析升级类型
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "patch" => Some(ChangeType::Patch),
+            "minor" => Some(ChangeType::Minor),
+            "major" => Some(ChangeType::Major),
+            _ => None,
+        }
+    }
+
+    /// 升级类型名称
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChangeType::Patch => "patch",
+            ChangeType::Minor => "minor",
+            ChangeType::Major => "major",
+        }
+    }
+
+    /// 对应的版本升级方式
+    pub fn bump_kind(&self) -> BumpKind {
+        match self {
+            ChangeType::Patch => BumpKind::Patch,
+            ChangeType::Minor => BumpKind::Minor,
+            ChangeType::Major => BumpKind::Major,
+        }
+    }
+}
+
+/// 变更涉及的包
+#[derive(Debug, Clone, Serialize)]
+pub struct ChangeRelease {
+    /// 包名
+    pub package: String,
+    /// 升级类型
+    pub bump: ChangeType,
+}
+
+/// 一个变更文件
+#[derive(Debug, Clone, Serialize)]
+pub struct ChangeFile {
+    /// 文件名（不含扩展名）
+    pub id: String,
+    /// 涉及的包
+    pub releases: Vec<ChangeRelease>,
+    /// 变更摘要
+    pub summary: String,
+    /// 文件路径
+    #[serde(skip)]
+    pub path: PathBuf,
+}
+
+impl ChangeFile {
+    /// 解析变更文件内容
+    fn parse(id: &str, path: &Path, content: &str) -> Option<Self> {
+        let content = content.replace("\r\n", "\n");
+        let rest = content.trim_start().strip_prefix(FRONT_MATTER_FENCE)?;
+        let (header, body) = rest.split_once(&format!("\n{}", FRONT_MATTER_FENCE))?;
+
+        let mut releases = Vec::new();
+        for line in header.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let (package, bump) = line.rsplit_once(':')?;
+            let package = package.trim().trim_matches(|c| c == '"' || c == '\'');
+            releases.push(ChangeRelease {
+                package: package.to_string(),
+                bump: ChangeType::parse(bump.trim())?,
+            });
+        }
+        if releases.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            id: id.to_string(),
+            releases,
+            summary: body.trim().to_string(),
+            path: path.to_path_buf(),
+        })
+    }
+
+    /// 生成变更文件内容
+    fn render(releases: &[ChangeRelease], summary: &str) -> String {
+        let mut content = format!("{}\n", FRONT_MATTER_FENCE);
+        for release in releases {
+            content.push_str(&format!("\"{}\": {}\n", release.package, release.bump.as_str()));
+        }
+        content.push_str(&format!("{}\n\n{}\n", FRONT_MATTER_FENCE, summary.trim()));
+        content
+    }
+}
+
+/// 变更文件存储
+pub struct ChangeStore {
+    /// 变更文件目录
+    dir: PathBuf,
+}
+
+impl ChangeStore {
+    /// 打开工作区的变更文件目录
+    pub fn new(workspace_root: &Path) -> Self {
+        Self { dir: workspace_root.join(MONOX_DIR).join(CHANGES_DIR) }
+    }
+
+    /// 读取全部变更文件（按文件名排序）
+    pub fn load_all(&self) -> Result<Vec<ChangeFile>> {
+        if !self.dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut paths: Vec<PathBuf> = fs::read_dir(&self.dir)
+            .with_context(|| tf!("change.read_failed", self.dir.display()))?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "md"))
+            .collect();
+        paths.sort();
+
+        let mut changes = Vec::new();
+        for path in paths {
+            let id = path.file_stem().map(|stem| stem.to_string_lossy()).unwrap_or_default();
+            let content = fs::read_to_string(&path)
+                .with_context(|| tf!("change.read_failed", path.display()))?;
+            let Some(change) = ChangeFile::parse(&id, &path, &content) else {
+                anyhow::bail!(tf!("change.parse_failed", path.display()));
+            };
+            changes.push(change);
+        }
+        Ok(changes)
+    }
+
+    /// 写入新的变更文件，返回文件路径
+    pub fn add(&self, releases: &[ChangeRelease], summary: &str) -> Result<PathBuf> {
+        fs::create_dir_all(&self.dir)
+            .with_context(|| tf!("change.write_failed", self.dir.display()))?;
+
+        let path = self.unique_path(summary);
+        fs::write(&path, ChangeFile::render(releases, summary))
+            .with_context(|| tf!("change.write_failed", path.display()))?;
+        Ok(path)
+    }
+
+    /// 删除已消费的变更文件
+    pub fn remove(&self, changes: &[ChangeFile]) -> Result<()> {
+        for change in changes {
+            fs::remove_file(&change.path)
+                .with_context(|| tf!("change.remove_failed", change.path.display()))?;
+        }
+        Ok(())
+    }
+
+    /// 由摘要和时间生成不重复的文件路径
+    fn unique_path(&self, summary: &str) -> PathBuf {
+        let slug: String = summary
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '-' })
+            .collect();
+        let slug: Vec<&str> = slug.split('-').filter(|word| !word.is_empty()).take(6).collect();
+        let seconds =
+            SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+
+        let base = if slug.is_empty() {
+            format!("change-{}", seconds)
+        } else {
+            format!("{}-{}", slug.join("-"), seconds)
+        };
+        let mut path = self.dir.join(format!("{}.md", base));
+        let mut suffix = 1;
+        while path.exists() {
+            suffix += 1;
+            path = self.dir.join(format!("{}-{}.md", base, suffix));
+        }
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(content: &str) -> Option<ChangeFile> {
+        ChangeFile::parse("brave-fox", Path::new(".monox/changes/brave-fox.md"), content)
+    }
+
+    fn releases(change: &ChangeFile) -> Vec<(&str, ChangeType)> {
+        change.releases.iter().map(|release| (release.package.as_str(), release.bump)).collect()
+    }
+
+    #[test]
+    fn parses_rendered_change_files() {
+        let rendered = ChangeFile::render(
+            &[
+                ChangeRelease { package: "@scope/core".to_string(), bump: ChangeType::Minor },
+                ChangeRelease { package: "app".to_string(), bump: ChangeType::Patch },
+            ],
+            "  Add retries\n\nWith backoff.\n",
+        );
+        assert_eq!(
+            rendered,
+            "---\n\"@scope/core\": minor\n\"app\": patch\n---\n\nAdd retries\n\nWith backoff.\n"
+        );
+
+        let change = parse(&rendered).unwrap();
+        assert_eq!(change.id, "brave-fox");
+        assert_eq!(
+            releases(&change),
+            [("@scope/core", ChangeType::Minor), ("app", ChangeType::Patch)]
+        );
+        assert_eq!(change.summary, "Add retries\n\nWith backoff.");
+    }
+
+    #[test]
+    fn parses_crlf_and_unquoted_names() {
+        let change =
+            parse("\r\n---\r\ncore: major\r\n'app': patch\r\n---\r\nDrop Node 14\r\n").unwrap();
+        assert_eq!(releases(&change), [("core", ChangeType::Major), ("app", ChangeType::Patch)]);
+        assert_eq!(change.summary, "Drop Node 14");
+    }
+
+    #[test]
+    fn rejects_malformed_front_matter() {
+        // 缺少开头或结尾的分隔线
+        assert!(parse("core: patch\n---\nFix").is_none());
+        assert!(parse("---\ncore: patch\nFix").is_none());
+        // 没有涉及的包、缺少冒号、未知的升级类型
+        assert!(parse("---\n---\nFix").is_none());
+        assert!(parse("---\ncore patch\n---\nFix").is_none());
+        assert!(parse("---\ncore: feature\n---\nFix").is_none());
+    }
+}
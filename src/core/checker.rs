@@ -6,8 +6,11 @@
 // 职责: 工作区健康检查核心逻辑
 // 边界:
 //   - ✅ 循环依赖检测和分析
-//   - ✅ 版本冲突检测和分析
-//   - ✅ 过期依赖检测和分析
+//   - ✅ 版本冲突检测和分析（基于 semver 范围）
+//   - ✅ 过期依赖检测和分析（通过 registry 客户端）
+//   - ✅ 重复安装依赖检测（基于锁文件）
+//   - ✅ 工作区内部依赖协议和版本检测
+//   - ✅ package.json 规范检查
 //   - ✅ package.json 解析和依赖收集
 //   - ✅ 异步任务调度和执行
 //   - ❌ 不应包含CLI参数处理
@@ -19,29 +22,110 @@
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
 use std::sync::{Arc, Mutex};
 
 use crate::core::analyzer::DependencyAnalyzer;
-use crate::core::scheduler::{AsyncTaskScheduler, SchedulerConfig};
-use crate::models::config::Config;
+use crate::core::boundaries;
+use crate::core::deps_usage;
+use crate::core::lockfile::{self, ImporterManifest, InstalledSizes};
+use crate::core::manifest::{self, Manifest, ManifestContext};
+use crate::core::registry::RegistryClient;
+use crate::core::scheduler::{AsyncTaskScheduler, SchedulerConfig, TaskResult};
+use crate::models::boundary::BoundaryReport;
+use crate::models::config::{Config, PackageManager, RegistryConfig};
+use crate::models::deps_usage::DependencyUsageReport;
+use crate::models::lockfile::{DuplicatePackage, DuplicateReport, DuplicateVersion, Lockfile};
+use crate::models::manifest::ManifestReport;
+use crate::models::package::DependencyAnalysisResult;
+use crate::utils::semver_range::NpmVersionSpec;
 
 /// 过期依赖信息
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OutdatedDependency {
     /// 包名
     pub name: String,
-    /// 当前版本
+    /// 声明的版本规范
+    pub spec: String,
+    /// 当前版本（已安装的版本，未安装时为满足规范的最低版本）
     pub current: String,
-    /// 最新版本
+    /// 规范范围内可用的最高版本
+    pub wanted: Option<String>,
+    /// 目标发布标签对应的版本
     pub latest: String,
+    /// 目标发布标签
+    pub dist_tag: String,
+    /// 更新级别
+    pub level: UpdateLevel,
     /// 所属包
     pub package: String,
     /// 依赖类型 (dependencies, devDependencies, etc.)
     pub dep_type: String,
 }
 
+/// 过期依赖检查结果
+#[derive(Debug, Clone, Default)]
+pub struct OutdatedCheckResult {
+    /// 过期依赖
+    pub outdated: Vec<OutdatedDependency>,
+    /// 检查的依赖数量
+    pub total_checked: usize,
+    /// 查询失败的依赖（依赖名, 错误信息，超时时为空），按依赖名排序
+    pub failures: Vec<(String, Option<String>)>,
+}
+
+/// 依赖更新级别（按影响从小到大排序）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateLevel {
+    /// 目标版本已在声明的范围内
+    InRange,
+    /// 补丁版本更新
+    Patch,
+    /// 次版本更新
+    Minor,
+    /// 主版本更新
+    Major,
+    /// 目标版本为预发布版本（通常来自 next、beta 等发布标签）
+    Prerelease,
+}
+
+impl UpdateLevel {
+    /// 从字符串解析更新级别（patch、minor、major、prerelease）
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "patch" => Some(UpdateLevel::Patch),
+            "minor" => Some(UpdateLevel::Minor),
+            "major" => Some(UpdateLevel::Major),
+            "prerelease" => Some(UpdateLevel::Prerelease),
+            _ => None,
+        }
+    }
+
+    /// 获取级别名称
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UpdateLevel::InRange => "in_range",
+            UpdateLevel::Patch => "patch",
+            UpdateLevel::Minor => "minor",
+            UpdateLevel::Major => "major",
+            UpdateLevel::Prerelease => "prerelease",
+        }
+    }
+
+    /// 两个版本之间的更新级别（不考虑声明范围）
+    pub fn between(current: &semver::Version, target: &semver::Version) -> Self {
+        if target.major != current.major {
+            UpdateLevel::Major
+        } else if target.minor != current.minor {
+            UpdateLevel::Minor
+        } else {
+            UpdateLevel::Patch
+        }
+    }
+}
+
 /// 版本冲突信息
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VersionConflict {
@@ -49,8 +133,22 @@ pub struct VersionConflict {
     pub name: String,
     /// 冲突的版本使用情况
     pub conflicts: Vec<ConflictUsage>,
+    /// 冲突类型
+    pub kind: ConflictKind,
     /// 推荐的统一版本
     pub recommended_version: String,
+    /// 推荐版本满足的使用数
+    pub satisfied_usages: usize,
+}
+
+/// 版本冲突类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictKind {
+    /// 范围互不兼容，不存在同时满足所有使用的版本
+    Incompatible,
+    /// 范围兼容，仅写法不同
+    Compatible,
 }
 
 /// 版本冲突使用情况
@@ -60,27 +158,108 @@ pub struct ConflictUsage {
     pub package: String,
     /// 版本规范
     pub version_spec: String,
-    /// 解析后的版本
+    /// 满足版本规范的最低版本
     pub resolved_version: String,
     /// 依赖类型
     pub dep_type: String,
 }
 
+/// 工作区内部依赖的写法问题
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProtocolIssue {
+    /// 声明依赖的包名
+    pub package: String,
+    /// 依赖的工作区包名
+    pub dependency: String,
+    /// 依赖类型
+    pub dep_type: String,
+    /// 声明的版本规范
+    pub spec: String,
+    /// 工作区包的本地版本
+    pub local_version: String,
+    /// 问题类型
+    pub kind: ProtocolIssueKind,
+    /// 推荐的写法
+    pub suggested: String,
+}
+
+/// 内部依赖问题类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProtocolIssueKind {
+    /// 未使用 workspace: 协议
+    MissingProtocol,
+    /// 包管理器不支持 workspace: 协议
+    UnsupportedProtocol,
+    /// 版本范围不包含本地版本
+    VersionMismatch,
+}
+
+impl ProtocolIssueKind {
+    /// 获取类型名称
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProtocolIssueKind::MissingProtocol => "missing_protocol",
+            ProtocolIssueKind::UnsupportedProtocol => "unsupported_protocol",
+            ProtocolIssueKind::VersionMismatch => "version_mismatch",
+        }
+    }
+}
+
+/// 内部依赖的写法
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InternalProtocol {
+    /// 使用 workspace:^
+    Workspace,
+    /// 使用本地包的精确版本
+    Version,
+}
+
+impl InternalProtocol {
+    /// 根据配置确定写法，未配置时按包管理器选择（yarn 仅 berry 支持 workspace: 协议）
+    pub fn resolve(workspace_root: &std::path::Path) -> Self {
+        match Config::get_workspace_config().internal_protocol.as_str() {
+            "workspace" => InternalProtocol::Workspace,
+            "version" => InternalProtocol::Version,
+            _ => match Config::get_package_manager() {
+                PackageManager::Pnpm => InternalProtocol::Workspace,
+                PackageManager::Yarn if workspace_root.join(".yarnrc.yml").exists() => {
+                    InternalProtocol::Workspace
+                }
+                _ => InternalProtocol::Version,
+            },
+        }
+    }
+
+    /// 内部依赖的推荐写法
+    fn suggested_spec(&self, local_version: &str) -> String {
+        match self {
+            InternalProtocol::Workspace => "workspace:^".to_string(),
+            InternalProtocol::Version => local_version.to_string(),
+        }
+    }
+}
+
 /// 依赖信息
 #[derive(Debug, Clone)]
 struct DependencyInfo {
     /// 包名
     name: String,
-    /// 版本规范
-    version_spec: String,
     /// 使用该依赖的包列表
-    used_by: Vec<(String, String)>,
+    used_by: Vec<DependencyUsage>,
 }
 
-/// npm view 命令的响应结构
-#[derive(Debug, Deserialize)]
-struct NpmViewResponse {
-    version: String,
+/// 依赖的单次使用
+#[derive(Debug, Clone)]
+struct DependencyUsage {
+    /// 使用该依赖的包名
+    package: String,
+    /// 依赖类型
+    dep_type: String,
+    /// 声明的版本规范
+    version_spec: String,
+    /// 已安装的版本（从包目录向上查找 node_modules）
+    installed: Option<String>,
 }
 
 /// 进度回调函数类型
@@ -90,12 +269,13 @@ pub type ProgressCallback = Arc<dyn Fn(usize, usize) + Send + Sync>;
 pub struct HealthChecker {
     workspace_root: std::path::PathBuf,
     verbose: bool,
+    offline: bool,
 }
 
 impl HealthChecker {
     /// 创建新的健康检查器
     pub fn new(workspace_root: std::path::PathBuf) -> Self {
-        Self { workspace_root, verbose: false }
+        Self { workspace_root, verbose: false, offline: false }
     }
 
     /// 设置详细模式
@@ -104,12 +284,63 @@ impl HealthChecker {
         self
     }
 
+    /// 设置离线模式（仅使用缓存的 registry 元数据）
+    pub fn with_offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
     /// 检查循环依赖
-    pub fn check_circular_dependencies(&self) -> Result<Vec<Vec<String>>> {
+    pub fn check_circular_dependencies(&self) -> Result<DependencyAnalysisResult> {
+        let mut analyzer =
+            DependencyAnalyzer::new(self.workspace_root.clone()).with_verbose(self.verbose);
+        analyzer.analyze_workspace()
+    }
+
+    /// 检查工作区内部依赖是否符合 [boundaries] 中的架构边界规则
+    pub fn check_boundaries(&self) -> Result<BoundaryReport> {
+        let config = Config::get_boundaries_config();
+        let mut analyzer =
+            DependencyAnalyzer::new(self.workspace_root.clone()).with_verbose(self.verbose);
+        let result = analyzer.analyze_workspace()?;
+        boundaries::check_boundaries(&result.packages, &config)
+    }
+
+    /// 扫描源码导入，检查未声明的依赖和未使用的依赖
+    pub fn check_deps_usage(&self) -> Result<DependencyUsageReport> {
+        let config = Config::get_deps_usage_config();
         let mut analyzer =
             DependencyAnalyzer::new(self.workspace_root.clone()).with_verbose(self.verbose);
         let result = analyzer.analyze_workspace()?;
-        Ok(result.circular_dependencies)
+        deps_usage::check_deps_usage(&result.packages, &self.workspace_root, &config)
+    }
+
+    /// 按 [manifest] 配置检查各包 package.json 的规范
+    pub fn check_manifest(&self) -> Result<ManifestReport> {
+        let config = Config::get_manifest_config();
+
+        let mut root = None;
+        let mut packages = Vec::new();
+        for package_file in self.collect_package_files()? {
+            let json = parse_package_json(&package_file)?;
+            let Some(dir) = package_file.parent() else {
+                continue;
+            };
+            if dir == self.workspace_root {
+                root = Some(Manifest { folder: ".".to_string(), json });
+                continue;
+            }
+            let folder = dir
+                .strip_prefix(&self.workspace_root)
+                .unwrap_or(dir)
+                .to_string_lossy()
+                .replace('\\', "/");
+            packages.push(Manifest { folder, json });
+        }
+        packages.sort_by(|a, b| a.folder.cmp(&b.folder));
+
+        let context = ManifestContext { root, packages, config: &config };
+        manifest::lint(&context, &manifest::builtin_rules())
     }
 
     /// 检查版本冲突
@@ -121,26 +352,83 @@ impl HealthChecker {
         self.collect_version_conflicts(&package_files)
     }
 
+    /// 检查重复安装的依赖（工作区没有锁文件时返回 None）
+    pub fn check_duplicates(&self) -> Result<Option<DuplicateReport>> {
+        let package_files = self.collect_package_files()?;
+        let manifests = self.collect_importer_manifests(&package_files)?;
+
+        let Some(lockfile) = lockfile::load_lockfile(&self.workspace_root, &manifests)? else {
+            return Ok(None);
+        };
+
+        let roots: Vec<std::path::PathBuf> =
+            manifests.iter().map(|m| self.workspace_root.join(&m.folder)).collect();
+        let sizes = lockfile::installed_sizes(&roots);
+
+        Ok(Some(find_duplicates(&lockfile, &sizes)))
+    }
+
+    /// 检查工作区内部依赖的协议和版本范围
+    pub fn check_workspace_protocol(&self) -> Result<Vec<ProtocolIssue>> {
+        let package_files = self.collect_package_files()?;
+        let manifests = package_files.iter().map(parse_package_json).collect::<Result<Vec<_>>>()?;
+
+        // 工作区包名 -> 本地版本
+        let local_versions: BTreeMap<&str, &str> = manifests
+            .iter()
+            .filter_map(|json| Some((json["name"].as_str()?, json["version"].as_str()?)))
+            .collect();
+        let protocol = InternalProtocol::resolve(&self.workspace_root);
+
+        let mut issues = Vec::new();
+        for package_json in &manifests {
+            let package_name = package_json["name"].as_str().unwrap_or("unknown");
+            for dep_type in DEP_TYPES {
+                let Some(deps) = package_json[dep_type].as_object() else {
+                    continue;
+                };
+                for (dep_name, spec) in deps {
+                    let Some(local_version) = local_versions.get(dep_name.as_str()) else {
+                        continue;
+                    };
+                    let spec = spec.as_str().unwrap_or("");
+                    if let Some(kind) = check_internal_spec(spec, local_version, protocol) {
+                        issues.push(ProtocolIssue {
+                            package: package_name.to_string(),
+                            dependency: dep_name.clone(),
+                            dep_type: dep_type.to_string(),
+                            spec: spec.to_string(),
+                            local_version: local_version.to_string(),
+                            kind,
+                            suggested: protocol.suggested_spec(local_version),
+                        });
+                    }
+                }
+            }
+        }
+
+        Ok(issues)
+    }
+
     /// 检查过期依赖
     pub async fn check_outdated_dependencies(&self) -> Result<Vec<OutdatedDependency>> {
-        let (outdated_deps, _) = self.check_outdated_dependencies_with_progress(None).await?;
-        Ok(outdated_deps)
+        let result = self.check_outdated_dependencies_with_progress(None).await?;
+        Ok(result.outdated)
     }
 
     /// 检查过期依赖（带进度回调）
-    /// 返回 (过期依赖列表, 总检测依赖数量)
     pub async fn check_outdated_dependencies_with_progress(
         &self,
         progress_callback: Option<ProgressCallback>,
-    ) -> Result<(Vec<OutdatedDependency>, usize)> {
+    ) -> Result<OutdatedCheckResult> {
         let package_files = self.collect_package_files()?;
         if package_files.is_empty() {
-            return Ok((Vec::new(), 0));
+            return Ok(OutdatedCheckResult::default());
         }
 
         let unique_dependencies = self.collect_unique_dependencies(&package_files)?;
         if unique_dependencies.is_empty() {
-            return Ok((Vec::new(), 0));
+            return Ok(OutdatedCheckResult::default());
         }
 
         // 获取固定的总依赖数量，并创建包装的进度回调
@@ -152,10 +440,10 @@ impl HealthChecker {
             }) as ProgressCallback
         });
 
-        let outdated_deps =
+        let (outdated, failures) =
             self.check_outdated_with_scheduler(unique_dependencies, wrapped_callback).await?;
 
-        Ok((outdated_deps, total_deps))
+        Ok(OutdatedCheckResult { outdated, total_checked: total_deps, failures })
     }
 }
 
@@ -212,11 +500,52 @@ impl HealthChecker {
             let package_json = parse_package_json(package_file)?;
             let package_name = package_json["name"].as_str().unwrap_or("unknown").to_string();
 
-            process_package_dependencies(&package_json, &package_name, &mut unique_dependencies);
+            let package_dir = package_file.parent().unwrap_or(&self.workspace_root);
+            let usage = PackageUsage {
+                package_name: &package_name,
+                package_dir,
+                workspace_root: &self.workspace_root,
+            };
+
+            process_package_dependencies(&package_json, &usage, &mut unique_dependencies);
         }
 
         Ok(unique_dependencies)
     }
+
+    /// 收集工作区包清单（供锁文件解析导入方依赖）
+    fn collect_importer_manifests(
+        &self,
+        package_files: &[std::path::PathBuf],
+    ) -> Result<Vec<ImporterManifest>> {
+        let mut manifests = Vec::new();
+
+        for package_file in package_files {
+            let package_json = parse_package_json(package_file)?;
+            let folder = package_file
+                .parent()
+                .and_then(|dir| dir.strip_prefix(&self.workspace_root).ok())
+                .map(|dir| dir.to_string_lossy().replace('\\', "/"))
+                .filter(|dir| !dir.is_empty())
+                .unwrap_or_else(|| ".".to_string());
+            let name =
+                package_json["name"].as_str().map(|n| n.to_string()).unwrap_or(folder.clone());
+
+            let mut dependencies = BTreeMap::new();
+            for dep_type in ["dependencies", "devDependencies", "optionalDependencies"] {
+                if let Some(deps) = package_json[dep_type].as_object() {
+                    for (dep_name, spec) in deps {
+                        dependencies
+                            .insert(dep_name.clone(), spec.as_str().unwrap_or("").to_string());
+                    }
+                }
+            }
+
+            manifests.push(ImporterManifest { name, folder, dependencies });
+        }
+
+        Ok(manifests)
+    }
 }
 
 // ============================================================================
@@ -229,10 +558,13 @@ impl HealthChecker {
         &self,
         unique_dependencies: BTreeMap<String, DependencyInfo>,
         progress_callback: Option<ProgressCallback>,
-    ) -> Result<Vec<OutdatedDependency>> {
+    ) -> Result<(Vec<OutdatedDependency>, Vec<(String, Option<String>)>)> {
         let total_deps = unique_dependencies.len();
         let outdated_deps = Arc::new(Mutex::new(Vec::new()));
         let found_packages = Arc::new(Mutex::new(std::collections::HashSet::<String>::new()));
+        let registry =
+            Arc::new(RegistryClient::from_config(&self.workspace_root)?.with_offline(self.offline));
+        let registry_config = Arc::new(Config::get_registry_config());
 
         // 创建调度器配置
         let config = SchedulerConfig {
@@ -247,14 +579,34 @@ impl HealthChecker {
         let scheduler = AsyncTaskScheduler::new(config);
         let tasks = create_outdated_check_tasks(
             unique_dependencies,
+            registry,
+            registry_config,
             outdated_deps.clone(),
             found_packages,
             self.verbose,
         );
-        let _results = scheduler.execute_batch(tasks).await;
+        let results = scheduler.execute_batch(tasks).await;
+
+        // 查询失败的依赖单独返回，避免误报为没有过期
+        let mut failures: Vec<(String, Option<String>)> = results
+            .into_iter()
+            .filter_map(|(name, result)| match result {
+                TaskResult::Success(()) => None,
+                TaskResult::Failed(error) => Some((name, Some(error))),
+                TaskResult::Timeout | TaskResult::Cancelled => Some((name, None)),
+            })
+            .collect();
+        failures.sort();
+
+        // 全部请求失败时（registry 不可达、认证错误等）直接返回第一个错误
+        if failures.len() == total_deps {
+            if let Some((_, Some(first_error))) = failures.iter().find(|(_, e)| e.is_some()) {
+                anyhow::bail!(first_error.clone());
+            }
+        }
 
         let result = outdated_deps.lock().unwrap().clone();
-        Ok(result)
+        Ok((result, failures))
     }
 }
 
@@ -297,10 +649,20 @@ fn parse_package_json(package_file: &std::path::PathBuf) -> Result<serde_json::V
     Ok(serde_json::from_str(&content)?)
 }
 
+/// 声明依赖的包
+struct PackageUsage<'a> {
+    /// 包名
+    package_name: &'a str,
+    /// 包目录
+    package_dir: &'a std::path::Path,
+    /// 工作区根目录（查找已安装版本的上限）
+    workspace_root: &'a std::path::Path,
+}
+
 /// 处理单个包的依赖
 fn process_package_dependencies(
     package_json: &serde_json::Value,
-    package_name: &str,
+    package: &PackageUsage,
     unique_dependencies: &mut BTreeMap<String, DependencyInfo>,
 ) {
     for dep_type in DEP_TYPES {
@@ -316,7 +678,7 @@ fn process_package_dependencies(
                     unique_dependencies,
                     dep_name,
                     &version_spec,
-                    package_name,
+                    package,
                     dep_type,
                 );
             }
@@ -329,23 +691,44 @@ fn add_or_update_dependency(
     unique_dependencies: &mut BTreeMap<String, DependencyInfo>,
     dep_name: &str,
     version_spec: &str,
-    package_name: &str,
+    package: &PackageUsage,
     dep_type: &str,
 ) {
+    let usage = DependencyUsage {
+        package: package.package_name.to_string(),
+        dep_type: dep_type.to_string(),
+        version_spec: version_spec.to_string(),
+        installed: installed_version(package.package_dir, package.workspace_root, dep_name),
+    };
+
     unique_dependencies
         .entry(dep_name.to_string())
-        .and_modify(|dep_info| {
-            dep_info.used_by.push((package_name.to_string(), dep_type.to_string()));
-        })
-        .or_insert_with(|| DependencyInfo {
-            name: dep_name.to_string(),
-            version_spec: version_spec.to_string(),
-            used_by: vec![(package_name.to_string(), dep_type.to_string())],
-        });
+        .or_insert_with(|| DependencyInfo { name: dep_name.to_string(), used_by: Vec::new() })
+        .used_by
+        .push(usage);
+}
+
+/// 查找依赖已安装的版本（与 Node.js 解析一致，从包目录逐级向上查找 node_modules，到工作区根目录为止）
+fn installed_version(
+    package_dir: &std::path::Path,
+    workspace_root: &std::path::Path,
+    dep_name: &str,
+) -> Option<String> {
+    for dir in package_dir.ancestors() {
+        let manifest = dir.join("node_modules").join(dep_name).join("package.json");
+        if let Ok(content) = fs::read_to_string(&manifest) {
+            let json: serde_json::Value = serde_json::from_str(&content).ok()?;
+            return json["version"].as_str().map(str::to_string);
+        }
+        if dir == workspace_root {
+            break;
+        }
+    }
+    None
 }
 
 /// 检查是否应该跳过依赖检查
-fn should_skip_dependency(version_spec: &str) -> bool {
+pub fn should_skip_dependency(version_spec: &str) -> bool {
     version_spec.starts_with("workspace:")
         || version_spec.starts_with("file:")
         || version_spec.starts_with("link:")
@@ -353,49 +736,64 @@ fn should_skip_dependency(version_spec: &str) -> bool {
         || version_spec.contains("github:")
 }
 
-/// 从版本规范中提取版本号
-fn extract_version_from_spec(version_spec: &str) -> String {
-    version_spec
-        .trim_start_matches('^')
-        .trim_start_matches('~')
-        .trim_start_matches(">=")
-        .trim_start_matches("<=")
-        .trim_start_matches('>')
-        .trim_start_matches('<')
-        .trim_start_matches('=')
-        .to_string()
-}
-
-/// 简单的版本比较
-fn is_version_satisfied(current: &str, latest: &str) -> bool {
-    current == latest
-}
-
-/// 异步获取最新版本
-async fn get_latest_version_async(package_name: &str) -> Result<Option<String>> {
-    use tokio::process::Command;
-
-    let output =
-        Command::new("npm").args(&["view", package_name, "version", "--json"]).output().await?;
-
-    if !output.status.success() {
-        return Ok(None);
+/// 检查单个内部依赖的写法，符合要求时返回 None
+fn check_internal_spec(
+    spec: &str,
+    local_version: &str,
+    protocol: InternalProtocol,
+) -> Option<ProtocolIssueKind> {
+    let local = semver::Version::parse(local_version).ok()?;
+
+    if let Some(range) = spec.strip_prefix("workspace:") {
+        if protocol == InternalProtocol::Version {
+            return Some(ProtocolIssueKind::UnsupportedProtocol);
+        }
+        // workspace:*、workspace:^、workspace:~ 始终指向本地版本
+        if matches!(range, "*" | "^" | "~") {
+            return None;
+        }
+        return match NpmVersionSpec::parse(range) {
+            Some(range) if range.matches(&local) => None,
+            _ => Some(ProtocolIssueKind::VersionMismatch),
+        };
     }
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let trimmed = stdout.trim();
+    // file:、link:、git 等显式来源和指向其他包的 npm: 别名不做要求
+    if should_skip_dependency(spec) {
+        return None;
+    }
+    let parsed = NpmVersionSpec::parse(spec);
+    if parsed.as_ref().is_some_and(|parsed| parsed.alias.is_some()) {
+        return None;
+    }
 
-    if trimmed.is_empty() {
-        return Ok(None);
+    // 版本不匹配比缺少协议更严重，优先报告
+    if !parsed.is_some_and(|range| range.matches(&local)) {
+        return Some(ProtocolIssueKind::VersionMismatch);
     }
+    (protocol == InternalProtocol::Workspace).then_some(ProtocolIssueKind::MissingProtocol)
+}
 
-    // 解析响应
-    match serde_json::from_str::<NpmViewResponse>(trimmed) {
-        Ok(response) => Ok(Some(response.version)),
-        Err(_) => {
-            let version = trimmed.trim_matches('"');
-            Ok(Some(version.to_string()))
-        }
+/// 从版本规范中提取版本号（满足规范的最低版本，无法解析时原样返回）
+fn extract_version_from_spec(version_spec: &str) -> String {
+    NpmVersionSpec::parse(version_spec)
+        .and_then(|spec| spec.min_version())
+        .map(|version| version.to_string())
+        .unwrap_or_else(|| version_spec.to_string())
+}
+
+/// 判断更新级别（目标版本满足规范时为范围内更新）
+fn classify_update(
+    spec: &NpmVersionSpec,
+    current: &semver::Version,
+    target: &semver::Version,
+) -> UpdateLevel {
+    if target <= current || spec.matches(target) {
+        UpdateLevel::InRange
+    } else if !target.pre.is_empty() {
+        UpdateLevel::Prerelease
+    } else {
+        UpdateLevel::between(current, target)
     }
 }
 
@@ -416,6 +814,8 @@ fn calculate_optimal_thread_count(dependency_count: usize) -> usize {
 /// 创建过期检查任务
 fn create_outdated_check_tasks(
     unique_dependencies: BTreeMap<String, DependencyInfo>,
+    registry: Arc<RegistryClient>,
+    registry_config: Arc<RegistryConfig>,
     outdated_deps: Arc<Mutex<Vec<OutdatedDependency>>>,
     found_packages: Arc<Mutex<std::collections::HashSet<String>>>,
     verbose: bool,
@@ -423,6 +823,8 @@ fn create_outdated_check_tasks(
     unique_dependencies
         .into_iter()
         .map(|(dep_name, dep_info)| {
+            let registry = Arc::clone(&registry);
+            let registry_config = Arc::clone(&registry_config);
             let outdated_deps = Arc::clone(&outdated_deps);
             let found_packages = Arc::clone(&found_packages);
             let task_name = dep_name.clone();
@@ -433,6 +835,8 @@ fn create_outdated_check_tasks(
                 process_dependency_version(
                     dep_name,
                     dep_info,
+                    registry,
+                    registry_config,
                     outdated_deps,
                     found_packages,
                     verbose,
@@ -449,36 +853,71 @@ fn create_outdated_check_tasks(
 async fn process_dependency_version(
     dep_name: String,
     dep_info: DependencyInfo,
+    registry: Arc<RegistryClient>,
+    registry_config: Arc<RegistryConfig>,
     outdated_deps: Arc<Mutex<Vec<OutdatedDependency>>>,
     found_packages: Arc<Mutex<std::collections::HashSet<String>>>,
     _verbose: bool,
 ) -> Result<()> {
-    let latest_version = match get_latest_version_async(&dep_name).await? {
-        Some(version) => version,
-        None => return Ok(()),
-    };
+    // 每个使用方按各自声明的规范分类
+    for usage in &dep_info.used_by {
+        let Some(spec) = NpmVersionSpec::parse(&usage.version_spec) else {
+            continue;
+        };
+        // 已安装时与安装的版本比较，未安装时使用范围下限
+        let installed = usage.installed.as_deref().and_then(|v| semver::Version::parse(v).ok());
+        let Some(current) = installed.or_else(|| spec.min_version()) else {
+            continue;
+        };
 
-    let current_version = extract_version_from_spec(&dep_info.version_spec);
+        // npm: 别名需要查询真实包名
+        let registry_name = spec.alias.as_deref().unwrap_or(&dep_name);
+        let Some(packument) = registry.packument(registry_name).await? else {
+            continue;
+        };
 
-    if current_version == latest_version || is_version_satisfied(&current_version, &latest_version)
-    {
-        return Ok(());
-    }
+        // 未发布配置标签的包回退到 latest
+        let mut dist_tag = registry_config.dist_tag_for(&dep_name).to_string();
+        let target = match packument.dist_tags.get(&dist_tag) {
+            Some(version) => version.as_str(),
+            None => {
+                dist_tag = "latest".to_string();
+                match packument.latest() {
+                    Some(version) => version,
+                    None => continue,
+                }
+            }
+        };
+        let Ok(target) = semver::Version::parse(target) else {
+            continue;
+        };
 
-    // 记录发现的过期包
-    let _is_new_package = {
-        let mut found_set = found_packages.lock().unwrap();
-        found_set.insert(dep_name.clone())
-    };
+        let wanted = packument
+            .versions
+            .keys()
+            .filter_map(|version| semver::Version::parse(version).ok())
+            .filter(|version| spec.matches(version))
+            .max();
+
+        // 与 npm outdated 一致：当前版本落后于 wanted 或 latest 时都列出
+        let behind_wanted = wanted.as_ref().is_some_and(|wanted| *wanted > current);
+        if target <= current && !behind_wanted {
+            continue;
+        }
+
+        // 记录发现的过期包
+        found_packages.lock().unwrap().insert(dep_name.clone());
 
-    // 为每个使用该依赖的包创建记录
-    for (package_name, dep_type) in &dep_info.used_by {
         let outdated = OutdatedDependency {
             name: dep_name.clone(),
-            current: current_version.clone(),
-            latest: latest_version.clone(),
-            package: package_name.clone(),
-            dep_type: dep_type.clone(),
+            spec: usage.version_spec.clone(),
+            current: current.to_string(),
+            wanted: wanted.map(|version| version.to_string()),
+            latest: target.to_string(),
+            level: classify_update(&spec, &current, &target),
+            dist_tag,
+            package: usage.package.clone(),
+            dep_type: usage.dep_type.clone(),
         };
         outdated_deps.lock().unwrap().push(outdated);
     }
@@ -497,7 +936,10 @@ fn collect_dependency_usages(
             for (dep_name, version_value) in deps {
                 let version_spec = version_value.as_str().unwrap_or("").to_string();
 
-                if should_skip_dependency(&version_spec) {
+                // 跳过无法按 semver 比较的规范（workspace:、dist-tag、URL 等）
+                if should_skip_dependency(&version_spec)
+                    || NpmVersionSpec::parse(&version_spec).is_none()
+                {
                     continue;
                 }
 
@@ -515,49 +957,320 @@ fn collect_dependency_usages(
     }
 }
 
-/// 查找版本冲突
+/// 查找版本冲突（版本规范写法不一致即视为冲突，再按兼容性分类）
 fn find_version_conflicts(
     dependency_usages: BTreeMap<String, Vec<ConflictUsage>>,
 ) -> Vec<VersionConflict> {
     let mut conflicts = Vec::new();
 
     for (dep_name, usages) in dependency_usages {
-        if usages.len() < 2 {
+        let unique_specs: BTreeSet<&str> =
+            usages.iter().map(|usage| usage.version_spec.trim()).collect();
+        if unique_specs.len() < 2 {
             continue;
         }
 
-        // 检查是否存在版本冲突
-        let unique_versions: HashMap<String, Vec<&ConflictUsage>> = group_by_version(&usages);
+        let specs: Vec<NpmVersionSpec> =
+            usages.iter().filter_map(|usage| NpmVersionSpec::parse(&usage.version_spec)).collect();
 
-        if unique_versions.len() > 1 {
-            let recommended_version = calculate_recommended_version(&usages);
-            conflicts.push(VersionConflict {
-                name: dep_name,
-                conflicts: usages,
-                recommended_version,
-            });
-        }
+        let (recommended, satisfied_usages) = recommend_version(&specs);
+
+        // npm: 别名指向不同的真实包时必然不兼容
+        let targets: BTreeSet<&str> =
+            specs.iter().map(|spec| spec.alias.as_deref().unwrap_or(&dep_name)).collect();
+        let kind = if targets.len() == 1 && satisfied_usages == usages.len() {
+            ConflictKind::Compatible
+        } else {
+            ConflictKind::Incompatible
+        };
+
+        conflicts.push(VersionConflict {
+            name: dep_name,
+            conflicts: usages,
+            kind,
+            recommended_version: recommended
+                .map(|version| version.to_string())
+                .unwrap_or_else(|| "unknown".to_string()),
+            satisfied_usages,
+        });
     }
 
     conflicts
 }
 
-/// 按版本分组
-fn group_by_version(usages: &[ConflictUsage]) -> HashMap<String, Vec<&ConflictUsage>> {
-    let mut unique_versions: HashMap<String, Vec<&ConflictUsage>> = HashMap::new();
-    for usage in usages {
-        unique_versions.entry(usage.resolved_version.clone()).or_default().push(usage);
+/// 计算推荐的统一版本：在各范围下界中选出满足使用数最多的版本，数量相同时取最高版本
+fn recommend_version(specs: &[NpmVersionSpec]) -> (Option<semver::Version>, usize) {
+    specs
+        .iter()
+        .flat_map(|spec| spec.lower_bounds())
+        .map(|candidate| {
+            let satisfied = specs.iter().filter(|spec| spec.matches(&candidate)).count();
+            (candidate, satisfied)
+        })
+        .max_by(|(a, a_count), (b, b_count)| a_count.cmp(b_count).then_with(|| a.cmp(b)))
+        .map(|(version, satisfied)| (Some(version), satisfied))
+        .unwrap_or((None, 0))
+}
+
+/// 查找安装了多个版本的包，统计引入方和预估额外体积
+fn find_duplicates(lockfile: &Lockfile, sizes: &InstalledSizes) -> DuplicateReport {
+    // 包名 -> 版本 -> 引入方
+    let mut installed: BTreeMap<&str, BTreeMap<&str, BTreeSet<String>>> = BTreeMap::new();
+    for package in lockfile.packages.values() {
+        installed.entry(&package.name).or_default().entry(&package.version).or_default();
+    }
+
+    let mut add_dependents = |dependent: String, dependencies: &[String]| {
+        for id in dependencies {
+            if let Some(dep) = lockfile.packages.get(id) {
+                installed
+                    .entry(&dep.name)
+                    .or_default()
+                    .entry(&dep.version)
+                    .or_default()
+                    .insert(dependent.clone());
+            }
+        }
+    };
+    for (importer, dependencies) in &lockfile.importers {
+        add_dependents(importer.clone(), dependencies);
+    }
+    for package in lockfile.packages.values() {
+        add_dependents(format!("{}@{}", package.name, package.version), &package.dependencies);
+    }
+
+    let total_packages = installed.values().map(|versions| versions.len()).sum();
+
+    let mut duplicates: Vec<DuplicatePackage> = installed
+        .into_iter()
+        .filter(|(_, versions)| versions.len() > 1)
+        .map(|(name, versions)| {
+            let mut versions: Vec<DuplicateVersion> = versions
+                .into_iter()
+                .map(|(version, pulled_by)| DuplicateVersion {
+                    version: version.to_string(),
+                    pulled_by: pulled_by.into_iter().collect(),
+                    size_bytes: sizes.get(&(name.to_string(), version.to_string())).copied(),
+                })
+                .collect();
+            versions.sort_by(|a, b| compare_versions(&a.version, &b.version));
+
+            // 统一到最高版本后可以省去的其他版本体积
+            let extra_bytes =
+                versions[..versions.len() - 1].iter().map(|v| v.size_bytes).sum::<Option<u64>>();
+
+            DuplicatePackage { name: name.to_string(), versions, extra_bytes }
+        })
+        .collect();
+
+    duplicates.sort_by(|a, b| {
+        b.extra_bytes
+            .unwrap_or(0)
+            .cmp(&a.extra_bytes.unwrap_or(0))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+
+    let known: Vec<u64> = duplicates.iter().filter_map(|d| d.extra_bytes).collect();
+    let extra_bytes = (!known.is_empty()).then(|| known.iter().sum());
+
+    DuplicateReport {
+        lockfile: lockfile.kind,
+        path: lockfile.path.clone(),
+        total_packages,
+        duplicates,
+        extra_bytes,
+    }
+}
+
+/// 按 semver 比较版本号（无法解析时按字符串比较）
+fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
+    match (semver::Version::parse(a), semver::Version::parse(b)) {
+        (Ok(a), Ok(b)) => a.cmp(&b),
+        _ => a.cmp(b),
     }
-    unique_versions
 }
 
-/// 计算推荐的统一版本
-fn calculate_recommended_version(usages: &[ConflictUsage]) -> String {
-    let mut versions: Vec<String> =
-        usages.iter().map(|usage| usage.resolved_version.clone()).collect();
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::lockfile::{LockedPackage, LockfileKind};
+
+    fn version(value: &str) -> semver::Version {
+        semver::Version::parse(value).unwrap()
+    }
+
+    #[test]
+    fn classifies_prerelease_as_its_own_level() {
+        let spec = NpmVersionSpec::parse("^1.2.0").unwrap();
+        let current = version("1.2.0");
+
+        assert_eq!(classify_update(&spec, &current, &version("1.3.0")), UpdateLevel::InRange);
+        assert_eq!(classify_update(&spec, &current, &version("2.0.0")), UpdateLevel::Major);
+        assert_eq!(
+            classify_update(&spec, &current, &version("2.0.0-beta.1")),
+            UpdateLevel::Prerelease
+        );
+    }
+
+    #[test]
+    fn finds_installed_version_up_to_workspace_root() {
+        let root = tempfile::tempdir().unwrap();
+        let package_dir = root.path().join("packages/app");
+        let hoisted = root.path().join("node_modules/lodash");
+        fs::create_dir_all(&package_dir).unwrap();
+        fs::create_dir_all(&hoisted).unwrap();
+        fs::write(hoisted.join("package.json"), r#"{"version":"4.17.21"}"#).unwrap();
+
+        assert_eq!(
+            installed_version(&package_dir, root.path(), "lodash"),
+            Some("4.17.21".to_string())
+        );
+        assert_eq!(installed_version(&package_dir, root.path(), "react"), None);
+    }
+
+    fn usage(package: &str, version_spec: &str) -> ConflictUsage {
+        ConflictUsage {
+            package: package.to_string(),
+            version_spec: version_spec.to_string(),
+            resolved_version: extract_version_from_spec(version_spec),
+            dep_type: "dependencies".to_string(),
+        }
+    }
+
+    fn conflict(dep_name: &str, specs: &[(&str, &str)]) -> Option<VersionConflict> {
+        let usages = specs.iter().map(|(package, spec)| usage(package, spec)).collect();
+        find_version_conflicts(BTreeMap::from([(dep_name.to_string(), usages)])).pop()
+    }
+
+    #[test]
+    fn recommends_the_version_satisfying_most_specs() {
+        let specs: Vec<NpmVersionSpec> = ["^1.2.0", "~1.4.0", ">=1.0.0 <1.5.0"]
+            .iter()
+            .map(|spec| NpmVersionSpec::parse(spec).unwrap())
+            .collect();
+        assert_eq!(recommend_version(&specs), (Some(version("1.4.0")), 3));
+
+        // 满足数相同时取最高版本
+        let specs: Vec<NpmVersionSpec> =
+            ["^1.0.0", "^2.0.0"].iter().map(|spec| NpmVersionSpec::parse(spec).unwrap()).collect();
+        assert_eq!(recommend_version(&specs), (Some(version("2.0.0")), 1));
+
+        assert_eq!(recommend_version(&[]), (None, 0));
+    }
+
+    #[test]
+    fn classifies_conflicts_by_compatibility() {
+        assert!(conflict("react", &[("a", "^18.0.0"), ("b", "^18.0.0")]).is_none());
 
-    versions.sort();
-    versions.dedup();
+        let compatible = conflict("react", &[("a", "^18.0.0"), ("b", "~18.2.0")]).unwrap();
+        assert_eq!(compatible.kind, ConflictKind::Compatible);
+        assert_eq!(compatible.recommended_version, "18.2.0");
+        assert_eq!(compatible.satisfied_usages, 2);
 
-    versions.last().cloned().unwrap_or_else(|| "unknown".to_string())
+        let incompatible = conflict("react", &[("a", "^17.0.0"), ("b", "^18.0.0")]).unwrap();
+        assert_eq!(incompatible.kind, ConflictKind::Incompatible);
+        assert_eq!(incompatible.satisfied_usages, 1);
+
+        // 范围兼容但别名指向不同的真实包
+        let aliased =
+            conflict("lodash", &[("a", "^4.17.0"), ("b", "npm:lodash-es@^4.17.0")]).unwrap();
+        assert_eq!(aliased.kind, ConflictKind::Incompatible);
+    }
+
+    fn locked(name: &str, version: &str, dependencies: &[&str]) -> LockedPackage {
+        LockedPackage {
+            name: name.to_string(),
+            version: version.to_string(),
+            dependencies: dependencies.iter().map(|id| id.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn finds_duplicates_with_dependents_and_extra_size() {
+        let lockfile = Lockfile {
+            kind: LockfileKind::Pnpm,
+            path: "pnpm-lock.yaml".into(),
+            packages: BTreeMap::from([
+                ("lodash@3.10.1".to_string(), locked("lodash", "3.10.1", &[])),
+                ("lodash@4.17.21".to_string(), locked("lodash", "4.17.21", &[])),
+                ("legacy@1.0.0".to_string(), locked("legacy", "1.0.0", &["lodash@3.10.1"])),
+                ("ms@2.1.2".to_string(), locked("ms", "2.1.2", &[])),
+            ]),
+            importers: BTreeMap::from([
+                ("app".to_string(), vec!["lodash@4.17.21".to_string(), "legacy@1.0.0".to_string()]),
+                ("web".to_string(), vec!["lodash@4.17.21".to_string(), "ms@2.1.2".to_string()]),
+            ]),
+        };
+        let sizes = InstalledSizes::from([
+            (("lodash".to_string(), "3.10.1".to_string()), 300),
+            (("lodash".to_string(), "4.17.21".to_string()), 500),
+        ]);
+
+        let report = find_duplicates(&lockfile, &sizes);
+        assert_eq!(report.total_packages, 4);
+        assert_eq!(report.extra_bytes, Some(300));
+
+        let [lodash] = report.duplicates.as_slice() else {
+            panic!("expected one duplicate, got {:?}", report.duplicates);
+        };
+        assert_eq!(lodash.name, "lodash");
+        assert_eq!(lodash.extra_bytes, Some(300));
+        let versions: Vec<(&str, &[String])> =
+            lodash.versions.iter().map(|v| (v.version.as_str(), v.pulled_by.as_slice())).collect();
+        assert_eq!(
+            versions,
+            [
+                ("3.10.1", &["legacy@1.0.0".to_string()][..]),
+                ("4.17.21", &["app".to_string(), "web".to_string()][..])
+            ]
+        );
+    }
+
+    #[test]
+    fn accepts_workspace_shorthands_under_workspace_protocol() {
+        for spec in ["workspace:*", "workspace:^", "workspace:~", "workspace:^1.0.0"] {
+            assert_eq!(check_internal_spec(spec, "1.2.0", InternalProtocol::Workspace), None);
+        }
+        // 版本写法不支持 workspace: 协议
+        assert_eq!(
+            check_internal_spec("workspace:*", "1.2.0", InternalProtocol::Version),
+            Some(ProtocolIssueKind::UnsupportedProtocol)
+        );
+    }
+
+    #[test]
+    fn reports_workspace_ranges_that_exclude_the_local_version() {
+        assert_eq!(
+            check_internal_spec("workspace:^2.0.0", "1.2.0", InternalProtocol::Workspace),
+            Some(ProtocolIssueKind::VersionMismatch)
+        );
+        assert_eq!(
+            check_internal_spec("workspace:not-a-range", "1.2.0", InternalProtocol::Workspace),
+            Some(ProtocolIssueKind::VersionMismatch)
+        );
+    }
+
+    #[test]
+    fn checks_plain_ranges_against_the_local_version() {
+        // 匹配的范围在版本写法下合规，在 workspace 写法下缺少协议
+        assert_eq!(check_internal_spec("1.2.0", "1.2.0", InternalProtocol::Version), None);
+        assert_eq!(check_internal_spec("^1.0.0", "1.2.0", InternalProtocol::Version), None);
+        assert_eq!(
+            check_internal_spec("1.2.0", "1.2.0", InternalProtocol::Workspace),
+            Some(ProtocolIssueKind::MissingProtocol)
+        );
+        // 版本不匹配优先于缺少协议
+        assert_eq!(
+            check_internal_spec("~1.1.0", "1.2.0", InternalProtocol::Workspace),
+            Some(ProtocolIssueKind::VersionMismatch)
+        );
+    }
+
+    #[test]
+    fn skips_aliases_and_explicit_sources() {
+        for spec in ["npm:other-core@^3.0.0", "file:../core", "link:../core"] {
+            assert_eq!(check_internal_spec(spec, "1.2.0", InternalProtocol::Workspace), None);
+            assert_eq!(check_internal_spec(spec, "1.2.0", InternalProtocol::Version), None);
+        }
+    }
 }
@@ -0,0 +1,371 @@
+// ============================================================================
+// MonoX - 循环依赖分析
+// ============================================================================
+//
+// 文件: src/core/cycles.rs
+// 职责: 在强连通分量内找出基本环，并计算断开循环所需的最少依赖
+// 边界:
+//   - ✅ 基本环枚举（数量过多时截断）
+//   - ✅ 最小反馈边集（边数少时精确求解，否则贪心近似）
+//   - ✅ 同样边数时优先断开开发依赖和对等依赖
+//   - ❌ 不应包含强连通分量检测和构建阶段计算
+//   - ❌ 不应包含输出格式化
+//
+// ============================================================================
+
+use std::collections::{HashMap, VecDeque};
+
+use crate::models::package::{CircularDependency, CycleEdge, ElementaryCycle, WorkspacePackage};
+
+/// 每个强连通分量最多列出的基本环数
+const MAX_CYCLES: usize = 50;
+
+/// 枚举基本环时最多访问的路径步数
+const MAX_STEPS: usize = 100_000;
+
+/// 边数不超过该值时精确求解最小反馈边集
+const EXACT_EDGE_LIMIT: usize = 16;
+
+/// 强连通分量内的图（节点为分量内包的下标）
+struct ComponentGraph {
+    /// 包名（已排序）
+    names: Vec<String>,
+    /// 边（依赖方下标，被依赖的包下标，原始边）
+    edges: Vec<(usize, usize, CycleEdge)>,
+}
+
+/// 为每个强连通分量生成循环依赖详情（只考虑决定构建顺序的依赖）
+pub fn describe_cycles(
+    packages: &[WorkspacePackage],
+    components: &[Vec<String>],
+) -> Vec<CircularDependency> {
+    let packages: HashMap<&str, &WorkspacePackage> =
+        packages.iter().map(|p| (p.name.as_str(), p)).collect();
+
+    components
+        .iter()
+        .map(|component| {
+            let graph = ComponentGraph::new(&packages, component);
+            let (cycles, truncated) = graph.elementary_cycles(&vec![false; graph.edges.len()]);
+            let removed = graph.feedback_edges();
+
+            CircularDependency {
+                packages: graph.names.clone(),
+                cycles: cycles.iter().map(|cycle| graph.to_cycle(cycle)).collect(),
+                truncated,
+                break_edges: removed.iter().map(|&e| graph.edges[e].2.clone()).collect(),
+            }
+        })
+        .collect()
+}
+
+impl ComponentGraph {
+    /// 由分量内的包和它们决定构建顺序的依赖构建图
+    fn new(packages: &HashMap<&str, &WorkspacePackage>, component: &[String]) -> Self {
+        let mut names = component.to_vec();
+        names.sort();
+        let index: HashMap<&str, usize> =
+            names.iter().enumerate().map(|(i, name)| (name.as_str(), i)).collect();
+
+        let mut edges = Vec::new();
+        for (from, name) in names.iter().enumerate() {
+            let Some(package) = packages.get(name.as_str()) else {
+                continue;
+            };
+            let mut deps: Vec<&String> = package.build_dependencies.iter().collect();
+            deps.sort();
+            for dep in deps {
+                if let Some(&to) = index.get(dep.as_str()) {
+                    let edge = CycleEdge {
+                        from: name.clone(),
+                        to: dep.clone(),
+                        types: package.dependency_types_of(dep).to_vec(),
+                    };
+                    edges.push((from, to, edge));
+                }
+            }
+        }
+
+        Self { names, edges }
+    }
+
+    /// 未移除的出边（边下标）
+    fn adjacency(&self, removed: &[bool]) -> Vec<Vec<usize>> {
+        let mut adjacency = vec![Vec::new(); self.names.len()];
+        for (e, (from, _, _)) in self.edges.iter().enumerate() {
+            if !removed[e] {
+                adjacency[*from].push(e);
+            }
+        }
+        adjacency
+    }
+
+    /// 枚举基本环（边下标序列），每个环从下标最小的节点开始；返回是否被截断
+    fn elementary_cycles(&self, removed: &[bool]) -> (Vec<Vec<usize>>, bool) {
+        let adjacency = self.adjacency(removed);
+        let mut search = CycleSearch {
+            graph: self,
+            adjacency: &adjacency,
+            on_path: vec![false; self.names.len()],
+            path: Vec::new(),
+            cycles: Vec::new(),
+            steps: 0,
+            truncated: false,
+        };
+        for start in 0..self.names.len() {
+            if search.truncated {
+                break;
+            }
+            search.on_path[start] = true;
+            search.visit(start, start);
+            search.on_path[start] = false;
+        }
+
+        let mut cycles = search.cycles;
+        cycles.sort_by_key(|cycle| cycle.len());
+        (cycles, search.truncated)
+    }
+
+    /// 移除部分边后是否无环（Kahn 拓扑排序）
+    fn is_acyclic(&self, removed: &[bool]) -> bool {
+        let mut in_degree = vec![0; self.names.len()];
+        for (e, (_, to, _)) in self.edges.iter().enumerate() {
+            if !removed[e] {
+                in_degree[*to] += 1;
+            }
+        }
+
+        let adjacency = self.adjacency(removed);
+        let mut queue: VecDeque<usize> =
+            (0..self.names.len()).filter(|&node| in_degree[node] == 0).collect();
+        let mut visited = 0;
+        while let Some(node) = queue.pop_front() {
+            visited += 1;
+            for &e in &adjacency[node] {
+                let to = self.edges[e].1;
+                in_degree[to] -= 1;
+                if in_degree[to] == 0 {
+                    queue.push_back(to);
+                }
+            }
+        }
+        visited == self.names.len()
+    }
+
+    /// 断开后无环的最少边（边下标）；边数相同时选择生产依赖最少的方案
+    fn feedback_edges(&self) -> Vec<usize> {
+        if self.edges.len() <= EXACT_EDGE_LIMIT {
+            self.exact_feedback_edges()
+        } else {
+            self.greedy_feedback_edges()
+        }
+    }
+
+    /// 边的断开代价（开发依赖和对等依赖为 0）
+    fn cost(&self, e: usize) -> usize {
+        usize::from(!self.edges[e].2.is_soft())
+    }
+
+    /// 穷举所有边集，按（边数，代价）取最小
+    fn exact_feedback_edges(&self) -> Vec<usize> {
+        let count = self.edges.len();
+        let mut best: Option<((u32, usize), u32)> = None;
+        for mask in 1u32..(1 << count) {
+            let removed: Vec<bool> = (0..count).map(|e| mask & (1 << e) != 0).collect();
+            let cost = (0..count).filter(|&e| removed[e]).map(|e| self.cost(e)).sum();
+            let key = (mask.count_ones(), cost);
+            if best.is_some_and(|(best_key, _)| key >= best_key) {
+                continue;
+            }
+            if self.is_acyclic(&removed) {
+                best = Some((key, mask));
+            }
+        }
+
+        let mask = best.map(|(_, mask)| mask).unwrap_or(0);
+        (0..count).filter(|&e| mask & (1 << e) != 0).collect()
+    }
+
+    /// 反复断开出现在最多基本环中的边（同样多时优先开发依赖和对等依赖），最后去掉多余的边
+    ///
+    /// 基本环的枚举受 MAX_CYCLES 和 MAX_STEPS 限制，因此以拓扑排序判断是否已经无环，
+    /// 枚举没有找到环时改为断开深度优先搜索找到的任意一个环，保证结果断开所有循环。
+    fn greedy_feedback_edges(&self) -> Vec<usize> {
+        let count = self.edges.len();
+        let mut removed = vec![false; count];
+
+        while !self.is_acyclic(&removed) {
+            let (mut cycles, _) = self.elementary_cycles(&removed);
+            if cycles.is_empty() {
+                cycles.extend(self.find_cycle(&removed));
+            }
+            let mut occurrences = vec![0usize; count];
+            for e in cycles.iter().flatten() {
+                occurrences[*e] += 1;
+            }
+            let Some(edge) = (0..count)
+                .filter(|&e| occurrences[e] > 0)
+                .max_by_key(|&e| (occurrences[e], usize::MAX - self.cost(e), usize::MAX - e))
+            else {
+                break;
+            };
+            removed[edge] = true;
+        }
+
+        // 先尝试恢复代价高的边
+        let mut selected: Vec<usize> = (0..count).filter(|&e| removed[e]).collect();
+        selected.sort_by_key(|&e| usize::MAX - self.cost(e));
+        for e in selected {
+            removed[e] = false;
+            if !self.is_acyclic(&removed) {
+                removed[e] = true;
+            }
+        }
+        (0..count).filter(|&e| removed[e]).collect()
+    }
+
+    /// 找出任意一个环（边下标序列），不受枚举数量限制；无环时返回 None
+    fn find_cycle(&self, removed: &[bool]) -> Option<Vec<usize>> {
+        let adjacency = self.adjacency(removed);
+        let mut on_path = vec![false; self.names.len()];
+        let mut finished = vec![false; self.names.len()];
+        let mut path = Vec::new();
+        (0..self.names.len()).find_map(|start| {
+            self.cycle_from(start, &adjacency, &mut on_path, &mut finished, &mut path)
+        })
+    }
+
+    /// 从 node 深度优先搜索，遇到当前路径上的节点时返回路径中构成环的部分
+    fn cycle_from(
+        &self,
+        node: usize,
+        adjacency: &[Vec<usize>],
+        on_path: &mut [bool],
+        finished: &mut [bool],
+        path: &mut Vec<usize>,
+    ) -> Option<Vec<usize>> {
+        if finished[node] {
+            return None;
+        }
+        on_path[node] = true;
+        for &e in &adjacency[node] {
+            let to = self.edges[e].1;
+            if on_path[to] {
+                let begin = path.iter().position(|&p| self.edges[p].0 == to).unwrap_or(path.len());
+                let mut cycle = path[begin..].to_vec();
+                cycle.push(e);
+                return Some(cycle);
+            }
+            path.push(e);
+            if let Some(cycle) = self.cycle_from(to, adjacency, on_path, finished, path) {
+                return Some(cycle);
+            }
+            path.pop();
+        }
+        on_path[node] = false;
+        finished[node] = true;
+        None
+    }
+
+    /// 由边下标序列生成基本环
+    fn to_cycle(&self, edges: &[usize]) -> ElementaryCycle {
+        ElementaryCycle {
+            packages: edges.iter().map(|&e| self.names[self.edges[e].0].clone()).collect(),
+            edges: edges.iter().map(|&e| self.edges[e].2.clone()).collect(),
+        }
+    }
+}
+
+/// 基本环深度优先搜索状态
+struct CycleSearch<'a> {
+    /// 分量图
+    graph: &'a ComponentGraph,
+    /// 出边
+    adjacency: &'a [Vec<usize>],
+    /// 节点是否在当前路径上
+    on_path: Vec<bool>,
+    /// 当前路径（边下标）
+    path: Vec<usize>,
+    /// 找到的基本环
+    cycles: Vec<Vec<usize>>,
+    /// 已访问的步数
+    steps: usize,
+    /// 是否因数量限制提前结束
+    truncated: bool,
+}
+
+impl CycleSearch<'_> {
+    /// 从 node 出发，只经过下标大于 start 的节点寻找回到 start 的路径
+    fn visit(&mut self, start: usize, node: usize) {
+        for &e in &self.adjacency[node] {
+            if self.truncated {
+                return;
+            }
+            self.steps += 1;
+            if self.cycles.len() >= MAX_CYCLES || self.steps > MAX_STEPS {
+                self.truncated = true;
+                return;
+            }
+
+            let to = self.graph.edges[e].1;
+            if to == start {
+                let mut cycle = self.path.clone();
+                cycle.push(e);
+                self.cycles.push(cycle);
+            } else if to > start && !self.on_path[to] {
+                self.on_path[to] = true;
+                self.path.push(e);
+                self.visit(start, to);
+                self.path.pop();
+                self.on_path[to] = false;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::package::DependencyType;
+
+    /// 由边列表（依赖方下标，被依赖的包下标）构建分量图
+    fn graph(nodes: usize, edges: &[(usize, usize)]) -> ComponentGraph {
+        let names: Vec<String> = (0..nodes).map(|i| format!("p{:02}", i)).collect();
+        let edges = edges
+            .iter()
+            .map(|&(from, to)| {
+                let edge = CycleEdge {
+                    from: names[from].clone(),
+                    to: names[to].clone(),
+                    types: vec![DependencyType::Prod],
+                };
+                (from, to, edge)
+            })
+            .collect();
+        ComponentGraph { names, edges }
+    }
+
+    #[test]
+    fn greedy_result_breaks_every_cycle_when_enumeration_is_truncated() {
+        // 完全有向图的基本环远多于 MAX_CYCLES
+        let nodes = 9;
+        let edges: Vec<(usize, usize)> = (0..nodes)
+            .flat_map(|from| (0..nodes).filter(move |&to| to != from).map(move |to| (from, to)))
+            .collect();
+        let graph = graph(nodes, &edges);
+        assert!(graph.elementary_cycles(&vec![false; edges.len()]).1);
+
+        let mut removed = vec![false; edges.len()];
+        for e in graph.greedy_feedback_edges() {
+            removed[e] = true;
+        }
+        assert!(graph.is_acyclic(&removed));
+    }
+
+    #[test]
+    fn finds_a_cycle_without_enumerating() {
+        let graph = graph(4, &[(0, 1), (1, 2), (2, 3), (3, 1)]);
+        assert_eq!(graph.find_cycle(&[false; 4]), Some(vec![1, 2, 3]));
+        assert_eq!(graph.find_cycle(&[false, false, false, true]), None);
+    }
+}
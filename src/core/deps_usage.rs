@@ -0,0 +1,453 @@
+// ============================================================================
+// MonoX - 依赖使用情况分析
+// ============================================================================
+//
+// 文件: src/core/deps_usage.rs
+// 职责: 扫描包内 JS/TS 源码的导入语句，找出未声明的依赖和未使用的依赖
+// 边界:
+//   - ✅ 源码文件收集（跳过 node_modules、隐藏目录、构建产物、忽略模式和嵌套的包）
+//   - ✅ import / export from / require / 动态 import 说明符提取
+//   - ✅ 说明符到包名的解析（Node 内置模块、相对路径和别名不计入）
+//   - ✅ package.json 脚本中使用的命令行工具和 @types 包的对应关系
+//   - ❌ 不应包含 TypeScript 路径别名和打包器配置解析
+//   - ❌ 不应包含输出格式化
+//
+// ============================================================================
+
+use anyhow::Result;
+use regex::Regex;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+use crate::models::config::{matches_any, Config, DepsUsageConfig};
+use crate::models::deps_usage::{DependencyUsageIssue, DependencyUsageKind, DependencyUsageReport};
+use crate::models::package::{DependencyType, WorkspacePackage};
+
+/// 扫描的源码文件扩展名
+const SOURCE_EXTENSIONS: &[&str] =
+    &["js", "jsx", "mjs", "cjs", "ts", "tsx", "mts", "cts", "vue", "svelte", "astro"];
+
+/// 构建产物和测试报告目录，不扫描
+const OUTPUT_DIRS: &[&str] = &["dist", "coverage", "storybook-static"];
+
+/// 超过该大小的文件视为构建产物，不扫描
+const MAX_FILE_SIZE: u64 = 1024 * 1024;
+
+/// 只被编译器或编译产物使用、源码中不会导入的依赖
+const TOOLING_DEPENDENCIES: &[&str] = &["typescript", "tslib"];
+
+/// Node.js 内置模块
+const NODE_BUILTINS: &[&str] = &[
+    "assert",
+    "async_hooks",
+    "buffer",
+    "child_process",
+    "cluster",
+    "console",
+    "constants",
+    "crypto",
+    "dgram",
+    "diagnostics_channel",
+    "dns",
+    "domain",
+    "events",
+    "fs",
+    "http",
+    "http2",
+    "https",
+    "inspector",
+    "module",
+    "net",
+    "os",
+    "path",
+    "perf_hooks",
+    "process",
+    "punycode",
+    "querystring",
+    "readline",
+    "repl",
+    "stream",
+    "string_decoder",
+    "sys",
+    "timers",
+    "tls",
+    "trace_events",
+    "tty",
+    "url",
+    "util",
+    "v8",
+    "vm",
+    "wasi",
+    "worker_threads",
+    "zlib",
+];
+
+/// 导入说明符提取器
+struct ImportScanner {
+    /// import ... from / export ... from / import "x" / require("x") / import("x")
+    patterns: Vec<Regex>,
+}
+
+/// 单个包中导入的依赖
+#[derive(Default)]
+struct PackageImports {
+    /// 包名 -> 导入位置（"文件:行号"）
+    packages: BTreeMap<String, Vec<String>>,
+    /// 是否导入了 Node.js 内置模块
+    uses_builtins: bool,
+    /// 扫描的文件数
+    files: usize,
+}
+
+/// 检查所有包的源码导入和 package.json 中声明的依赖是否一致
+pub fn check_deps_usage(
+    packages: &[WorkspacePackage],
+    workspace_root: &Path,
+    config: &DepsUsageConfig,
+) -> Result<DependencyUsageReport> {
+    let scanner = ImportScanner::new()?;
+    let package_roots: HashSet<&Path> =
+        packages.iter().map(|p| p.absolute_path.as_path()).collect();
+    let mut sorted: Vec<&WorkspacePackage> = packages.iter().collect();
+    sorted.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut issues = Vec::new();
+    let mut files_scanned = 0;
+    for package in &sorted {
+        let imports = scanner.scan_package(package, workspace_root, &package_roots);
+        files_scanned += imports.files;
+        issues.extend(undeclared_dependencies(package, &imports, config));
+        issues.extend(unused_dependencies(package, &imports, config));
+    }
+
+    Ok(DependencyUsageReport { packages_scanned: sorted.len(), files_scanned, issues })
+}
+
+/// 代码中导入了但没有声明的依赖
+fn undeclared_dependencies(
+    package: &WorkspacePackage,
+    imports: &PackageImports,
+    config: &DepsUsageConfig,
+) -> Vec<DependencyUsageIssue> {
+    imports
+        .packages
+        .iter()
+        .filter(|(name, _)| {
+            **name != package.name
+                && !package.has_dependency(name)
+                && !NODE_BUILTINS.contains(&name.as_str())
+                && !matches_any(&config.ignore_undeclared, name)
+        })
+        .map(|(name, locations)| DependencyUsageIssue {
+            package: package.name.clone(),
+            dependency: name.clone(),
+            kind: DependencyUsageKind::Undeclared,
+            types: Vec::new(),
+            locations: locations.clone(),
+        })
+        .collect()
+}
+
+/// 声明了但代码和脚本中都没有使用的依赖（只是对等依赖的不检查）
+fn unused_dependencies(
+    package: &WorkspacePackage,
+    imports: &PackageImports,
+    config: &DepsUsageConfig,
+) -> Vec<DependencyUsageIssue> {
+    let script_tools = script_tools(package);
+    let is_used = |name: &str| imports.packages.contains_key(name) || script_tools.contains(name);
+
+    let mut names: Vec<&String> = package.dependencies.keys().collect();
+    names.sort();
+    names
+        .into_iter()
+        .filter(|name| {
+            let types = package.dependency_types_of(name);
+            let peer_only = !types.is_empty() && types.iter().all(|t| *t == DependencyType::Peer);
+            !peer_only
+                && !is_used(name)
+                && !types_package_used(name, imports, &is_used)
+                && !TOOLING_DEPENDENCIES.contains(&name.as_str())
+                && !matches_any(&config.ignore_unused, name)
+        })
+        .map(|name| DependencyUsageIssue {
+            package: package.name.clone(),
+            dependency: name.clone(),
+            kind: DependencyUsageKind::Unused,
+            types: package.dependency_types_of(name).to_vec(),
+            locations: Vec::new(),
+        })
+        .collect()
+}
+
+/// @types 包对应的包被使用时视为已使用（@types/node 对应 Node.js 内置模块）
+fn types_package_used(
+    name: &str,
+    imports: &PackageImports,
+    is_used: &impl Fn(&str) -> bool,
+) -> bool {
+    let Some(target) = name.strip_prefix("@types/") else {
+        return false;
+    };
+    if target == "node" {
+        return imports.uses_builtins;
+    }
+    // @types/babel__core 对应 @babel/core
+    let target = match target.split_once("__") {
+        Some((scope, name)) => format!("@{}/{}", scope, name),
+        None => target.to_string(),
+    };
+    is_used(&target)
+}
+
+/// package.json 脚本中出现的命令和包名（如 "tsc -p . && vite build" 中的 vite）
+fn script_tools(package: &WorkspacePackage) -> BTreeSet<String> {
+    let mut tools = BTreeSet::new();
+    for script in package.scripts.values() {
+        for token in script.split(|c: char| c.is_whitespace() || "&|;()=\"'".contains(c)) {
+            if let Some(name) = package_name(token) {
+                tools.insert(name);
+            }
+        }
+    }
+
+    // 作用域包的命令名通常是包名的最后一段（如 @biomejs/biome 提供 biome）
+    for name in package.dependencies.keys() {
+        if let Some((_, bin)) = name.split_once('/') {
+            if tools.contains(bin) {
+                tools.insert(name.clone());
+            }
+        }
+    }
+    tools
+}
+
+impl ImportScanner {
+    /// 编译导入语句的正则表达式
+    fn new() -> Result<Self> {
+        let patterns = [
+            r#"(?:^|[^.\w$])(?:import|export)\b[^'";]*?\bfrom\s*['"]([^'"\n]+)['"]"#,
+            r#"(?:^|[^.\w$])import\s*['"]([^'"\n]+)['"]"#,
+            r#"(?:^|[^.\w$])(?:require(?:\.resolve)?|import)\s*\(\s*['"]([^'"\n]+)['"]\s*[,)]"#,
+        ];
+        let patterns = patterns.iter().map(|p| Regex::new(p)).collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { patterns })
+    }
+
+    /// 扫描包内所有源码文件
+    fn scan_package(
+        &self,
+        package: &WorkspacePackage,
+        workspace_root: &Path,
+        package_roots: &HashSet<&Path>,
+    ) -> PackageImports {
+        let mut imports = PackageImports::default();
+        for file in source_files(&package.absolute_path, workspace_root, package_roots) {
+            let Ok(source) = fs::read_to_string(&file) else {
+                continue;
+            };
+            imports.files += 1;
+
+            let relative = file.strip_prefix(&package.absolute_path).unwrap_or(&file);
+            let relative = relative.to_string_lossy().replace('\\', "/");
+            for (specifier, line) in self.specifiers(&source) {
+                if specifier.starts_with("node:") {
+                    imports.uses_builtins = true;
+                    continue;
+                }
+                let Some(name) = package_name(&specifier) else {
+                    continue;
+                };
+                if NODE_BUILTINS.contains(&name.as_str()) {
+                    imports.uses_builtins = true;
+                }
+                let locations = imports.packages.entry(name).or_default();
+                locations.push(format!("{}:{}", relative, line));
+            }
+        }
+        imports
+    }
+
+    /// 提取源码中的导入说明符和所在行号（忽略注释和字符串中的内容）
+    fn specifiers(&self, source: &str) -> Vec<(String, usize)> {
+        let (code, string_starts) = strip_comments(source);
+        let line_starts: Vec<usize> =
+            std::iter::once(0).chain(code.match_indices('\n').map(|(i, _)| i + 1)).collect();
+
+        let mut found = Vec::new();
+        for pattern in &self.patterns {
+            for captures in pattern.captures_iter(&code) {
+                let Some(specifier) = captures.get(1) else {
+                    continue;
+                };
+                // 说明符的引号必须是一个字符串的开头，排除字符串中的 "import x from 'y'"
+                if !string_starts.contains(&(specifier.start() - 1)) {
+                    continue;
+                }
+                let line = line_starts.partition_point(|&start| start <= specifier.start());
+                found.push((specifier.as_str().trim().to_string(), line));
+            }
+        }
+        found.sort_by_key(|(_, line)| *line);
+        found
+    }
+}
+
+/// 收集包目录下的源码文件（其他工作区包的目录除外）
+fn source_files(
+    package_dir: &Path,
+    workspace_root: &Path,
+    package_roots: &HashSet<&Path>,
+) -> Vec<PathBuf> {
+    WalkDir::new(package_dir)
+        .follow_links(false)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| {
+            if entry.depth() == 0 || !entry.file_type().is_dir() {
+                return true;
+            }
+            let name = entry.file_name().to_string_lossy();
+            if name.starts_with('.') || name == "node_modules" || OUTPUT_DIRS.contains(&&*name) {
+                return false;
+            }
+            // 嵌套的工作区包单独扫描，其它带 package.json 的目录（如测试夹具）仍属于当前包
+            if package_roots.contains(entry.path()) {
+                return false;
+            }
+            let relative = entry.path().strip_prefix(workspace_root).unwrap_or(entry.path());
+            !Config::should_ignore_path(&relative.to_string_lossy()).unwrap_or(false)
+        })
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            entry.file_type().is_file()
+                && entry
+                    .path()
+                    .extension()
+                    .and_then(|ext| ext.to_str())
+                    .is_some_and(|ext| SOURCE_EXTENSIONS.contains(&ext))
+                && entry.metadata().is_ok_and(|meta| meta.len() <= MAX_FILE_SIZE)
+        })
+        .map(|entry| entry.into_path())
+        .collect()
+}
+
+/// 说明符对应的包名（"@scope/name/sub" -> "@scope/name"，"name/sub" -> "name"）
+///
+/// 相对路径、绝对路径、带协议的说明符（node:、virtual: 等）以及 #、~、@/ 开头的别名返回 None
+fn package_name(specifier: &str) -> Option<String> {
+    if specifier.is_empty()
+        || specifier.contains(':')
+        || specifier.starts_with(['.', '/', '#', '~', '$', '-'])
+    {
+        return None;
+    }
+
+    let mut segments = specifier.split('/');
+    let name = if specifier.starts_with('@') {
+        let scope = segments.next()?;
+        let name = segments.next()?;
+        if scope.len() < 2 || name.is_empty() {
+            return None;
+        }
+        format!("{}/{}", scope, name)
+    } else {
+        segments.next()?.to_string()
+    };
+
+    let valid = name.chars().all(|c| c.is_ascii_alphanumeric() || "-._@/".contains(c))
+        && name.chars().any(|c| c.is_ascii_alphabetic());
+    valid.then_some(name)
+}
+
+/// 把注释替换为空白（保留换行以便计算行号），字符串和模板字符串原样保留
+///
+/// 同时返回结果中每个字符串开头引号的位置
+fn strip_comments(source: &str) -> (String, HashSet<usize>) {
+    #[derive(Clone, Copy, PartialEq)]
+    enum State {
+        Code,
+        Quoted(char),
+        LineComment,
+        BlockComment,
+    }
+
+    let mut result = String::with_capacity(source.len());
+    let mut string_starts = HashSet::new();
+    let mut state = State::Code;
+    let mut chars = source.chars().peekable();
+    while let Some(c) = chars.next() {
+        match state {
+            State::Code => match c {
+                '/' if chars.peek() == Some(&'/') => {
+                    chars.next();
+                    result.push_str("  ");
+                    state = State::LineComment;
+                }
+                '/' if chars.peek() == Some(&'*') => {
+                    chars.next();
+                    result.push_str("  ");
+                    state = State::BlockComment;
+                }
+                '\'' | '"' | '`' => {
+                    string_starts.insert(result.len());
+                    result.push(c);
+                    state = State::Quoted(c);
+                }
+                _ => result.push(c),
+            },
+            State::Quoted(quote) => {
+                result.push(c);
+                if c == '\\' {
+                    if let Some(escaped) = chars.next() {
+                        result.push(escaped);
+                    }
+                } else if c == quote || (c == '\n' && quote != '`') {
+                    state = State::Code;
+                }
+            }
+            State::LineComment => {
+                if c == '\n' {
+                    result.push(c);
+                    state = State::Code;
+                } else {
+                    result.push(' ');
+                }
+            }
+            State::BlockComment => {
+                if c == '*' && chars.peek() == Some(&'/') {
+                    chars.next();
+                    result.push_str("  ");
+                    state = State::Code;
+                } else {
+                    result.push(if c == '\n' { '\n' } else { ' ' });
+                }
+            }
+        }
+    }
+    (result, string_starts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skips_only_nested_workspace_packages() {
+        let root = tempfile::tempdir().unwrap();
+        let package_dir = root.path().join("app");
+        let nested = package_dir.join("plugins/nested");
+        let fixture = package_dir.join("test/fixtures/project");
+        for dir in [&nested, &fixture] {
+            fs::create_dir_all(dir).unwrap();
+            fs::write(dir.join("package.json"), "{}").unwrap();
+            fs::write(dir.join("index.js"), "").unwrap();
+        }
+
+        let package_roots: HashSet<&Path> = [package_dir.as_path(), nested.as_path()].into();
+        let files = source_files(&package_dir, root.path(), &package_roots);
+        assert_eq!(files, vec![fixture.join("index.js")]);
+    }
+}
@@ -0,0 +1,270 @@
+// ============================================================================
+// MonoX - 任务环境变量解析
+// ============================================================================
+//
+// 文件: src/core/env.rs
+// 职责: 任务执行环境变量的合并、过滤与脱敏
+// 边界:
+//   - ✅ env 文件加载和解析（工作区根目录、包目录）
+//   - ✅ 工作区级、任务级环境变量合并
+//   - ✅ 内置变量注入（MONOX_PACKAGE_NAME 等）
+//   - ✅ 父进程环境变量透传的允许/禁止列表
+//   - ✅ 敏感变量值在输出中的脱敏
+//   - ❌ 不应包含任务执行逻辑
+//   - ❌ 不应包含 UI 显示逻辑
+//   - ❌ 不应包含 CLI 相关逻辑
+//
+// ============================================================================
+
+use glob::{MatchOptions, Pattern};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::models::config::Config;
+use crate::models::package::WorkspacePackage;
+use crate::tf;
+use crate::utils::logger::Logger;
+
+/// 始终透传的系统变量（缺失会导致包管理器无法运行）
+const REQUIRED_PASSTHROUGH: &[&str] =
+    &["PATH", "HOME", "USERPROFILE", "SYSTEMROOT", "COMSPEC", "PATHEXT", "TEMP", "TMP", "TMPDIR"];
+
+/// 默认的敏感变量名模式（AUTH 只匹配独立的单词，避免误伤 GIT_AUTHOR_NAME 等变量）
+const DEFAULT_SECRET_PATTERNS: &[&str] = &[
+    "*TOKEN*",
+    "*SECRET*",
+    "*PASSWORD*",
+    "*PASSWD*",
+    "*API_KEY*",
+    "*PRIVATE_KEY*",
+    "*_AUTH",
+    "*_AUTH_*",
+];
+
+/// 脱敏占位符
+const MASK: &str = "***";
+
+/// 短于该长度的值不脱敏，避免误伤普通文本
+const MIN_SECRET_LEN: usize = 4;
+
+/// 变量名匹配选项（不区分大小写）
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: false,
+    require_literal_separator: false,
+    require_literal_leading_dot: false,
+};
+
+/// 任务环境变量解析器
+///
+/// 优先级（从低到高）: env 文件 < 父进程环境 < 工作区 env < 任务 env < 内置变量
+#[derive(Debug, Clone, Default)]
+pub struct EnvResolver {
+    /// 工作区根目录
+    workspace_root: PathBuf,
+    /// 按顺序加载的 env 文件名
+    env_files: Vec<String>,
+    /// 工作区级环境变量
+    workspace_env: BTreeMap<String, String>,
+    /// 任务级环境变量
+    task_env: BTreeMap<String, String>,
+    /// 允许透传的父进程变量模式
+    passthrough: Vec<Pattern>,
+    /// 禁止透传的父进程变量模式
+    deny: Vec<Pattern>,
+    /// 敏感变量名模式
+    secrets: Vec<Pattern>,
+    /// 透传的父进程变量中需要脱敏的值（创建时计算一次）
+    inherited_secrets: Vec<String>,
+}
+
+impl EnvResolver {
+    /// 从全局配置创建解析器
+    pub fn from_config() -> Self {
+        let workspace = Config::get_workspace_config();
+        let secrets = DEFAULT_SECRET_PATTERNS
+            .iter()
+            .map(|p| p.to_string())
+            .chain(workspace.env_secrets.iter().cloned())
+            .collect::<Vec<_>>();
+
+        let mut resolver = Self {
+            workspace_root: Config::get_workspace_root(),
+            env_files: workspace.env_files,
+            workspace_env: workspace.env,
+            task_env: BTreeMap::new(),
+            passthrough: compile_patterns(&workspace.env_passthrough),
+            deny: compile_patterns(&workspace.env_deny),
+            secrets: compile_patterns(&secrets),
+            inherited_secrets: Vec::new(),
+        };
+        resolver.inherited_secrets = resolver
+            .inherited_env()
+            .into_iter()
+            .filter(|(key, value)| value.len() >= MIN_SECRET_LEN && resolver.is_secret(key))
+            .map(|(_, value)| value)
+            .collect();
+        resolver
+    }
+
+    /// 设置任务级环境变量
+    pub fn with_task_env(mut self, task_env: BTreeMap<String, String>) -> Self {
+        self.task_env = task_env;
+        self
+    }
+
+    /// 是否需要过滤父进程环境变量（配置了允许或禁止列表）
+    pub fn filters_parent(&self) -> bool {
+        !self.passthrough.is_empty() || !self.deny.is_empty()
+    }
+
+    /// 父进程中允许透传给任务的环境变量
+    pub fn inherited_env(&self) -> HashMap<String, String> {
+        std::env::vars().filter(|(key, _)| self.is_passed_through(key)).collect()
+    }
+
+    /// 生成任务的环境变量（不含透传的父进程变量）
+    pub fn resolve(
+        &self,
+        package: &WorkspacePackage,
+        stage: usize,
+        command: &str,
+    ) -> HashMap<String, String> {
+        let inherited = self.inherited_env();
+        let mut env = HashMap::new();
+
+        // env 文件：先工作区根目录，再包目录；父进程中已存在的变量不被覆盖
+        let package_dir = self.workspace_root.join(&package.folder);
+        let mut dirs = vec![self.workspace_root.clone()];
+        if package_dir != self.workspace_root {
+            dirs.push(package_dir);
+        }
+        for dir in &dirs {
+            for file in &self.env_files {
+                for (key, value) in load_env_file(&dir.join(file)) {
+                    if !inherited.contains_key(&key) {
+                        env.insert(key, value);
+                    }
+                }
+            }
+        }
+
+        env.extend(self.workspace_env.clone());
+        env.extend(self.task_env.clone());
+
+        // 内置变量
+        env.insert("MONOX_PACKAGE_NAME".to_string(), package.name.clone());
+        env.insert("MONOX_PACKAGE_VERSION".to_string(), package.version.clone());
+        env.insert("MONOX_PACKAGE_PATH".to_string(), package.folder.to_string_lossy().to_string());
+        env.insert("MONOX_STAGE".to_string(), stage.to_string());
+        env.insert("MONOX_TASK".to_string(), command.to_string());
+        env.insert(
+            "MONOX_WORKSPACE_ROOT".to_string(),
+            self.workspace_root.to_string_lossy().to_string(),
+        );
+
+        env
+    }
+
+    /// 将文本中出现的敏感变量值替换为 ***
+    pub fn mask(&self, env: &HashMap<String, String>, text: &str) -> String {
+        let mut secrets: Vec<&str> = env
+            .iter()
+            .filter(|(key, value)| value.len() >= MIN_SECRET_LEN && self.is_secret(key))
+            .map(|(_, value)| value.as_str())
+            .chain(self.inherited_secrets.iter().map(String::as_str))
+            .collect();
+
+        if secrets.is_empty() {
+            return text.to_string();
+        }
+
+        // 先替换较长的值，避免部分替换
+        secrets.sort_by_key(|value| std::cmp::Reverse(value.len()));
+        secrets.iter().fold(text.to_string(), |masked, secret| masked.replace(secret, MASK))
+    }
+
+    /// 脱敏后的环境变量（用于显示）
+    pub fn masked_env(&self, env: &HashMap<String, String>) -> BTreeMap<String, String> {
+        env.iter()
+            .map(|(key, value)| {
+                let value = if self.is_secret(key) { MASK.to_string() } else { value.clone() };
+                (key.clone(), value)
+            })
+            .collect()
+    }
+
+    /// 判断变量名是否为敏感变量
+    fn is_secret(&self, key: &str) -> bool {
+        self.secrets.iter().any(|p| p.matches_with(key, MATCH_OPTIONS))
+    }
+
+    /// 判断父进程变量是否允许透传
+    fn is_passed_through(&self, key: &str) -> bool {
+        if REQUIRED_PASSTHROUGH.iter().any(|required| required.eq_ignore_ascii_case(key)) {
+            return true;
+        }
+        if self.deny.iter().any(|p| p.matches_with(key, MATCH_OPTIONS)) {
+            return false;
+        }
+        self.passthrough.is_empty()
+            || self.passthrough.iter().any(|p| p.matches_with(key, MATCH_OPTIONS))
+    }
+}
+
+/// 编译变量名模式（忽略无效模式）
+fn compile_patterns(patterns: &[String]) -> Vec<Pattern> {
+    patterns.iter().filter_map(|p| Pattern::new(p).ok()).collect()
+}
+
+/// 加载 env 文件（文件不存在时返回空）
+fn load_env_file(path: &Path) -> Vec<(String, String)> {
+    if !path.is_file() {
+        return Vec::new();
+    }
+
+    match fs::read_to_string(path) {
+        Ok(content) => parse_env_file(&content),
+        Err(e) => {
+            Logger::warn(tf!("env.file_read_failed", path.display(), e));
+            Vec::new()
+        }
+    }
+}
+
+/// 解析 env 文件内容（支持注释、export 前缀、单双引号）
+fn parse_env_file(content: &str) -> Vec<(String, String)> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                return None;
+            }
+
+            let line = line.strip_prefix("export ").unwrap_or(line);
+            let (key, value) = line.split_once('=')?;
+            let key = key.trim();
+            if key.is_empty() || key.contains(char::is_whitespace) {
+                return None;
+            }
+
+            let value = value.trim();
+            let value = if let Some(quoted) = value.strip_prefix('"') {
+                quoted
+                    .rsplit_once('"')
+                    .map(|(inner, _)| inner)
+                    .unwrap_or(quoted)
+                    .replace("\\n", "\n")
+                    .replace("\\\"", "\"")
+            } else if let Some(quoted) = value.strip_prefix('\'') {
+                quoted.rsplit_once('\'').map(|(inner, _)| inner).unwrap_or(quoted).to_string()
+            } else {
+                // 未加引号的值支持行尾注释
+                value.split(" #").next().unwrap_or("").trim().to_string()
+            };
+
+            Some((key.to_string(), value))
+        })
+        .collect()
+}
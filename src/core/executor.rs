@@ -16,15 +16,25 @@
 //
 // ============================================================================
 
+use crate::core::env::EnvResolver;
+use crate::core::history::HistoryStore;
+use crate::core::profile::write_chrome_trace;
+use crate::core::report::write_report;
 use crate::core::{AsyncTaskScheduler, DependencyAnalyzer, SchedulerConfig, SchedulerTaskResult};
 use crate::models::config::Config;
-use crate::models::package::WorkspacePackage;
-use crate::models::{Task, TaskConfig, TaskResult, TaskStatus};
+use crate::models::history::{HostInfo, RunRecord, StageRecord};
+use crate::models::package::{DependencyAnalysisResult, WorkspacePackage};
+use crate::models::plan::{ExecutionPlan, PlannedTask, SelectionReason};
+use crate::models::report::{ReportTarget, RunReport, TaskReport};
+use crate::models::{SkipReason, Task, TaskConfig, TaskResult, TaskStatus};
 use crate::ui::runner::RunnerUI;
-use crate::ui::summary::render_execution_summary;
+use crate::ui::summary::{print_execution_plan, render_execution_summary};
 use crate::utils::logger::Logger;
+use crate::utils::performance::now_millis;
 use crate::{t, tf};
 use anyhow::{Context, Result};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::PathBuf;
 use std::process::{Command, Stdio};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
@@ -37,14 +47,18 @@ struct ExecutionStats {
     failed: usize,
     skipped: usize,
     start_time: Option<Instant>,
+    /// 已完成的任务报告（用于执行历史和运行报告）
+    tasks: Vec<TaskReport>,
+    /// 已完成的阶段记录（用于执行历史）
+    stages: Vec<StageRecord>,
 }
 
 /// 执行命令并返回结果
-async fn run_command(task: &Task) -> Result<TaskResult> {
+async fn run_command(task: &Task, env: &EnvResolver) -> Result<TaskResult> {
     let start_time = Instant::now();
 
     let package_manager = Config::get_package_manager().as_str();
-    let command_str = &format!("{} run {}", package_manager, task.command);
+    let command_str = &command_line(task);
 
     // 构建命令
     let mut command = Command::new(package_manager);
@@ -53,6 +67,11 @@ async fn run_command(task: &Task) -> Result<TaskResult> {
     // 执行命令目录
     let working_directory = Config::get_workspace_root().join(&task.working_directory);
 
+    // 按允许/禁止列表过滤父进程环境变量
+    if env.filters_parent() {
+        command.env_clear().envs(env.inherited_env());
+    }
+
     command
         .args(&task.args)
         .current_dir(&working_directory)
@@ -70,8 +89,8 @@ async fn run_command(task: &Task) -> Result<TaskResult> {
 
     let duration = start_time.elapsed();
     let exit_code = output.status.code().unwrap_or(-1);
-    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+    let stdout = env.mask(&task.env_vars, &String::from_utf8_lossy(&output.stdout));
+    let stderr = env.mask(&task.env_vars, &String::from_utf8_lossy(&output.stderr));
     let success = output.status.success();
 
     // 在详细模式下输出命令输出
@@ -94,11 +113,21 @@ async fn run_command(task: &Task) -> Result<TaskResult> {
     Ok(result)
 }
 
+/// 任务实际执行的完整命令行
+fn command_line(task: &Task) -> String {
+    let mut parts = vec![Config::get_package_manager().as_str().to_string(), "run".to_string()];
+    parts.push(task.command.clone());
+    parts.extend(task.args.iter().cloned());
+    parts.join(" ")
+}
+
 /// 执行单个任务
 async fn execute_task(
     task: &mut Task,
+    stage: usize,
+    env: Arc<EnvResolver>,
     ui: Option<Arc<Mutex<RunnerUI>>>,
-    stats_collector: Option<Arc<Mutex<ExecutionStats>>>,
+    stats_collector: Arc<Mutex<ExecutionStats>>,
 ) -> Result<()> {
     let task_id = format!("{}:{}", task.package_name, task.command);
 
@@ -122,8 +151,10 @@ async fn execute_task(
         }
 
         // 更新统计 - 跳过的任务
-        if let Some(collector) = &stats_collector {
-            collector.lock().unwrap().skipped += 1;
+        {
+            let mut stats = stats_collector.lock().unwrap();
+            stats.skipped += 1;
+            stats.tasks.push(TaskReport::from_task(task, stage));
         }
 
         return Ok(());
@@ -132,7 +163,7 @@ async fn execute_task(
     let start_time = Instant::now();
 
     // 执行命令
-    let result = run_command(task).await?;
+    let result = run_command(task, &env).await?;
 
     // 更新任务状态
     task.complete(result);
@@ -176,27 +207,72 @@ async fn execute_task(
     }
 
     // 更新统计 - 成功或失败的任务
-    if let Some(collector) = &stats_collector {
+    {
+        let mut stats = stats_collector.lock().unwrap();
         if task.is_success() {
-            collector.lock().unwrap().successful += 1;
+            stats.successful += 1;
         } else {
-            collector.lock().unwrap().failed += 1;
+            stats.failed += 1;
         }
+        stats.tasks.push(TaskReport::from_task(task, stage));
     }
 
     Ok(())
 }
 
+/// 目标包没有全部安排到构建阶段时报错，而不是跳过未安排的包
+fn ensure_schedulable(result: &DependencyAnalysisResult) -> Result<()> {
+    if result.stages.is_empty() && !result.circular_dependencies.is_empty() {
+        let cycles: Vec<String> =
+            result.circular_dependencies.iter().map(|cycle| cycle.join(", ")).collect();
+        anyhow::bail!(tf!("run.circular_blocked", cycles.join("; ")));
+    }
+
+    let scheduled: HashSet<&str> =
+        result.stages.iter().flatten().map(|package| package.name.as_str()).collect();
+    let mut unscheduled: Vec<&str> = result
+        .packages
+        .iter()
+        .map(|package| package.name.as_str())
+        .filter(|name| !scheduled.contains(name))
+        .collect();
+    if !unscheduled.is_empty() {
+        unscheduled.sort();
+        anyhow::bail!(tf!("analyze.unschedulable", unscheduled.join(", ")));
+    }
+    Ok(())
+}
+
 /// 基础任务执行器
 pub struct TaskExecutor {
     /// 任务配置
     config: TaskConfig,
+    /// 性能剖析文件输出路径
+    profile_output: Option<PathBuf>,
+    /// 运行报告输出目标
+    reports: Vec<ReportTarget>,
+    /// 是否只输出执行计划（试运行）
+    dry_run: bool,
+    /// 执行计划输出格式 (table, json)
+    plan_format: String,
+    /// 任务环境变量解析器
+    env: Arc<EnvResolver>,
+    /// 是否允许循环依赖（同一循环中的包在同一阶段执行）
+    allow_cycles: bool,
 }
 
 impl TaskExecutor {
     /// 创建新的任务执行器
     pub fn new(config: TaskConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            profile_output: None,
+            reports: Vec::new(),
+            dry_run: false,
+            plan_format: "table".to_string(),
+            env: Arc::new(EnvResolver::from_config()),
+            allow_cycles: false,
+        }
     }
 
     /// 从全局配置创建任务执行器
@@ -206,7 +282,62 @@ impl TaskExecutor {
             verbose: Config::get_verbose(),
             ..Default::default()
         };
-        Ok(Self { config })
+        Ok(Self {
+            config,
+            profile_output: None,
+            reports: Vec::new(),
+            dry_run: false,
+            plan_format: "table".to_string(),
+            env: Arc::new(EnvResolver::from_config()),
+            allow_cycles: false,
+        })
+    }
+
+    /// 设置性能剖析文件输出路径（Chrome Trace 格式）
+    pub fn with_profile_output(mut self, profile_output: Option<PathBuf>) -> Self {
+        self.profile_output = profile_output;
+        self
+    }
+
+    /// 设置运行报告输出目标（JSON / JUnit）
+    pub fn with_reports(mut self, reports: Vec<ReportTarget>) -> Self {
+        self.reports = reports;
+        self
+    }
+
+    /// 允许循环依赖：同一循环中的包在同一阶段执行，不再因循环依赖拒绝执行
+    pub fn with_allow_cycles(mut self, allow_cycles: bool) -> Self {
+        self.allow_cycles = allow_cycles;
+        self
+    }
+
+    /// 设置试运行模式：只输出执行计划，不执行任何命令
+    pub fn with_dry_run(mut self, dry_run: bool, plan_format: &str) -> Self {
+        self.dry_run = dry_run;
+        self.plan_format = plan_format.to_string();
+        self
+    }
+
+    /// 设置任务级环境变量（来自 monox.toml 中的任务定义）
+    pub fn with_task_env(mut self, task_env: BTreeMap<String, String>) -> Self {
+        self.env = Arc::new(EnvResolver::from_config().with_task_env(task_env));
+        self
+    }
+
+    /// 创建包任务（注入解析后的环境变量）
+    fn create_task(&self, package: &WorkspacePackage, command: &str, stage: usize) -> Task {
+        Task::new(
+            package.name.clone(),
+            package.folder.to_string_lossy().to_string(),
+            command.to_string(),
+            vec![],
+        )
+        .with_env_vars(self.env.resolve(package, stage, command))
+    }
+
+    /// 是否以 JSON 输出执行计划（此时不输出过程日志，保证输出可解析）
+    fn is_json_plan(&self) -> bool {
+        self.dry_run && self.plan_format == "json"
     }
 
     /// 通用执行方法，支持 run 和 exec 两种调用方式
@@ -235,11 +366,15 @@ impl TaskExecutor {
         // 获取工作区根目录（从全局配置中获取）
         let workspace_root = Config::get_workspace_root();
         // 创建分析器，获取包信息
-        let mut analyzer =
-            DependencyAnalyzer::new(workspace_root.to_path_buf()).with_verbose(self.config.verbose);
+        let mut analyzer = DependencyAnalyzer::new(workspace_root.to_path_buf())
+            .with_verbose(self.config.verbose)
+            .with_allow_cycles(self.allow_cycles);
         let analysis_result = analyzer.analyze_packages(package_names)?;
+        ensure_schedulable(&analysis_result)?;
 
-        Logger::info(tf!("run.scanning_packages", package_names.join(", ")));
+        if !self.is_json_plan() {
+            Logger::info(tf!("run.scanning_packages", package_names.join(", ")));
+        }
 
         // 验证所有指定的包都存在
         for package_name in package_names {
@@ -263,9 +398,12 @@ impl TaskExecutor {
             .filter(|pkg| pkg.scripts.contains_key(command))
             .count();
 
-        Logger::info(tf!("run.found_executable_packages", executable_count, command));
+        if !self.is_json_plan() {
+            Logger::info(tf!("run.found_executable_packages", executable_count, command));
+        }
 
-        self.execute_stages(&analysis_result.stages, command, post_command).await
+        self.execute_stages(&analysis_result.stages, command, post_command, Some(package_names))
+            .await
     }
 
     /// 执行所有包（all = true）
@@ -277,11 +415,15 @@ impl TaskExecutor {
         // 获取工作区根目录（从全局配置中获取）
         let workspace_root = Config::get_workspace_root();
         // 创建分析器，获取包信息
-        let mut analyzer =
-            DependencyAnalyzer::new(workspace_root.to_path_buf()).with_verbose(self.config.verbose);
+        let mut analyzer = DependencyAnalyzer::new(workspace_root.to_path_buf())
+            .with_verbose(self.config.verbose)
+            .with_allow_cycles(self.allow_cycles);
         let analysis_result = analyzer.analyze_workspace()?;
+        ensure_schedulable(&analysis_result)?;
 
-        Logger::info(t!("run.scanning_all_packages"));
+        if !self.is_json_plan() {
+            Logger::info(t!("run.scanning_all_packages"));
+        }
 
         // 检查包是否有指定的脚本
         let executable_packages: Vec<_> = analysis_result
@@ -303,9 +445,11 @@ impl TaskExecutor {
             anyhow::bail!(tf!("run.no_executable_packages", command));
         }
 
-        Logger::info(tf!("run.found_executable_packages", executable_packages.len(), command));
+        if !self.is_json_plan() {
+            Logger::info(tf!("run.found_executable_packages", executable_packages.len(), command));
+        }
 
-        self.execute_stages(&analysis_result.stages, command, post_command).await
+        self.execute_stages(&analysis_result.stages, command, post_command, None).await
     }
 
     /// 执行单个包
@@ -318,9 +462,11 @@ impl TaskExecutor {
         // 获取工作区根目录（从全局配置中获取）
         let workspace_root = Config::get_workspace_root();
         // 创建分析器，获取包信息
-        let mut analyzer =
-            DependencyAnalyzer::new(workspace_root.to_path_buf()).with_verbose(self.config.verbose);
+        let mut analyzer = DependencyAnalyzer::new(workspace_root.to_path_buf())
+            .with_verbose(self.config.verbose)
+            .with_allow_cycles(self.allow_cycles);
         let analysis_result = analyzer.analyze_single_package(package_name)?;
+        ensure_schedulable(&analysis_result)?;
 
         // 查找指定的包
         let package = analysis_result
@@ -342,9 +488,12 @@ impl TaskExecutor {
             .filter(|pkg| pkg.scripts.contains_key(command))
             .count();
 
-        Logger::info(tf!("run.found_executable_packages", executable_count, command));
+        if !self.is_json_plan() {
+            Logger::info(tf!("run.found_executable_packages", executable_count, command));
+        }
 
-        self.execute_stages(&analysis_result.stages, command, post_command).await
+        let targets = [package_name.to_string()];
+        self.execute_stages(&analysis_result.stages, command, post_command, Some(&targets)).await
     }
 
     /// 执行阶段任务
@@ -353,15 +502,26 @@ impl TaskExecutor {
         stages: &Vec<Vec<WorkspacePackage>>,
         command: &str,
         post_command: &Option<String>,
+        targets: Option<&[String]>,
     ) -> Result<()> {
+        // 试运行：只输出执行计划
+        if self.dry_run {
+            let plan = self.build_plan(stages, command, post_command, targets);
+            match self.plan_format.as_str() {
+                "json" => println!("{}", serde_json::to_string_pretty(&plan)?),
+                _ => print_execution_plan(&plan),
+            }
+            return Ok(());
+        }
+
         let verbose = self.config.verbose;
+        let run_started_at = now_millis();
 
-        // 在 verbose 模式下，我们需要收集执行统计信息
-        let stats_collector =
-            if verbose { Some(Arc::new(Mutex::new(ExecutionStats::default()))) } else { None };
+        // 收集执行统计信息（verbose 模式汇总和执行历史共用）
+        let stats_collector = Arc::new(Mutex::new(ExecutionStats::default()));
 
-        // 非 verbose 模式下使用 UI 渲染
-        let ui = if !verbose {
+        // 非 verbose 模式下使用 UI 渲染（日志写到标准错误时不绘制，标准输出保留给 JSON 报告）
+        let ui = if !verbose && !Logger::is_stderr_output() {
             let runner_ui = RunnerUI::new(false, true);
             let ui = Arc::new(Mutex::new(runner_ui));
 
@@ -385,13 +545,146 @@ impl TaskExecutor {
         };
 
         // 计算总任务数
-        if let Some(collector) = &stats_collector {
+        {
             let total_tasks: usize = stages.iter().map(|stage| stage.len()).sum();
-            collector.lock().unwrap().total = total_tasks;
-            collector.lock().unwrap().start_time = Some(Instant::now());
+            let mut stats = stats_collector.lock().unwrap();
+            stats.total = total_tasks;
+            stats.start_time = Some(Instant::now());
         }
 
         // 执行阶段
+        let run_result = self
+            .run_all_stages(stages, command, post_command, ui.clone(), stats_collector.clone())
+            .await;
+
+        // 无论成功与否都记录执行历史和性能剖析
+        let run_record =
+            build_run_record(command, run_started_at, &stats_collector, run_result.is_ok());
+        save_run_history(&run_record);
+        if let Some(profile_output) = &self.profile_output {
+            match write_chrome_trace(profile_output, &run_record) {
+                Ok(()) => Logger::info(tf!("profile.saved", profile_output.display())),
+                Err(e) => Logger::warn(tf!("profile.save_failed", e)),
+            }
+        }
+        if !self.reports.is_empty() {
+            let report = build_run_report(&run_record, &stats_collector);
+            for target in &self.reports {
+                match write_report(target, &report) {
+                    Ok(()) => Logger::info(tf!("report.saved", target.path.display())),
+                    Err(e) => Logger::warn(tf!("report.save_failed", e)),
+                }
+            }
+        }
+        run_result?;
+
+        // 显示执行总结
+        if let Some(ui) = &ui {
+            ui.lock().unwrap().render_summary();
+        } else if verbose {
+            let stats = stats_collector.lock().unwrap();
+
+            // 调用完整的汇总渲染函数，使用真实收集的数据
+            render_execution_summary(
+                stats.total,
+                stats.successful,
+                stats.failed,
+                stats.skipped,
+                stats.start_time.map(|start| start.elapsed().as_millis() as u64),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// 生成执行计划（targets 为空表示通过 --all 选中全部包）
+    fn build_plan(
+        &self,
+        stages: &[Vec<WorkspacePackage>],
+        command: &str,
+        post_command: &Option<String>,
+        targets: Option<&[String]>,
+    ) -> ExecutionPlan {
+        let workspace_root = Config::get_workspace_root();
+        let packages: HashMap<&str, &WorkspacePackage> =
+            stages.iter().flatten().map(|pkg| (pkg.name.as_str(), pkg)).collect();
+
+        // 记录每个依赖包是被哪些指定包引入的
+        let mut required_by: HashMap<&str, Vec<String>> = HashMap::new();
+        for target in targets.unwrap_or_default() {
+            let mut visited = HashSet::new();
+            let mut pending = vec![target.as_str()];
+            while let Some(name) = pending.pop() {
+                let Some(package) = packages.get(name) else { continue };
+                for dep in &package.build_dependencies {
+                    if packages.contains_key(dep.as_str()) && visited.insert(dep.as_str()) {
+                        required_by.entry(dep.as_str()).or_default().push(target.clone());
+                        pending.push(dep.as_str());
+                    }
+                }
+            }
+        }
+
+        let mut tasks = Vec::new();
+        for (stage_idx, stage) in stages.iter().enumerate() {
+            for package in stage {
+                let task = self.create_task(package, command, stage_idx + 1);
+
+                let selection = match targets {
+                    None => SelectionReason::All,
+                    Some(targets) if targets.contains(&package.name) => SelectionReason::Requested,
+                    Some(_) => SelectionReason::Dependency {
+                        required_by: required_by
+                            .get(package.name.as_str())
+                            .cloned()
+                            .unwrap_or_default(),
+                    },
+                };
+
+                let mut depends_on: Vec<String> = package
+                    .build_dependencies
+                    .iter()
+                    .filter(|dep| packages.contains_key(dep.as_str()))
+                    .cloned()
+                    .collect();
+                depends_on.sort();
+
+                tasks.push(PlannedTask {
+                    package: package.name.clone(),
+                    version: package.version.clone(),
+                    stage: stage_idx + 1,
+                    command_line: command_line(&task),
+                    working_directory: workspace_root
+                        .join(&task.working_directory)
+                        .to_string_lossy()
+                        .to_string(),
+                    env: self.env.masked_env(&task.env_vars),
+                    depends_on,
+                    selection,
+                    skip_reason: (!package.scripts.contains_key(command))
+                        .then_some(SkipReason::ScriptNotFound),
+                });
+            }
+        }
+
+        ExecutionPlan {
+            command: command.to_string(),
+            post_command: post_command.clone(),
+            package_manager: Config::get_package_manager().as_str().to_string(),
+            total_stages: stages.len(),
+            tasks,
+        }
+    }
+
+    /// 依次执行所有阶段
+    async fn run_all_stages(
+        &self,
+        stages: &[Vec<WorkspacePackage>],
+        command: &str,
+        post_command: &Option<String>,
+        ui: Option<Arc<Mutex<RunnerUI>>>,
+        stats_collector: Arc<Mutex<ExecutionStats>>,
+    ) -> Result<()> {
         for (stage_idx, stage) in stages.iter().enumerate() {
             if let Some(ui) = &ui {
                 let mut ui_lock = ui.lock().unwrap();
@@ -404,31 +697,32 @@ impl TaskExecutor {
                 drop(ui_lock); // 释放锁
             }
 
-            self.execute_single_stage(
-                stage,
-                command,
-                post_command,
-                ui.clone(),
-                stats_collector.clone(),
-            )
-            .await?;
-        }
-
-        // 显示执行总结
-        if let Some(ui) = &ui {
-            ui.lock().unwrap().render_summary();
-        } else if verbose {
-            if let Some(collector) = &stats_collector {
-                let stats = collector.lock().unwrap();
-
-                // 调用完整的汇总渲染函数，使用真实收集的数据
-                render_execution_summary(
-                    stats.total,
-                    stats.successful,
-                    stats.failed,
-                    stats.skipped,
-                    None,
+            let stage_started_at = now_millis();
+            let stage_result = self
+                .execute_single_stage(
+                    stage,
+                    stage_idx + 1,
+                    command,
+                    post_command,
+                    ui.clone(),
+                    stats_collector.clone(),
+                )
+                .await;
+
+            stats_collector.lock().unwrap().stages.push(StageRecord {
+                stage: stage_idx + 1,
+                started_at: stage_started_at,
+                finished_at: now_millis(),
+                tasks: stage.len(),
+            });
+            if let Err(e) = stage_result {
+                record_upstream_failed(
+                    &stages[stage_idx + 1..],
+                    command,
+                    stage_idx + 2,
+                    &stats_collector,
                 );
+                return Err(e);
             }
         }
 
@@ -439,10 +733,11 @@ impl TaskExecutor {
     async fn execute_single_stage(
         &self,
         stage: &Vec<WorkspacePackage>,
+        stage_number: usize,
         command: &str,
         post_command: &Option<String>,
         ui: Option<Arc<Mutex<RunnerUI>>>,
-        stats_collector: Option<Arc<Mutex<ExecutionStats>>>,
+        stats_collector: Arc<Mutex<ExecutionStats>>,
     ) -> Result<()> {
         if stage.is_empty() {
             return Ok(());
@@ -451,13 +746,14 @@ impl TaskExecutor {
         // 单个包时保持原有串行逻辑，避免异步开销
         if stage.len() == 1 {
             let package = &stage[0];
-            let mut task = Task::new(
-                package.name.clone(),
-                package.folder.to_string_lossy().to_string(),
-                command.to_string(),
-                vec![],
-            );
-            return execute_task(&mut task, ui, stats_collector).await;
+            let mut task = self.create_task(package, command, stage_number);
+            execute_task(&mut task, stage_number, self.env.clone(), ui, stats_collector).await?;
+
+            // 与并发执行保持一致：任务失败时返回错误
+            if task.status == TaskStatus::Failed {
+                anyhow::bail!("阶段执行失败: {}", package.name);
+            }
+            return Ok(());
         }
 
         // 多个包时使用并发执行
@@ -483,18 +779,15 @@ impl TaskExecutor {
             .map(|package| {
                 let task_id = format!("{}:{}", package.name, command);
 
-                let mut task = Task::new(
-                    package.name.clone(),
-                    package.folder.to_string_lossy().to_string(),
-                    command.to_string(),
-                    vec![],
-                );
+                let mut task = self.create_task(package, command, stage_number);
 
-                // 克隆 UI 引用和统计收集器用于异步任务
+                // 克隆 UI 引用、环境解析器和统计收集器用于异步任务
+                let env_clone = self.env.clone();
                 let ui_clone = ui.clone();
                 let stats_clone = stats_collector.clone();
-                let task_future =
-                    async move { execute_task(&mut task, ui_clone, stats_clone).await };
+                let task_future = async move {
+                    execute_task(&mut task, stage_number, env_clone, ui_clone, stats_clone).await
+                };
 
                 (task_id, task_future)
             })
@@ -536,7 +829,19 @@ impl TaskExecutor {
                 SchedulerTaskResult::Cancelled => {
                     cancelled_count += 1;
                     Logger::warn(tf!("executor.task_concurrent_cancelled", &task_id));
-                    // 取消的任务不计入统计中
+                    // fail-fast 取消的任务未执行完，记为跳过
+                    if let Some(package) =
+                        stage.iter().find(|p| format!("{}:{}", p.name, command) == task_id)
+                    {
+                        let mut stats = stats_collector.lock().unwrap();
+                        record_skipped(
+                            &mut stats,
+                            package,
+                            command,
+                            stage_number,
+                            SkipReason::Cancelled,
+                        );
+                    }
                 }
             }
         }
@@ -574,3 +879,93 @@ impl TaskExecutor {
         Ok(())
     }
 }
+
+/// 根据执行统计生成本次运行记录
+fn build_run_record(
+    command: &str,
+    started_at: u64,
+    stats_collector: &Arc<Mutex<ExecutionStats>>,
+    success: bool,
+) -> RunRecord {
+    let stats = stats_collector.lock().unwrap();
+    RunRecord {
+        id: format!("{:x}", started_at),
+        command: command.to_string(),
+        started_at,
+        finished_at: now_millis(),
+        success: success && stats.failed == 0,
+        host: HostInfo::current(),
+        tasks: stats.tasks.iter().map(|task| task.record.clone()).collect(),
+        stages: stats.stages.clone(),
+    }
+}
+
+/// 保存本次运行的执行历史（失败时仅警告，不影响执行结果）
+fn save_run_history(record: &RunRecord) {
+    let history_config = Config::get_history_config();
+    if !history_config.enabled {
+        return;
+    }
+
+    let store = HistoryStore::new(&Config::get_workspace_root());
+    if let Err(e) = store.append(record, history_config.max_runs) {
+        Logger::warn(tf!("history.save_failed", e));
+    }
+}
+
+/// 根据运行记录和执行统计生成运行报告
+fn build_run_report(run: &RunRecord, stats_collector: &Arc<Mutex<ExecutionStats>>) -> RunReport {
+    let stats = stats_collector.lock().unwrap();
+    RunReport {
+        command: run.command.clone(),
+        started_at: run.started_at,
+        finished_at: run.finished_at,
+        duration_ms: run.duration_ms(),
+        success: run.success,
+        total: stats.total,
+        successful: stats.successful,
+        failed: stats.failed,
+        skipped: stats.skipped,
+        tasks: stats.tasks.clone(),
+    }
+}
+
+/// 前序阶段失败时，将后续阶段的任务记为跳过
+fn record_upstream_failed(
+    stages: &[Vec<WorkspacePackage>],
+    command: &str,
+    first_stage_number: usize,
+    stats_collector: &Arc<Mutex<ExecutionStats>>,
+) {
+    let mut stats = stats_collector.lock().unwrap();
+    for (offset, stage) in stages.iter().enumerate() {
+        for package in stage {
+            record_skipped(
+                &mut stats,
+                package,
+                command,
+                first_stage_number + offset,
+                SkipReason::UpstreamFailed,
+            );
+        }
+    }
+}
+
+/// 将未执行的任务记为跳过
+fn record_skipped(
+    stats: &mut ExecutionStats,
+    package: &WorkspacePackage,
+    command: &str,
+    stage_number: usize,
+    reason: SkipReason,
+) {
+    let mut task = Task::new(
+        package.name.clone(),
+        package.folder.to_string_lossy().to_string(),
+        command.to_string(),
+        vec![],
+    );
+    task.skip(reason);
+    stats.skipped += 1;
+    stats.tasks.push(TaskReport::from_task(&task, stage_number));
+}
@@ -0,0 +1,88 @@
+// ============================================================================
+// MonoX - Git 操作
+// ============================================================================
+//
+// 文件: src/core/git.rs
+// 职责: 调用 git 命令完成发布相关的提交和标签操作
+// 边界:
+//   - ✅ 仓库检测、暂存、提交、标签
+//   - ✅ git 命令失败时返回带输出的错误
+//   - ❌ 不应包含版本计算逻辑
+//   - ❌ 不应包含 package.json 修改逻辑
+//   - ❌ 不应包含 CLI 参数处理
+//
+// ============================================================================
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::{t, tf};
+
+/// git 仓库
+pub struct GitRepository {
+    /// 执行 git 命令的目录
+    root: PathBuf,
+}
+
+impl GitRepository {
+    /// 打开目录所在的 git 仓库，不在仓库中时返回错误
+    pub fn open(root: &Path) -> Result<Self> {
+        let repository = Self { root: root.to_path_buf() };
+        if repository.run(&["rev-parse", "--is-inside-work-tree"]).is_err() {
+            anyhow::bail!(t!("git.not_repository"));
+        }
+        Ok(repository)
+    }
+
+    /// 暂存文件
+    pub fn add(&self, paths: &[PathBuf]) -> Result<()> {
+        let mut args = vec!["add".to_string(), "--".to_string()];
+        args.extend(paths.iter().map(|path| path.to_string_lossy().to_string()));
+        self.run(&args.iter().map(String::as_str).collect::<Vec<_>>())?;
+        Ok(())
+    }
+
+    /// 只提交指定文件，索引中其他已暂存的修改不会混入提交
+    pub fn commit(&self, message: &str, paths: &[PathBuf]) -> Result<()> {
+        let mut args = vec!["commit".to_string(), "-m".to_string(), message.to_string()];
+        args.push("--".to_string());
+        args.extend(paths.iter().map(|path| path.to_string_lossy().to_string()));
+        self.run(&args.iter().map(String::as_str).collect::<Vec<_>>())?;
+        Ok(())
+    }
+
+    /// 创建附注标签
+    pub fn tag(&self, name: &str) -> Result<()> {
+        self.run(&["tag", "-a", name, "-m", name])?;
+        Ok(())
+    }
+
+    /// 文件是否已被 git 跟踪
+    pub fn is_tracked(&self, path: &Path) -> bool {
+        self.run(&["ls-files", "--error-unmatch", "--", &path.to_string_lossy()]).is_ok()
+    }
+
+    /// 标签是否已存在
+    pub fn tag_exists(&self, name: &str) -> bool {
+        self.run(&["rev-parse", "--quiet", "--verify", &format!("refs/tags/{}", name)]).is_ok()
+    }
+
+    /// 执行 git 命令并返回标准输出
+    fn run(&self, args: &[&str]) -> Result<String> {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(&self.root)
+            .output()
+            .with_context(|| t!("git.spawn_failed"))?;
+
+        if !output.status.success() {
+            anyhow::bail!(tf!(
+                "git.command_failed",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    }
+}
@@ -0,0 +1,181 @@
+// ============================================================================
+// MonoX - 执行历史存储
+// ============================================================================
+//
+// 文件: src/core/history.rs
+// 职责: 执行历史记录的本地持久化
+// 边界:
+//   - ✅ 运行记录追加写入 .monox/history.jsonl
+//   - ✅ 历史记录读取和数量裁剪
+//   - ✅ 损坏记录行的容错跳过
+//   - ✅ 历史统计报告生成（最慢包、不稳定任务、趋势、分位数）
+//   - ❌ 不应包含任务执行逻辑
+//   - ❌ 不应包含 UI 显示逻辑
+//   - ❌ 不应包含 CLI 相关逻辑
+//
+// ============================================================================
+
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::models::history::{
+    FlakyTask, HistoryReport, PackageTiming, RunRecord, RunTrend, TaskPercentile,
+};
+use crate::models::task::TaskStatus;
+use crate::tf;
+use crate::utils::performance::{mean, percentile};
+
+/// MonoX 本地数据目录名
+pub const MONOX_DIR: &str = ".monox";
+
+/// 历史记录文件名
+const HISTORY_FILE: &str = "history.jsonl";
+
+/// 执行历史存储
+pub struct HistoryStore {
+    /// 历史记录文件路径
+    path: PathBuf,
+}
+
+impl HistoryStore {
+    /// 打开工作区的历史存储
+    pub fn new(workspace_root: &Path) -> Self {
+        Self { path: workspace_root.join(MONOX_DIR).join(HISTORY_FILE) }
+    }
+
+    /// 获取历史记录文件路径
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 追加一条运行记录，并按 max_runs 裁剪旧记录
+    pub fn append(&self, record: &RunRecord, max_runs: usize) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| tf!("history.write_failed", parent.display()))?;
+        }
+
+        let line = serde_json::to_string(record)?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| tf!("history.write_failed", self.path.display()))?;
+        writeln!(file, "{}", line)?;
+        drop(file);
+
+        if max_runs > 0 {
+            self.prune(max_runs)?;
+        }
+
+        Ok(())
+    }
+
+    /// 读取全部运行记录（按时间顺序，跳过无法解析的行）
+    pub fn load_all(&self) -> Result<Vec<RunRecord>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let content = fs::read_to_string(&self.path)
+            .with_context(|| tf!("history.read_failed", self.path.display()))?;
+
+        Ok(content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .filter_map(|line| serde_json::from_str::<RunRecord>(line).ok())
+            .collect())
+    }
+
+    /// 裁剪历史记录，只保留最近 max_runs 条
+    fn prune(&self, max_runs: usize) -> Result<()> {
+        let content = fs::read_to_string(&self.path)?;
+        let lines: Vec<&str> = content.lines().filter(|line| !line.trim().is_empty()).collect();
+
+        if lines.len() <= max_runs {
+            return Ok(());
+        }
+
+        let mut kept = lines[lines.len() - max_runs..].join("\n");
+        kept.push('\n');
+        fs::write(&self.path, kept)
+            .with_context(|| tf!("history.write_failed", self.path.display()))?;
+
+        Ok(())
+    }
+}
+
+/// 根据运行记录生成统计报告
+pub fn build_report(runs: &[RunRecord], top: usize) -> HistoryReport {
+    // 按包、按任务收集耗时样本和执行结果（忽略跳过的任务）
+    let mut package_durations: BTreeMap<String, Vec<u64>> = BTreeMap::new();
+    let mut task_durations: BTreeMap<String, Vec<u64>> = BTreeMap::new();
+    let mut task_outcomes: BTreeMap<String, Vec<bool>> = BTreeMap::new();
+
+    for run in runs {
+        for task in &run.tasks {
+            if task.status == TaskStatus::Skipped {
+                continue;
+            }
+            task_outcomes.entry(task.key()).or_default().push(task.status == TaskStatus::Success);
+            if let Some(duration) = task.duration_ms {
+                package_durations.entry(task.package.clone()).or_default().push(duration);
+                task_durations.entry(task.key()).or_default().push(duration);
+            }
+        }
+    }
+
+    let mut slowest_packages: Vec<PackageTiming> = package_durations
+        .into_iter()
+        .map(|(package, durations)| PackageTiming {
+            package,
+            samples: durations.len(),
+            mean_ms: mean(&durations).unwrap_or(0),
+            max_ms: durations.iter().copied().max().unwrap_or(0),
+        })
+        .collect();
+    slowest_packages.sort_by_key(|timing| std::cmp::Reverse(timing.mean_ms));
+    slowest_packages.truncate(top);
+
+    let mut flaky_tasks: Vec<FlakyTask> = task_outcomes
+        .into_iter()
+        .filter_map(|(task, outcomes)| {
+            let flips = outcomes.windows(2).filter(|pair| pair[0] != pair[1]).count();
+            // 至少两次切换（如 成功→失败→成功）才视为不稳定，单次切换更可能是真实回归
+            (flips >= 2).then(|| FlakyTask {
+                task,
+                runs: outcomes.len(),
+                failures: outcomes.iter().filter(|ok| !**ok).count(),
+                flips,
+            })
+        })
+        .collect();
+    flaky_tasks.sort_by(|a, b| b.flips.cmp(&a.flips).then_with(|| b.failures.cmp(&a.failures)));
+
+    let trend = runs
+        .iter()
+        .map(|run| RunTrend {
+            id: run.id.clone(),
+            started_at: run.started_at,
+            command: run.command.clone(),
+            duration_ms: run.duration_ms(),
+            tasks: run.tasks.len(),
+            failed: run.failed_count(),
+        })
+        .collect();
+
+    let task_percentiles = task_durations
+        .into_iter()
+        .map(|(task, durations)| TaskPercentile {
+            task,
+            samples: durations.len(),
+            p50_ms: percentile(&durations, 50.0).unwrap_or(0),
+            p95_ms: percentile(&durations, 95.0).unwrap_or(0),
+        })
+        .collect();
+
+    HistoryReport { total_runs: runs.len(), slowest_packages, flaky_tasks, trend, task_percentiles }
+}
@@ -0,0 +1,278 @@
+// ============================================================================
+// MonoX - 安装与验证
+// ============================================================================
+//
+// 文件: src/core/installer.rs
+// 职责: fix/update 写入 package.json 后的安装、验证和失败回滚
+// 边界:
+//   - ✅ package.json 和锁文件的写入前备份与恢复
+//   - ✅ 调用包管理器安装依赖
+//   - ✅ 计算受影响的包（修改的包及其依赖方）并运行验证任务
+//   - ✅ 安装、验证和回滚结果（供 JSON 报告使用）
+//   - ❌ 不应包含依赖版本计算逻辑
+//   - ❌ 不应包含 package.json 修改逻辑
+//   - ❌ 不应包含 CLI 参数处理
+//
+// ============================================================================
+
+use anyhow::{Context, Result};
+use serde::{Serialize, Serializer};
+use std::collections::{BTreeSet, VecDeque};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use crate::core::analyzer::DependencyAnalyzer;
+use crate::core::TaskExecutor;
+use crate::models::config::Config;
+use crate::utils::logger::Logger;
+use crate::{t, tf};
+
+/// 需要备份的锁文件
+const LOCKFILE_NAMES: &[&str] = &["pnpm-lock.yaml", "package-lock.json", "yarn.lock"];
+
+/// 写入前的文件备份
+#[derive(Debug, Clone, Default)]
+pub struct ManifestBackup {
+    /// 文件路径和原始内容（None 表示原本不存在）
+    files: Vec<(PathBuf, Option<Vec<u8>>)>,
+}
+
+impl ManifestBackup {
+    /// 备份给定的 package.json 和工作区根目录的锁文件
+    pub fn capture(workspace_root: &Path, manifests: &[PathBuf]) -> Result<Self> {
+        let lockfiles = LOCKFILE_NAMES.iter().map(|name| workspace_root.join(name));
+        let mut files = Vec::new();
+
+        for path in manifests.iter().cloned().chain(lockfiles) {
+            let content = if path.exists() {
+                Some(
+                    fs::read(&path)
+                        .with_context(|| tf!("install.backup_failed", path.display()))?,
+                )
+            } else {
+                None
+            };
+            files.push((path, content));
+        }
+
+        Ok(Self { files })
+    }
+
+    /// 恢复备份（只写回内容发生变化的文件，删除新生成的锁文件）
+    pub fn restore(&self) -> Result<()> {
+        for (path, original) in &self.files {
+            match original {
+                Some(content) if fs::read(path).ok().as_ref() != Some(content) => {
+                    fs::write(path, content)
+                        .with_context(|| tf!("install.restore_failed", path.display()))?;
+                }
+                Some(_) => {}
+                None if path.exists() => {
+                    fs::remove_file(path)
+                        .with_context(|| tf!("install.restore_failed", path.display()))?;
+                }
+                None => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 安装或验证步骤的结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StepStatus {
+    /// 成功
+    Passed,
+    /// 失败
+    Failed,
+    /// 没有需要验证的包
+    Skipped,
+}
+
+/// 安装和验证的执行结果
+#[derive(Debug, Default, Serialize)]
+pub struct PostWriteOutcome {
+    /// 安装结果（未运行时为空）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub install: Option<StepStatus>,
+    /// 验证结果（未运行时为空）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verify: Option<StepStatus>,
+    /// 是否已从备份恢复
+    pub rolled_back: bool,
+    /// 失败原因
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "serialize_error")]
+    pub error: Option<anyhow::Error>,
+}
+
+impl PostWriteOutcome {
+    /// 转换为执行结果，步骤失败时返回失败原因
+    pub fn into_result(self) -> Result<()> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+}
+
+/// 失败原因序列化为包含上下文的错误信息
+fn serialize_error<S: Serializer>(
+    error: &Option<anyhow::Error>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match error {
+        Some(error) => serializer.serialize_str(&format!("{:#}", error)),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// 写入后的安装和验证步骤
+pub struct PostWriteRunner {
+    /// 工作区根目录
+    workspace_root: PathBuf,
+    /// 是否运行包管理器安装
+    install: bool,
+    /// 验证任务（如 build、test）
+    verify: Option<String>,
+}
+
+impl PostWriteRunner {
+    /// 创建新的安装验证器
+    pub fn new(workspace_root: PathBuf) -> Self {
+        Self { workspace_root, install: false, verify: None }
+    }
+
+    /// 设置是否运行安装
+    pub fn with_install(mut self, install: bool) -> Self {
+        self.install = install;
+        self
+    }
+
+    /// 设置验证任务
+    pub fn with_verify(mut self, verify: Option<String>) -> Self {
+        self.verify = verify;
+        self
+    }
+
+    /// 是否有需要执行的步骤
+    pub fn is_enabled(&self) -> bool {
+        self.install || self.verify.is_some()
+    }
+
+    /// 执行安装和验证，任一步骤失败时恢复备份（只有恢复失败时返回错误，步骤失败记录在结果中）
+    pub async fn run(
+        &self,
+        backup: &ManifestBackup,
+        changed_packages: &[String],
+    ) -> Result<PostWriteOutcome> {
+        let mut outcome = PostWriteOutcome::default();
+
+        if let Err(error) = self.run_steps(changed_packages, &mut outcome).await {
+            Logger::warn(t!("install.restoring"));
+            backup.restore()?;
+            Logger::warn(tf!("install.restored", Config::get_package_manager()));
+            outcome.rolled_back = true;
+            outcome.error = Some(error);
+        }
+        Ok(outcome)
+    }
+
+    /// 依次执行安装和验证，并记录每个步骤的结果
+    async fn run_steps(
+        &self,
+        changed_packages: &[String],
+        outcome: &mut PostWriteOutcome,
+    ) -> Result<()> {
+        if self.install {
+            let result = self.run_install();
+            outcome.install =
+                Some(if result.is_ok() { StepStatus::Passed } else { StepStatus::Failed });
+            result?;
+        }
+
+        if let Some(task) = &self.verify {
+            let result = self.run_verify(task, changed_packages).await;
+            outcome.verify = Some(match &result {
+                Ok(true) => StepStatus::Passed,
+                Ok(false) => StepStatus::Skipped,
+                Err(_) => StepStatus::Failed,
+            });
+            result?;
+        }
+        Ok(())
+    }
+
+    /// 在工作区根目录运行包管理器安装
+    fn run_install(&self) -> Result<()> {
+        let package_manager = Config::get_package_manager();
+        Logger::info(tf!("install.running", package_manager));
+
+        // 日志写到标准错误时，安装输出也写到标准错误，标准输出保留给 JSON 报告
+        let mut command = Command::new(package_manager.as_str());
+        command.arg("install").current_dir(&self.workspace_root);
+        if Logger::is_stderr_output() {
+            command.stdout(Stdio::from(io::stderr()));
+        }
+        let status =
+            command.status().with_context(|| tf!("install.spawn_failed", package_manager))?;
+
+        if !status.success() {
+            anyhow::bail!(tf!("install.failed", package_manager, status.code().unwrap_or(-1)));
+        }
+        Logger::success(t!("install.completed"));
+        Ok(())
+    }
+
+    /// 在受影响的包中运行验证任务，没有可验证的包时返回 false
+    async fn run_verify(&self, task: &str, changed_packages: &[String]) -> Result<bool> {
+        let verbose = Config::get_verbose();
+        let mut analyzer =
+            DependencyAnalyzer::new(self.workspace_root.clone()).with_verbose(verbose);
+        let analysis = analyzer.analyze_workspace()?;
+
+        let affected = affected_packages(&analysis.packages, changed_packages);
+        let targets: Vec<String> = analysis
+            .packages
+            .iter()
+            .filter(|package| affected.contains(&package.name))
+            .filter(|package| package.scripts.contains_key(task))
+            .map(|package| package.name.clone())
+            .collect();
+
+        if targets.is_empty() {
+            Logger::warn(tf!("install.verify_no_targets", task));
+            return Ok(false);
+        }
+
+        Logger::info(tf!("install.verifying", task, targets.join(", ")));
+        TaskExecutor::new_from_config()?
+            .execute_packages(&targets, task, &None)
+            .await
+            .with_context(|| tf!("install.verify_failed", task))?;
+        Logger::success(tf!("install.verified", task));
+        Ok(true)
+    }
+}
+
+/// 修改的包及所有（传递）依赖它们的工作区包
+fn affected_packages(
+    packages: &[crate::models::package::WorkspacePackage],
+    changed_packages: &[String],
+) -> BTreeSet<String> {
+    let mut affected: BTreeSet<String> = changed_packages.iter().cloned().collect();
+    let mut queue: VecDeque<String> = changed_packages.iter().cloned().collect();
+
+    while let Some(name) = queue.pop_front() {
+        for package in packages {
+            if package.workspace_dependencies.contains(&name)
+                && affected.insert(package.name.clone())
+            {
+                queue.push_back(package.name.clone());
+            }
+        }
+    }
+    affected
+}
@@ -0,0 +1,867 @@
+// ============================================================================
+// MonoX - 锁文件解析
+// ============================================================================
+//
+// 文件: src/core/lockfile.rs
+// 职责: 解析包管理器锁文件，得到实际安装的依赖图
+// 边界:
+//   - ✅ 锁文件查找（按配置的包管理器优先）
+//   - ✅ pnpm-lock.yaml 解析（v5 / v6 / v9）
+//   - ✅ package-lock.json 解析（v2 / v3）
+//   - ✅ yarn.lock 解析（classic / berry）
+//   - ✅ 已安装包体积估算（扫描 node_modules）
+//   - ❌ 不应包含重复依赖分析逻辑
+//   - ❌ 不应包含 UI 显示逻辑
+//   - ❌ 不应包含 CLI 相关逻辑
+//
+// ============================================================================
+
+use anyhow::{Context, Result};
+use serde_json::Value as JsonValue;
+use serde_yaml::Value as YamlValue;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+use crate::models::config::{Config, PackageManager};
+use crate::models::lockfile::{LockedPackage, Lockfile, LockfileKind};
+use crate::tf;
+
+/// 工作区包清单（用于解析不记录导入方的锁文件，如 yarn classic）
+#[derive(Debug, Clone)]
+pub struct ImporterManifest {
+    /// 包名
+    pub name: String,
+    /// 相对工作区根目录的路径（根目录为 "."）
+    pub folder: String,
+    /// 声明的依赖（包名 -> 版本规范）
+    pub dependencies: BTreeMap<String, String>,
+}
+
+/// 已安装包体积（(包名, 版本) -> 字节）
+pub type InstalledSizes = HashMap<(String, String), u64>;
+
+/// 查找工作区根目录下的锁文件（优先使用配置的包管理器对应的锁文件）
+pub fn find_lockfile(workspace_root: &Path) -> Option<PathBuf> {
+    let mut candidates = vec!["pnpm-lock.yaml", "package-lock.json", "yarn.lock"];
+    let preferred = match Config::get_package_manager() {
+        PackageManager::Pnpm => "pnpm-lock.yaml",
+        PackageManager::Npm => "package-lock.json",
+        PackageManager::Yarn => "yarn.lock",
+    };
+    candidates.retain(|name| *name != preferred);
+    candidates.insert(0, preferred);
+
+    candidates.into_iter().map(|name| workspace_root.join(name)).find(|path| path.is_file())
+}
+
+/// 加载并解析锁文件（不存在时返回 None）
+pub fn load_lockfile(
+    workspace_root: &Path,
+    manifests: &[ImporterManifest],
+) -> Result<Option<Lockfile>> {
+    let Some(path) = find_lockfile(workspace_root) else {
+        return Ok(None);
+    };
+
+    let content =
+        fs::read_to_string(&path).with_context(|| tf!("lockfile.read_failed", path.display()))?;
+    let file_name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+
+    let lockfile = match file_name.as_str() {
+        "pnpm-lock.yaml" => parse_pnpm(path.clone(), &content, manifests),
+        "package-lock.json" => parse_npm(path.clone(), &content),
+        _ if content.contains("__metadata:") => parse_yarn_berry(path.clone(), &content),
+        _ => Ok(parse_yarn_classic(path.clone(), &content, manifests)),
+    }
+    .with_context(|| tf!("lockfile.parse_failed", path.display()))?;
+
+    Ok(Some(lockfile))
+}
+
+/// 生成包 ID
+fn package_id(name: &str, version: &str) -> String {
+    format!("{}@{}", name, version)
+}
+
+/// 拆分 "名称@其余部分"（作用域包名以 @ 开头）
+fn split_name(descriptor: &str) -> Option<(&str, &str)> {
+    let at = descriptor.get(1..)?.find('@')? + 1;
+    Some((&descriptor[..at], &descriptor[at + 1..]))
+}
+
+/// 去重并排序依赖 ID
+fn finalize(mut packages: BTreeMap<String, LockedPackage>) -> BTreeMap<String, LockedPackage> {
+    for package in packages.values_mut() {
+        package.dependencies.sort();
+        package.dependencies.dedup();
+    }
+    packages
+}
+
+// ============================================================================
+// pnpm-lock.yaml
+// ============================================================================
+
+/// pnpm 依赖字段
+const PNPM_DEP_FIELDS: &[&str] = &["dependencies", "optionalDependencies", "devDependencies"];
+
+/// 解析 pnpm-lock.yaml
+fn parse_pnpm(path: PathBuf, content: &str, manifests: &[ImporterManifest]) -> Result<Lockfile> {
+    let doc: YamlValue = serde_yaml::from_str(content)?;
+
+    // v5 使用 /name/version_peer 格式，v6 起使用 name@version(peer)
+    let lockfile_version = match &doc["lockfileVersion"] {
+        YamlValue::Number(n) => n.as_f64().unwrap_or(0.0),
+        YamlValue::String(s) => s.parse().unwrap_or(0.0),
+        _ => 0.0,
+    };
+    let legacy = lockfile_version < 6.0;
+
+    // v9 将依赖关系移到 snapshots，packages 仅保留元数据
+    let graph = if doc["snapshots"].is_mapping() { &doc["snapshots"] } else { &doc["packages"] };
+
+    let mut packages: BTreeMap<String, LockedPackage> = BTreeMap::new();
+    if let Some(entries) = graph.as_mapping() {
+        for (key, entry) in entries {
+            let Some((name, version)) = key.as_str().and_then(|k| parse_pnpm_key(k, legacy)) else {
+                continue;
+            };
+            let dependencies = pnpm_dependency_ids(entry, legacy);
+            packages
+                .entry(package_id(&name, &version))
+                .or_insert_with(|| LockedPackage { name, version, dependencies: Vec::new() })
+                .dependencies
+                .extend(dependencies);
+        }
+    }
+
+    let mut importers = BTreeMap::new();
+    match doc["importers"].as_mapping() {
+        Some(entries) => {
+            for (folder, entry) in entries {
+                let folder = folder.as_str().unwrap_or(".");
+                importers
+                    .insert(importer_name(folder, manifests), pnpm_dependency_ids(entry, legacy));
+            }
+        }
+        // 非工作区项目的依赖记录在顶层
+        None => {
+            importers.insert(importer_name(".", manifests), pnpm_dependency_ids(&doc, legacy));
+        }
+    }
+
+    Ok(Lockfile { kind: LockfileKind::Pnpm, path, packages: finalize(packages), importers })
+}
+
+/// 解析 pnpm 包键（"/name@1.0.0(peer)"、"name@1.0.0"、"/name/1.0.0_peer"）
+fn parse_pnpm_key(key: &str, legacy: bool) -> Option<(String, String)> {
+    let key = key.trim_start_matches('/');
+    let key = key.split('(').next()?;
+
+    let (name, version) = if legacy { key.rsplit_once('/')? } else { split_name(key)? };
+    let version = if legacy { version.split('_').next()? } else { version };
+
+    if name.is_empty() || version.is_empty() {
+        return None;
+    }
+    Some((name.to_string(), version.to_string()))
+}
+
+/// 解析 pnpm 依赖引用（版本号、别名包键或 link:）
+fn pnpm_reference_id(name: &str, reference: &str, legacy: bool) -> Option<String> {
+    if reference.starts_with("link:") || reference.starts_with("file:") {
+        return None;
+    }
+
+    let is_full_key = if legacy {
+        reference.starts_with('/')
+    } else {
+        let reference = reference.split('(').next().unwrap_or(reference);
+        reference.starts_with('/') || reference.get(1..).is_some_and(|rest| rest.contains('@'))
+    };
+
+    if is_full_key {
+        let (name, version) = parse_pnpm_key(reference, legacy)?;
+        return Some(package_id(&name, &version));
+    }
+
+    let version = reference.split(['(', '_']).next()?;
+    Some(package_id(name, version))
+}
+
+/// 收集 pnpm 条目的依赖 ID
+fn pnpm_dependency_ids(entry: &YamlValue, legacy: bool) -> Vec<String> {
+    let mut ids = Vec::new();
+    for field in PNPM_DEP_FIELDS {
+        let Some(deps) = entry[*field].as_mapping() else {
+            continue;
+        };
+        for (name, value) in deps {
+            let Some(name) = name.as_str() else {
+                continue;
+            };
+            // 导入方 v6+ 为 { specifier, version }，其余为版本字符串
+            let reference = value.as_str().or_else(|| value["version"].as_str());
+            if let Some(id) = reference.and_then(|r| pnpm_reference_id(name, r, legacy)) {
+                ids.push(id);
+            }
+        }
+    }
+    ids
+}
+
+/// 根据目录查找导入方包名（找不到时使用目录本身）
+fn importer_name(folder: &str, manifests: &[ImporterManifest]) -> String {
+    manifests
+        .iter()
+        .find(|m| m.folder == folder)
+        .map(|m| m.name.clone())
+        .unwrap_or_else(|| folder.to_string())
+}
+
+// ============================================================================
+// package-lock.json
+// ============================================================================
+
+/// npm 依赖字段
+const NPM_DEP_FIELDS: &[&str] =
+    &["dependencies", "optionalDependencies", "peerDependencies", "devDependencies"];
+
+/// 解析 package-lock.json（v2 / v3 的 packages 字段）
+fn parse_npm(path: PathBuf, content: &str) -> Result<Lockfile> {
+    let doc: JsonValue = serde_json::from_str(content)?;
+
+    let lockfile_version = doc["lockfileVersion"].as_u64().unwrap_or(1);
+    let entries = match doc["packages"].as_object() {
+        Some(entries) if lockfile_version >= 2 => entries,
+        _ => anyhow::bail!(tf!("lockfile.npm_unsupported_version", lockfile_version)),
+    };
+
+    let mut packages = BTreeMap::new();
+    let mut importers = BTreeMap::new();
+
+    for (key, entry) in entries {
+        let dependencies = npm_dependency_ids(entries, key, entry);
+
+        if !is_npm_installed_path(key) {
+            // 根目录和工作区包目录
+            let name = entry["name"].as_str().map(|n| n.to_string()).unwrap_or_else(|| {
+                if key.is_empty() {
+                    ".".to_string()
+                } else {
+                    key.clone()
+                }
+            });
+            importers.insert(name, dependencies);
+            continue;
+        }
+
+        if is_npm_link(entry) {
+            continue;
+        }
+
+        let Some(version) = entry["version"].as_str() else {
+            continue;
+        };
+        let name = entry["name"].as_str().unwrap_or_else(|| npm_name_from_path(key));
+
+        packages.insert(
+            key.clone(),
+            LockedPackage { name: name.to_string(), version: version.to_string(), dependencies },
+        );
+    }
+
+    Ok(Lockfile { kind: LockfileKind::Npm, path, packages: finalize(packages), importers })
+}
+
+/// 是否为 node_modules 中的安装路径
+fn is_npm_installed_path(key: &str) -> bool {
+    key.starts_with("node_modules/") || key.contains("/node_modules/")
+}
+
+/// 是否为指向工作区包的链接
+fn is_npm_link(entry: &JsonValue) -> bool {
+    entry["link"].as_bool().unwrap_or(false)
+}
+
+/// 从安装路径提取包名（最后一个 node_modules/ 之后的部分）
+fn npm_name_from_path(key: &str) -> &str {
+    key.rsplit_once("node_modules/").map(|(_, name)| name).unwrap_or(key)
+}
+
+/// 收集 npm 条目的依赖（按 Node 模块解析规则逐级向上查找）
+fn npm_dependency_ids(
+    entries: &serde_json::Map<String, JsonValue>,
+    key: &str,
+    entry: &JsonValue,
+) -> Vec<String> {
+    let mut ids = Vec::new();
+    for field in NPM_DEP_FIELDS {
+        if let Some(deps) = entry[*field].as_object() {
+            ids.extend(deps.keys().filter_map(|dep| npm_resolve(entries, key, dep)));
+        }
+    }
+    ids
+}
+
+/// 从指定路径开始解析依赖的安装位置（链接到工作区包时返回 None）
+fn npm_resolve(
+    entries: &serde_json::Map<String, JsonValue>,
+    from: &str,
+    dep: &str,
+) -> Option<String> {
+    let mut base = from.to_string();
+    loop {
+        let candidate = if base.is_empty() {
+            format!("node_modules/{}", dep)
+        } else {
+            format!("{}/node_modules/{}", base, dep)
+        };
+
+        if let Some(entry) = entries.get(&candidate) {
+            return (!is_npm_link(entry)).then_some(candidate);
+        }
+        if base.is_empty() {
+            return None;
+        }
+
+        base = match base.rfind("/node_modules/") {
+            Some(i) => base[..i].to_string(),
+            None => String::new(),
+        };
+    }
+}
+
+// ============================================================================
+// yarn.lock
+// ============================================================================
+
+/// yarn 锁文件条目
+struct YarnEntry {
+    descriptors: Vec<String>,
+    name: String,
+    version: String,
+    dependencies: Vec<(String, String)>,
+    is_workspace: bool,
+}
+
+/// 解析 yarn classic (v1) 锁文件
+fn parse_yarn_classic(path: PathBuf, content: &str, manifests: &[ImporterManifest]) -> Lockfile {
+    let mut entries: Vec<YarnEntry> = Vec::new();
+    let mut section: Option<String> = None;
+
+    for line in content.lines() {
+        let text = line.trim();
+        if text.is_empty() || text.starts_with('#') {
+            continue;
+        }
+        let indent = line.len() - line.trim_start().len();
+
+        match indent {
+            0 => {
+                let descriptors: Vec<String> = text
+                    .trim_end_matches(':')
+                    .split(", ")
+                    .map(|d| unquote(d).to_string())
+                    .collect();
+                let name = descriptors
+                    .first()
+                    .and_then(|d| split_name(d))
+                    .map(|(name, _)| name.to_string())
+                    .unwrap_or_default();
+                entries.push(YarnEntry {
+                    descriptors,
+                    name,
+                    version: String::new(),
+                    dependencies: Vec::new(),
+                    is_workspace: false,
+                });
+                section = None;
+            }
+            2 => {
+                section = text.strip_suffix(':').map(|s| s.to_string());
+                if let (Some(version), Some(entry)) =
+                    (text.strip_prefix("version "), entries.last_mut())
+                {
+                    entry.version = unquote(version).to_string();
+                }
+            }
+            _ => {
+                let in_deps = matches!(
+                    section.as_deref(),
+                    Some("dependencies") | Some("optionalDependencies")
+                );
+                if let (true, Some((name, range)), Some(entry)) =
+                    (in_deps, text.split_once(' '), entries.last_mut())
+                {
+                    entry
+                        .dependencies
+                        .push((unquote(name).to_string(), unquote(range).to_string()));
+                }
+            }
+        }
+    }
+
+    let (packages, descriptor_ids) = build_yarn_graph(&entries, false);
+
+    // classic 锁文件不记录工作区包，按 package.json 声明解析
+    let importers = manifests
+        .iter()
+        .map(|manifest| {
+            let ids = manifest
+                .dependencies
+                .iter()
+                .filter_map(|(name, range)| {
+                    descriptor_ids.get(&format!("{}@{}", name, range)).cloned()
+                })
+                .collect();
+            (manifest.name.clone(), ids)
+        })
+        .collect();
+
+    Lockfile { kind: LockfileKind::YarnClassic, path, packages, importers }
+}
+
+/// 解析 yarn berry (v2+) 锁文件
+fn parse_yarn_berry(path: PathBuf, content: &str) -> Result<Lockfile> {
+    let doc: YamlValue = serde_yaml::from_str(content)?;
+    let mut entries = Vec::new();
+
+    if let Some(mapping) = doc.as_mapping() {
+        for (key, entry) in mapping {
+            let Some(key) = key.as_str().filter(|k| *k != "__metadata") else {
+                continue;
+            };
+            let resolution = entry["resolution"].as_str().unwrap_or(key);
+            let Some((name, _)) = split_name(resolution) else {
+                continue;
+            };
+
+            let dependencies = entry["dependencies"]
+                .as_mapping()
+                .map(|deps| {
+                    deps.iter()
+                        .filter_map(|(name, range)| {
+                            Some((name.as_str()?.to_string(), yaml_scalar(range)?))
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            entries.push(YarnEntry {
+                descriptors: key.split(", ").map(|d| d.trim().to_string()).collect(),
+                name: name.to_string(),
+                version: entry["version"].as_str().map(|v| v.to_string()).unwrap_or_default(),
+                dependencies,
+                is_workspace: resolution.contains("@workspace:"),
+            });
+        }
+    }
+
+    let (packages, descriptor_ids) = build_yarn_graph(&entries, true);
+
+    let importers = entries
+        .iter()
+        .filter(|entry| entry.is_workspace)
+        .map(|entry| {
+            let ids = entry
+                .dependencies
+                .iter()
+                .filter_map(|(name, range)| {
+                    resolve_yarn_descriptor(&descriptor_ids, name, range, true)
+                })
+                .collect();
+            (entry.name.clone(), ids)
+        })
+        .collect();
+
+    Ok(Lockfile { kind: LockfileKind::YarnBerry, path, packages, importers })
+}
+
+/// 构建 yarn 依赖图，返回 (已安装包, 描述符 -> 包 ID)
+fn build_yarn_graph(
+    entries: &[YarnEntry],
+    berry: bool,
+) -> (BTreeMap<String, LockedPackage>, HashMap<String, String>) {
+    let mut descriptor_ids = HashMap::new();
+    for entry in entries.iter().filter(|e| !e.is_workspace && !e.version.is_empty()) {
+        let id = package_id(&entry.name, &entry.version);
+        for descriptor in &entry.descriptors {
+            descriptor_ids.insert(descriptor.clone(), id.clone());
+        }
+    }
+
+    let mut packages: BTreeMap<String, LockedPackage> = BTreeMap::new();
+    for entry in entries.iter().filter(|e| !e.is_workspace && !e.version.is_empty()) {
+        let dependencies = entry.dependencies.iter().filter_map(|(name, range)| {
+            resolve_yarn_descriptor(&descriptor_ids, name, range, berry)
+        });
+        packages
+            .entry(package_id(&entry.name, &entry.version))
+            .or_insert_with(|| LockedPackage {
+                name: entry.name.clone(),
+                version: entry.version.clone(),
+                dependencies: Vec::new(),
+            })
+            .dependencies
+            .extend(dependencies);
+    }
+
+    (finalize(packages), descriptor_ids)
+}
+
+/// 根据依赖名和范围查找包 ID（berry 中省略协议的范围默认为 npm:）
+fn resolve_yarn_descriptor(
+    descriptor_ids: &HashMap<String, String>,
+    name: &str,
+    range: &str,
+    berry: bool,
+) -> Option<String> {
+    descriptor_ids.get(&format!("{}@{}", name, range)).cloned().or_else(|| {
+        berry.then(|| descriptor_ids.get(&format!("{}@npm:{}", name, range)).cloned()).flatten()
+    })
+}
+
+/// 去除包裹的双引号
+fn unquote(value: &str) -> &str {
+    value.trim().trim_matches('"')
+}
+
+/// 读取 YAML 标量为字符串
+fn yaml_scalar(value: &YamlValue) -> Option<String> {
+    match value {
+        YamlValue::String(s) => Some(s.clone()),
+        YamlValue::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+// ============================================================================
+// 安装体积估算
+// ============================================================================
+
+/// 扫描各 node_modules 目录，统计已安装包的单份体积
+pub fn installed_sizes(roots: &[PathBuf]) -> InstalledSizes {
+    let mut sizes = InstalledSizes::new();
+    for root in roots {
+        scan_node_modules(&root.join("node_modules"), &mut sizes);
+    }
+    sizes
+}
+
+/// 递归扫描 node_modules 目录（跳过符号链接，避免重复统计）
+fn scan_node_modules(dir: &Path, sizes: &mut InstalledSizes) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        if !entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
+            continue;
+        }
+        let path = entry.path();
+        let file_name = entry.file_name().to_string_lossy().to_string();
+
+        // pnpm 虚拟存储: node_modules/.pnpm/<id>/node_modules/<name>
+        if file_name == ".pnpm" {
+            if let Ok(stores) = fs::read_dir(&path) {
+                for store in stores.flatten() {
+                    scan_node_modules(&store.path().join("node_modules"), sizes);
+                }
+            }
+            continue;
+        }
+        if file_name.starts_with('.') {
+            continue;
+        }
+        if file_name.starts_with('@') {
+            scan_node_modules(&path, sizes);
+            continue;
+        }
+
+        record_package_size(&path, sizes);
+        scan_node_modules(&path.join("node_modules"), sizes);
+    }
+}
+
+/// 记录单个已安装包的体积（不含嵌套的 node_modules）
+fn record_package_size(dir: &Path, sizes: &mut InstalledSizes) {
+    let Ok(content) = fs::read_to_string(dir.join("package.json")) else {
+        return;
+    };
+    let Ok(manifest) = serde_json::from_str::<JsonValue>(&content) else {
+        return;
+    };
+    let (Some(name), Some(version)) = (manifest["name"].as_str(), manifest["version"].as_str())
+    else {
+        return;
+    };
+
+    sizes.entry((name.to_string(), version.to_string())).or_insert_with(|| {
+        WalkDir::new(dir)
+            .into_iter()
+            .filter_entry(|e| e.depth() == 0 || e.file_name() != "node_modules")
+            .flatten()
+            .filter(|e| e.file_type().is_file())
+            .filter_map(|e| e.metadata().ok())
+            .map(|m| m.len())
+            .sum()
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest(name: &str, folder: &str, dependencies: &[(&str, &str)]) -> ImporterManifest {
+        ImporterManifest {
+            name: name.to_string(),
+            folder: folder.to_string(),
+            dependencies: dependencies
+                .iter()
+                .map(|(name, range)| (name.to_string(), range.to_string()))
+                .collect(),
+        }
+    }
+
+    /// 已安装包 ID 及其依赖
+    fn graph(lockfile: &Lockfile) -> Vec<(&str, Vec<&str>)> {
+        lockfile
+            .packages
+            .iter()
+            .map(|(id, package)| {
+                (id.as_str(), package.dependencies.iter().map(String::as_str).collect())
+            })
+            .collect()
+    }
+
+    /// 导入方直接依赖的包 ID（排序后）
+    fn importer<'a>(lockfile: &'a Lockfile, name: &str) -> Vec<&'a str> {
+        let mut ids: Vec<&str> = lockfile.importers[name].iter().map(String::as_str).collect();
+        ids.sort();
+        ids
+    }
+
+    #[test]
+    fn parses_pnpm_v5() {
+        let content = "\
+lockfileVersion: 5.4
+importers:
+  .:
+    specifiers: {}
+  packages/app:
+    specifiers:
+      lodash: ^4.17.0
+    dependencies:
+      core: link:../core
+      debug: 4.3.4_supports-color@8.1.1
+      lodash: 4.17.21
+packages:
+  /debug/4.3.4_supports-color@8.1.1:
+    dependencies:
+      ms: 2.1.2
+  /lodash/4.17.21:
+    resolution: {integrity: sha512-a}
+  /ms/2.1.2:
+    resolution: {integrity: sha512-b}
+";
+        let manifests = [manifest("app", "packages/app", &[])];
+        let lockfile = parse_pnpm(PathBuf::from("pnpm-lock.yaml"), content, &manifests).unwrap();
+
+        assert_eq!(
+            graph(&lockfile),
+            [("debug@4.3.4", vec!["ms@2.1.2"]), ("lodash@4.17.21", vec![]), ("ms@2.1.2", vec![])]
+        );
+        assert_eq!(importer(&lockfile, "app"), ["debug@4.3.4", "lodash@4.17.21"]);
+        assert!(importer(&lockfile, ".").is_empty());
+    }
+
+    #[test]
+    fn parses_pnpm_v6_with_peers_and_aliases() {
+        let content = "\
+lockfileVersion: '6.0'
+importers:
+  packages/app:
+    dependencies:
+      legacy-lodash:
+        specifier: npm:lodash@^4
+        version: /lodash@4.17.21
+      react-dom:
+        specifier: ^18.0.0
+        version: 18.2.0(react@18.2.0)
+packages:
+  /lodash@4.17.21:
+    resolution: {integrity: sha512-a}
+  /react-dom@18.2.0(react@18.2.0):
+    dependencies:
+      react: 18.2.0
+  /react@18.2.0:
+    resolution: {integrity: sha512-b}
+";
+        let manifests = [manifest("app", "packages/app", &[])];
+        let lockfile = parse_pnpm(PathBuf::from("pnpm-lock.yaml"), content, &manifests).unwrap();
+
+        assert_eq!(
+            graph(&lockfile),
+            [
+                ("lodash@4.17.21", vec![]),
+                ("react-dom@18.2.0", vec!["react@18.2.0"]),
+                ("react@18.2.0", vec![])
+            ]
+        );
+        assert_eq!(importer(&lockfile, "app"), ["lodash@4.17.21", "react-dom@18.2.0"]);
+    }
+
+    #[test]
+    fn parses_pnpm_v9_snapshots() {
+        let content = "\
+lockfileVersion: '9.0'
+importers:
+  .:
+    dependencies:
+      react:
+        specifier: ^18.0.0
+        version: 18.2.0
+packages:
+  loose-envify@1.4.0:
+    resolution: {integrity: sha512-a}
+  react@18.2.0:
+    resolution: {integrity: sha512-b}
+snapshots:
+  loose-envify@1.4.0: {}
+  react@18.2.0:
+    dependencies:
+      loose-envify: 1.4.0
+";
+        let manifests = [manifest("root", ".", &[])];
+        let lockfile = parse_pnpm(PathBuf::from("pnpm-lock.yaml"), content, &manifests).unwrap();
+
+        assert_eq!(
+            graph(&lockfile),
+            [("loose-envify@1.4.0", vec![]), ("react@18.2.0", vec!["loose-envify@1.4.0"])]
+        );
+        assert_eq!(importer(&lockfile, "root"), ["react@18.2.0"]);
+    }
+
+    #[test]
+    fn parses_npm_v3_with_nested_installs_and_links() {
+        let content = r#"{
+  "name": "root",
+  "lockfileVersion": 3,
+  "packages": {
+    "": { "name": "root", "devDependencies": { "lodash": "^4.17.0" } },
+    "packages/app": {
+      "name": "app",
+      "version": "1.0.0",
+      "dependencies": { "core": "*", "lodash": "^3.10.0" }
+    },
+    "node_modules/app": { "resolved": "packages/app", "link": true },
+    "node_modules/core": { "resolved": "packages/core", "link": true },
+    "node_modules/lodash": { "version": "4.17.21" },
+    "packages/app/node_modules/lodash": { "version": "3.10.1" }
+  }
+}"#;
+        let lockfile = parse_npm(PathBuf::from("package-lock.json"), content).unwrap();
+
+        assert_eq!(
+            graph(&lockfile),
+            [("node_modules/lodash", vec![]), ("packages/app/node_modules/lodash", vec![])]
+        );
+        assert_eq!(lockfile.packages["packages/app/node_modules/lodash"].version, "3.10.1");
+        assert_eq!(importer(&lockfile, "root"), ["node_modules/lodash"]);
+        assert_eq!(importer(&lockfile, "app"), ["packages/app/node_modules/lodash"]);
+    }
+
+    #[test]
+    fn parses_npm_v2_and_rejects_v1() {
+        let v2 = r#"{
+  "lockfileVersion": 2,
+  "packages": {
+    "": { "dependencies": { "debug": "^4.0.0" } },
+    "node_modules/debug": { "version": "4.3.4", "dependencies": { "ms": "2.1.2" } },
+    "node_modules/ms": { "version": "2.1.2" }
+  },
+  "dependencies": {}
+}"#;
+        let lockfile = parse_npm(PathBuf::from("package-lock.json"), v2).unwrap();
+        assert_eq!(
+            graph(&lockfile),
+            [("node_modules/debug", vec!["node_modules/ms"]), ("node_modules/ms", vec![])]
+        );
+        assert_eq!(importer(&lockfile, "."), ["node_modules/debug"]);
+
+        let v1 = r#"{ "lockfileVersion": 1, "dependencies": {} }"#;
+        assert!(parse_npm(PathBuf::from("package-lock.json"), v1).is_err());
+    }
+
+    #[test]
+    fn parses_yarn_classic() {
+        let content = r#"# THIS IS AN AUTOGENERATED FILE. DO NOT EDIT THIS FILE DIRECTLY.
+# yarn lockfile v1
+
+
+debug@^4.0.0:
+  version "4.3.4"
+  resolved "https://registry.yarnpkg.com/debug/-/debug-4.3.4.tgz"
+  dependencies:
+    ms "2.1.2"
+
+"lodash@^4.17.0", lodash@^4.17.20:
+  version "4.17.21"
+
+ms@2.1.2:
+  version "2.1.2"
+"#;
+        let manifests = [
+            manifest("app", "packages/app", &[("core", "1.0.0"), ("debug", "^4.0.0")]),
+            manifest("web", "packages/web", &[("lodash", "^4.17.20")]),
+        ];
+        let lockfile = parse_yarn_classic(PathBuf::from("yarn.lock"), content, &manifests);
+
+        assert_eq!(
+            graph(&lockfile),
+            [("debug@4.3.4", vec!["ms@2.1.2"]), ("lodash@4.17.21", vec![]), ("ms@2.1.2", vec![])]
+        );
+        assert_eq!(importer(&lockfile, "app"), ["debug@4.3.4"]);
+        assert_eq!(importer(&lockfile, "web"), ["lodash@4.17.21"]);
+    }
+
+    #[test]
+    fn parses_yarn_berry() {
+        let content = r#"# This file is generated by running "yarn install" inside your project.
+
+__metadata:
+  version: 6
+  cacheKey: 8
+
+"app@workspace:packages/app":
+  version: 0.0.0-use.local
+  resolution: "app@workspace:packages/app"
+  dependencies:
+    core: "workspace:*"
+    debug: ^4.0.0
+  languageName: unknown
+  linkType: soft
+
+"debug@npm:^4.0.0":
+  version: 4.3.4
+  resolution: "debug@npm:4.3.4"
+  dependencies:
+    ms: 2.1.2
+  languageName: node
+  linkType: hard
+
+"ms@npm:2.1.2":
+  version: 2.1.2
+  resolution: "ms@npm:2.1.2"
+  languageName: node
+  linkType: hard
+"#;
+        let lockfile = parse_yarn_berry(PathBuf::from("yarn.lock"), content).unwrap();
+
+        assert_eq!(graph(&lockfile), [("debug@4.3.4", vec!["ms@2.1.2"]), ("ms@2.1.2", vec![])]);
+        assert_eq!(importer(&lockfile, "app"), ["debug@4.3.4"]);
+    }
+}
@@ -0,0 +1,664 @@
+// ============================================================================
+// MonoX - package.json 规范检查
+// ============================================================================
+//
+// 文件: src/core/manifest.rs
+// 职责: 可插拔的 package.json 规范规则和规则引擎
+// 边界:
+//   - ✅ 规则接口定义和内置规则实现
+//   - ✅ 按 [manifest.rules] 应用严重级别、跳过关闭的规则
+//   - ✅ 生成可以安全自动应用的修改（由 fix --manifest 写回）
+//   - ❌ 不应包含 package.json 文件收集和写回逻辑
+//   - ❌ 不应包含输出格式化
+//
+// ============================================================================
+
+use anyhow::Result;
+use regex::Regex;
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use crate::models::config::ManifestConfig;
+use crate::models::manifest::{ManifestFix, ManifestIssue, ManifestReport, RuleSeverity};
+use crate::{t, tf};
+
+/// 不受脚本命名规则约束的 npm 生命周期脚本
+const LIFECYCLE_SCRIPTS: &[&str] = &["prepublishOnly"];
+
+/// 可能声明发布后依赖的字段（devDependencies 不会随包安装）
+const RUNTIME_DEPENDENCY_FIELDS: &[&str] =
+    &["dependencies", "peerDependencies", "optionalDependencies"];
+
+/// 已读取的 package.json
+pub struct Manifest {
+    /// package.json 所在目录的相对路径
+    pub folder: String,
+    /// 文件内容
+    pub json: Value,
+}
+
+/// 规则检查的上下文
+pub struct ManifestContext<'a> {
+    /// 工作区根目录的 package.json
+    pub root: Option<Manifest>,
+    /// 工作区包的 package.json（不含根目录）
+    pub packages: Vec<Manifest>,
+    /// 检查配置
+    pub config: &'a ManifestConfig,
+}
+
+/// 规则发现的问题（严重级别由规则引擎按配置填写）
+pub struct Finding {
+    /// 包名（没有包名时为目录名）
+    package: String,
+    /// 包的相对路径
+    folder: String,
+    /// 问题描述
+    message: String,
+    /// 自动修复
+    fix: Option<ManifestFix>,
+}
+
+/// package.json 规范规则
+pub trait ManifestRule {
+    /// 规则标识（用于 [manifest.rules] 配置）
+    fn id(&self) -> &'static str;
+
+    /// 未配置时的严重级别
+    fn default_severity(&self) -> RuleSeverity;
+
+    /// 检查所有包
+    fn check(&self, context: &ManifestContext) -> Result<Vec<Finding>>;
+}
+
+/// 内置规则
+pub fn builtin_rules() -> Vec<Box<dyn ManifestRule>> {
+    vec![
+        Box::new(RequiredFields),
+        Box::new(DuplicateName),
+        Box::new(FolderName),
+        Box::new(EnginesNode),
+        Box::new(PrivateDependency),
+        Box::new(PublishFields),
+        Box::new(ScriptNames),
+    ]
+}
+
+/// 运行规则，按配置的严重级别生成报告
+pub fn lint(context: &ManifestContext, rules: &[Box<dyn ManifestRule>]) -> Result<ManifestReport> {
+    let mut issues = Vec::new();
+    for rule in rules {
+        let severity =
+            context.config.rules.get(rule.id()).copied().unwrap_or(rule.default_severity());
+        if severity == RuleSeverity::Off {
+            continue;
+        }
+        for finding in rule.check(context)? {
+            issues.push(ManifestIssue {
+                rule: rule.id().to_string(),
+                severity,
+                package: finding.package,
+                folder: finding.folder,
+                message: finding.message,
+                fix: finding.fix,
+            });
+        }
+    }
+    issues.sort_by(|a, b| a.folder.cmp(&b.folder).then_with(|| a.rule.cmp(&b.rule)));
+
+    let known: BTreeSet<&str> = rules.iter().map(|rule| rule.id()).collect();
+    let unknown_rules =
+        context.config.rules.keys().filter(|id| !known.contains(id.as_str())).cloned().collect();
+
+    Ok(ManifestReport {
+        packages_checked: context.packages.len(),
+        errors: issues.iter().filter(|i| i.severity == RuleSeverity::Error).count(),
+        warnings: issues.iter().filter(|i| i.severity == RuleSeverity::Warn).count(),
+        unknown_rules,
+        issues,
+    })
+}
+
+impl Manifest {
+    /// 包名（为空时返回 None）
+    fn name(&self) -> Option<&str> {
+        self.json["name"].as_str().filter(|name| !name.trim().is_empty())
+    }
+
+    /// 是否为私有包（不会发布）
+    fn is_private(&self) -> bool {
+        self.json["private"].as_bool().unwrap_or(false)
+    }
+
+    /// 字段是否存在且不为空
+    fn has_field(&self, field: &str) -> bool {
+        match self.json.get(field) {
+            None | Some(Value::Null) => false,
+            Some(Value::String(value)) => !value.trim().is_empty(),
+            Some(_) => true,
+        }
+    }
+}
+
+impl Finding {
+    /// 为包创建问题
+    fn new(manifest: &Manifest, message: impl ToString) -> Self {
+        Self {
+            package: manifest.name().unwrap_or(&manifest.folder).to_string(),
+            folder: manifest.folder.clone(),
+            message: message.to_string(),
+            fix: None,
+        }
+    }
+
+    /// 附加自动修复
+    fn with_fix(mut self, fix: Option<ManifestFix>) -> Self {
+        self.fix = fix;
+        self
+    }
+}
+
+/// 必需字段：所有包都需要 name，可发布的包还需要 required_fields 中的字段
+struct RequiredFields;
+
+impl RequiredFields {
+    /// repository 可以从根目录的 package.json 补全
+    ///
+    /// license 是每个包自己的授权声明，不能假定与根目录相同，只报告不自动修复。
+    fn fix(root: Option<&Manifest>, manifest: &Manifest, field: &str) -> Option<ManifestFix> {
+        let root = root?;
+        match field {
+            "repository" if manifest.json.get("repository").is_none() => {
+                let url = match &root.json["repository"] {
+                    Value::String(url) => url.as_str(),
+                    repository => repository["url"].as_str()?,
+                };
+                Some(ManifestFix::Insert {
+                    key: "repository".to_string(),
+                    value: json!({ "type": "git", "url": url, "directory": manifest.folder }),
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+impl ManifestRule for RequiredFields {
+    fn id(&self) -> &'static str {
+        "required-fields"
+    }
+
+    fn default_severity(&self) -> RuleSeverity {
+        RuleSeverity::Error
+    }
+
+    fn check(&self, context: &ManifestContext) -> Result<Vec<Finding>> {
+        let required = context.config.required_fields();
+        let mut findings = Vec::new();
+        for manifest in &context.packages {
+            let fields: Vec<&str> = if manifest.is_private() {
+                vec!["name"]
+            } else {
+                std::iter::once("name")
+                    .chain(required.iter().map(String::as_str).filter(|f| *f != "name"))
+                    .collect()
+            };
+            for field in fields {
+                if !manifest.has_field(field) {
+                    let fix = Self::fix(context.root.as_ref(), manifest, field);
+                    let message = tf!("check.manifest.rule.missing_field", field);
+                    findings.push(Finding::new(manifest, message).with_fix(fix));
+                }
+            }
+        }
+        Ok(findings)
+    }
+}
+
+/// 包名重复
+struct DuplicateName;
+
+impl ManifestRule for DuplicateName {
+    fn id(&self) -> &'static str {
+        "duplicate-name"
+    }
+
+    fn default_severity(&self) -> RuleSeverity {
+        RuleSeverity::Error
+    }
+
+    fn check(&self, context: &ManifestContext) -> Result<Vec<Finding>> {
+        let mut folders: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+        for manifest in &context.packages {
+            if let Some(name) = manifest.name() {
+                folders.entry(name).or_default().push(&manifest.folder);
+            }
+        }
+
+        let mut findings = Vec::new();
+        for manifest in &context.packages {
+            let Some(others) = manifest.name().and_then(|name| folders.get(name)) else {
+                continue;
+            };
+            if others.len() > 1 {
+                let others: Vec<&str> =
+                    others.iter().copied().filter(|f| *f != manifest.folder).collect();
+                let message = tf!("check.manifest.rule.duplicate_name", others.join(", "));
+                findings.push(Finding::new(manifest, message));
+            }
+        }
+        Ok(findings)
+    }
+}
+
+/// 包名（不含作用域）与目录名不一致
+struct FolderName;
+
+impl ManifestRule for FolderName {
+    fn id(&self) -> &'static str {
+        "folder-name"
+    }
+
+    fn default_severity(&self) -> RuleSeverity {
+        RuleSeverity::Warn
+    }
+
+    fn check(&self, context: &ManifestContext) -> Result<Vec<Finding>> {
+        let mut findings = Vec::new();
+        for manifest in &context.packages {
+            let Some(name) = manifest.name() else {
+                continue;
+            };
+            let Some(folder) = Path::new(&manifest.folder).file_name() else {
+                continue;
+            };
+            let folder = folder.to_string_lossy();
+            let unscoped = name.rsplit('/').next().unwrap_or(name);
+            if unscoped != folder {
+                let message = tf!("check.manifest.rule.folder_name", folder);
+                findings.push(Finding::new(manifest, message));
+            }
+        }
+        Ok(findings)
+    }
+}
+
+/// engines.node 与工作区不一致（以根目录的声明为准，否则取最常见的值）
+struct EnginesNode;
+
+impl EnginesNode {
+    /// 工作区使用的 engines.node
+    fn reference(context: &ManifestContext) -> Option<String> {
+        if let Some(node) =
+            context.root.as_ref().and_then(|root| root.json["engines"]["node"].as_str())
+        {
+            return Some(node.to_string());
+        }
+
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for manifest in &context.packages {
+            if let Some(node) = manifest.json["engines"]["node"].as_str() {
+                *counts.entry(node).or_default() += 1;
+            }
+        }
+        // 次数相同时取字典序最小的值，保证结果稳定
+        let max = counts.values().copied().max()?;
+        counts.into_iter().find(|(_, count)| *count == max).map(|(node, _)| node.to_string())
+    }
+}
+
+impl ManifestRule for EnginesNode {
+    fn id(&self) -> &'static str {
+        "engines-node"
+    }
+
+    fn default_severity(&self) -> RuleSeverity {
+        RuleSeverity::Warn
+    }
+
+    fn check(&self, context: &ManifestContext) -> Result<Vec<Finding>> {
+        let Some(reference) = Self::reference(context) else {
+            return Ok(Vec::new());
+        };
+
+        let mut findings = Vec::new();
+        for manifest in &context.packages {
+            let engines = manifest.json.get("engines");
+            let (message, fix) = match engines.map(|engines| &engines["node"]) {
+                Some(Value::String(node)) if *node == reference => continue,
+                Some(Value::String(node)) => (
+                    tf!("check.manifest.rule.engines_mismatch", node, reference),
+                    Some(ManifestFix::Replace {
+                        key: vec!["engines".to_string(), "node".to_string()],
+                        from: node.clone(),
+                        to: reference.clone(),
+                    }),
+                ),
+                // 已有 engines 对象时不自动插入嵌套字段
+                Some(_) => (tf!("check.manifest.rule.engines_missing", reference), None),
+                None => (
+                    tf!("check.manifest.rule.engines_missing", reference),
+                    Some(ManifestFix::Insert {
+                        key: "engines".to_string(),
+                        value: json!({ "node": reference }),
+                    }),
+                ),
+            };
+            findings.push(Finding::new(manifest, message).with_fix(fix));
+        }
+        Ok(findings)
+    }
+}
+
+/// 可发布的包依赖了私有包（安装时无法获取）
+struct PrivateDependency;
+
+impl ManifestRule for PrivateDependency {
+    fn id(&self) -> &'static str {
+        "private-dependency"
+    }
+
+    fn default_severity(&self) -> RuleSeverity {
+        RuleSeverity::Error
+    }
+
+    fn check(&self, context: &ManifestContext) -> Result<Vec<Finding>> {
+        let private: BTreeSet<&str> = context
+            .packages
+            .iter()
+            .filter(|manifest| manifest.is_private())
+            .filter_map(Manifest::name)
+            .collect();
+
+        let mut findings = Vec::new();
+        for manifest in context.packages.iter().filter(|manifest| !manifest.is_private()) {
+            for field in RUNTIME_DEPENDENCY_FIELDS {
+                let Some(deps) = manifest.json[*field].as_object() else {
+                    continue;
+                };
+                for dep in deps.keys().filter(|dep| private.contains(dep.as_str())) {
+                    let message = tf!("check.manifest.rule.private_dependency", dep, field);
+                    findings.push(Finding::new(manifest, message));
+                }
+            }
+        }
+        Ok(findings)
+    }
+}
+
+/// 可发布的包缺少 files 或 exports
+struct PublishFields;
+
+impl ManifestRule for PublishFields {
+    fn id(&self) -> &'static str {
+        "publish-fields"
+    }
+
+    fn default_severity(&self) -> RuleSeverity {
+        RuleSeverity::Warn
+    }
+
+    fn check(&self, context: &ManifestContext) -> Result<Vec<Finding>> {
+        let mut findings = Vec::new();
+        for manifest in context.packages.iter().filter(|manifest| !manifest.is_private()) {
+            if !manifest.has_field("files") {
+                findings.push(Finding::new(manifest, t!("check.manifest.rule.missing_files")));
+            }
+            if !manifest.has_field("exports") {
+                findings.push(Finding::new(manifest, t!("check.manifest.rule.missing_exports")));
+            }
+        }
+        Ok(findings)
+    }
+}
+
+/// 脚本名不符合 script_pattern
+struct ScriptNames;
+
+impl ManifestRule for ScriptNames {
+    fn id(&self) -> &'static str {
+        "script-names"
+    }
+
+    fn default_severity(&self) -> RuleSeverity {
+        RuleSeverity::Warn
+    }
+
+    fn check(&self, context: &ManifestContext) -> Result<Vec<Finding>> {
+        let pattern = context.config.script_pattern();
+        let regex = Regex::new(&pattern).map_err(|e| {
+            anyhow::anyhow!(tf!("check.manifest.invalid_script_pattern", pattern, e))
+        })?;
+
+        let mut findings = Vec::new();
+        for manifest in &context.packages {
+            let Some(scripts) = manifest.json["scripts"].as_object() else {
+                continue;
+            };
+            for name in scripts.keys() {
+                if !regex.is_match(name) && !LIFECYCLE_SCRIPTS.contains(&name.as_str()) {
+                    let message = tf!("check.manifest.rule.script_name", name, pattern);
+                    findings.push(Finding::new(manifest, message));
+                }
+            }
+        }
+        Ok(findings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest(folder: &str, json: Value) -> Manifest {
+        Manifest { folder: folder.to_string(), json }
+    }
+
+    fn workspace(
+        root: Option<Value>,
+        packages: Vec<Manifest>,
+        config: &ManifestConfig,
+    ) -> ManifestContext<'_> {
+        ManifestContext { root: root.map(|json| manifest(".", json)), packages, config }
+    }
+
+    /// 有问题的包目录
+    fn folders(findings: &[Finding]) -> Vec<&str> {
+        findings.iter().map(|finding| finding.folder.as_str()).collect()
+    }
+
+    #[test]
+    fn required_fields_only_fix_repository() {
+        let config = ManifestConfig::default();
+        let context = workspace(
+            Some(json!({ "repository": { "type": "git", "url": "https://example.com/mono.git" } })),
+            vec![
+                manifest("packages/core", json!({ "name": "core", "version": "1.0.0" })),
+                manifest("packages/internal", json!({ "name": "internal", "private": true })),
+                manifest(
+                    "packages/done",
+                    json!({ "name": "done", "version": "1.0.0", "license": "MIT", "repository": "x" }),
+                ),
+            ],
+            &config,
+        );
+
+        let findings = RequiredFields.check(&context).unwrap();
+        assert_eq!(folders(&findings), ["packages/core", "packages/core"]);
+        // license 只报告，repository 从根目录补全
+        assert!(findings[0].message.contains("license") && findings[0].fix.is_none());
+        let Some(ManifestFix::Insert { key, value }) = &findings[1].fix else {
+            panic!("expected repository insert");
+        };
+        assert_eq!(key, "repository");
+        assert_eq!(value["url"], "https://example.com/mono.git");
+        assert_eq!(value["directory"], "packages/core");
+
+        let private =
+            workspace(None, vec![manifest("packages/x", json!({ "private": true }))], &config);
+        assert_eq!(folders(&RequiredFields.check(&private).unwrap()), ["packages/x"]);
+    }
+
+    #[test]
+    fn duplicate_names_are_reported_for_each_folder() {
+        let config = ManifestConfig::default();
+        let context = workspace(
+            None,
+            vec![
+                manifest("packages/a", json!({ "name": "core" })),
+                manifest("legacy/a", json!({ "name": "core" })),
+                manifest("packages/b", json!({ "name": "b" })),
+                manifest("packages/c", json!({})),
+            ],
+            &config,
+        );
+
+        let findings = DuplicateName.check(&context).unwrap();
+        assert_eq!(folders(&findings), ["packages/a", "legacy/a"]);
+        assert!(findings[0].message.contains("legacy/a"));
+    }
+
+    #[test]
+    fn folder_name_ignores_scope() {
+        let config = ManifestConfig::default();
+        let context = workspace(
+            None,
+            vec![
+                manifest("packages/core", json!({ "name": "@acme/core" })),
+                manifest("packages/helpers", json!({ "name": "utils" })),
+            ],
+            &config,
+        );
+
+        assert_eq!(folders(&FolderName.check(&context).unwrap()), ["packages/helpers"]);
+    }
+
+    #[test]
+    fn engines_node_follows_root_and_fixes_safe_cases() {
+        let config = ManifestConfig::default();
+        let packages = || {
+            vec![
+                manifest("packages/a", json!({ "engines": { "node": ">=18" } })),
+                manifest("packages/b", json!({ "engines": { "node": ">=16" } })),
+                manifest("packages/c", json!({})),
+                manifest("packages/d", json!({ "engines": { "npm": ">=9" } })),
+            ]
+        };
+
+        let with_root =
+            workspace(Some(json!({ "engines": { "node": ">=18" } })), packages(), &config);
+        let findings = EnginesNode.check(&with_root).unwrap();
+        assert_eq!(folders(&findings), ["packages/b", "packages/c", "packages/d"]);
+        assert!(
+            matches!(&findings[0].fix, Some(ManifestFix::Replace { from, to, .. }) if from == ">=16" && to == ">=18")
+        );
+        assert!(
+            matches!(&findings[1].fix, Some(ManifestFix::Insert { key, .. }) if key == "engines")
+        );
+        // 已有 engines 对象时不自动修复
+        assert!(findings[2].fix.is_none());
+
+        // 没有根目录声明时以最常见的值为准（次数相同取字典序最小）
+        let without_root = workspace(None, packages(), &config);
+        let findings = EnginesNode.check(&without_root).unwrap();
+        assert_eq!(folders(&findings), ["packages/a", "packages/c", "packages/d"]);
+
+        let none = workspace(None, vec![manifest("packages/a", json!({}))], &config);
+        assert!(EnginesNode.check(&none).unwrap().is_empty());
+    }
+
+    #[test]
+    fn private_dependency_checks_runtime_fields_of_public_packages() {
+        let config = ManifestConfig::default();
+        let context = workspace(
+            None,
+            vec![
+                manifest("packages/secret", json!({ "name": "secret", "private": true })),
+                manifest(
+                    "packages/app",
+                    json!({ "name": "app", "peerDependencies": { "secret": "*" } }),
+                ),
+                manifest(
+                    "packages/lib",
+                    json!({ "name": "lib", "devDependencies": { "secret": "*" } }),
+                ),
+                manifest(
+                    "packages/tool",
+                    json!({ "name": "tool", "private": true, "dependencies": { "secret": "*" } }),
+                ),
+            ],
+            &config,
+        );
+
+        assert_eq!(folders(&PrivateDependency.check(&context).unwrap()), ["packages/app"]);
+    }
+
+    #[test]
+    fn publish_fields_skip_private_packages() {
+        let config = ManifestConfig::default();
+        let context = workspace(
+            None,
+            vec![
+                manifest("packages/a", json!({ "files": [] })),
+                manifest("packages/b", json!({ "files": ["dist"], "exports": "./dist/index.js" })),
+                manifest("packages/c", json!({ "private": true })),
+            ],
+            &config,
+        );
+
+        let findings = PublishFields.check(&context).unwrap();
+        // 空数组也算声明了 files
+        assert_eq!(folders(&findings), ["packages/a"]);
+        assert!(findings.iter().all(|finding| finding.fix.is_none()));
+    }
+
+    #[test]
+    fn script_names_follow_pattern_except_lifecycle_scripts() {
+        let config = ManifestConfig::default();
+        let packages = vec![manifest(
+            "packages/a",
+            json!({ "scripts": { "build:prod": "", "test-unit": "", "prepublishOnly": "", "Build": "" } }),
+        )];
+        let default = workspace(None, packages, &config);
+        let findings = ScriptNames.check(&default).unwrap();
+        assert_eq!(folders(&findings), ["packages/a"]);
+        assert!(findings[0].message.contains("Build"));
+
+        let invalid = ManifestConfig { script_pattern: "[".to_string(), ..Default::default() };
+        let invalid = workspace(None, vec![manifest("packages/a", json!({}))], &invalid);
+        assert!(ScriptNames.check(&invalid).is_err());
+    }
+
+    #[test]
+    fn lint_applies_configured_severities() {
+        let config = ManifestConfig {
+            required_fields: vec!["name".to_string()],
+            rules: BTreeMap::from([
+                ("folder-name".to_string(), RuleSeverity::Error),
+                ("publish-fields".to_string(), RuleSeverity::Off),
+                ("no-such-rule".to_string(), RuleSeverity::Warn),
+            ]),
+            ..Default::default()
+        };
+        let context = workspace(
+            None,
+            vec![manifest(
+                "packages/helpers",
+                json!({ "name": "utils", "scripts": { "Build": "" } }),
+            )],
+            &config,
+        );
+
+        let report = lint(&context, &builtin_rules()).unwrap();
+        let issues: Vec<(&str, RuleSeverity)> =
+            report.issues.iter().map(|issue| (issue.rule.as_str(), issue.severity)).collect();
+        assert_eq!(
+            issues,
+            [("folder-name", RuleSeverity::Error), ("script-names", RuleSeverity::Warn)]
+        );
+        assert_eq!((report.errors, report.warnings), (1, 1));
+        assert_eq!(report.unknown_rules, ["no-such-rule"]);
+        assert_eq!(report.packages_checked, 1);
+    }
+}
@@ -16,10 +16,27 @@
 // ============================================================================
 
 pub mod analyzer;
+pub mod boundaries;
 pub mod cache;
+pub mod changelog;
+pub mod changes;
 pub mod checker;
+pub mod cycles;
+pub mod deps_usage;
+pub mod env;
 pub mod executor;
+pub mod git;
+pub mod history;
+pub mod installer;
+pub mod lockfile;
+pub mod manifest;
+pub mod profile;
+pub mod publisher;
+pub mod registry;
+pub mod report;
 pub mod scheduler;
+pub mod updater;
+pub mod versioner;
 
 // 重新导出常用类型
 pub use analyzer::DependencyAnalyzer;
@@ -0,0 +1,240 @@
+// ============================================================================
+// MonoX - 执行性能剖析导出
+// ============================================================================
+//
+// 文件: src/core/profile.rs
+// 职责: 将运行记录导出为 Chrome Trace Event 格式
+// 边界:
+//   - ✅ 任务按并发槽位分配泳道
+//   - ✅ 任务执行区间事件生成
+//   - ✅ 阶段边界、缓存命中、失败重试的瞬时事件生成
+//   - ✅ 剖析文件写入（可由 chrome://tracing 或 Perfetto 打开）
+//   - ❌ 不应包含任务执行逻辑
+//   - ❌ 不应包含 UI 显示逻辑
+//   - ❌ 不应包含 CLI 相关逻辑
+//
+// ============================================================================
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::fs;
+use std::path::Path;
+
+use crate::models::history::{CacheStatus, RunRecord, TaskRecord};
+use crate::models::task::TaskStatus;
+use crate::tf;
+
+/// 进程 ID（单次运行只有一个进程）
+const TRACE_PID: u32 = 1;
+
+/// 阶段泳道的线程 ID，任务槽位从 1 开始
+const STAGE_LANE_TID: usize = 0;
+
+/// Chrome Trace 文件
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ChromeTrace {
+    trace_events: Vec<TraceEvent>,
+    display_time_unit: &'static str,
+    other_data: Value,
+}
+
+/// Chrome Trace 事件
+#[derive(Debug, Serialize)]
+struct TraceEvent {
+    /// 事件名称
+    name: String,
+    /// 事件分类
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cat: Option<&'static str>,
+    /// 事件类型（X: 区间, i: 瞬时, M: 元数据）
+    ph: &'static str,
+    /// 相对运行开始的时间（微秒）
+    ts: u64,
+    /// 持续时间（微秒，仅区间事件）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dur: Option<u64>,
+    pid: u32,
+    tid: usize,
+    /// 瞬时事件作用域（g: 全局, t: 线程）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    s: Option<&'static str>,
+    #[serde(skip_serializing_if = "Value::is_null")]
+    args: Value,
+}
+
+impl TraceEvent {
+    fn metadata(name: &str, tid: usize, value: String) -> Self {
+        Self {
+            name: name.to_string(),
+            cat: None,
+            ph: "M",
+            ts: 0,
+            dur: None,
+            pid: TRACE_PID,
+            tid,
+            s: None,
+            args: json!({ "name": value }),
+        }
+    }
+
+    fn instant(name: String, cat: &'static str, ts: u64, tid: usize, global: bool) -> Self {
+        Self {
+            name,
+            cat: Some(cat),
+            ph: "i",
+            ts,
+            dur: None,
+            pid: TRACE_PID,
+            tid,
+            s: Some(if global { "g" } else { "t" }),
+            args: Value::Null,
+        }
+    }
+}
+
+/// 将运行记录写入 Chrome Trace 格式的剖析文件
+pub fn write_chrome_trace(path: &Path, run: &RunRecord) -> Result<()> {
+    let trace = build_chrome_trace(run);
+    let content = serde_json::to_string_pretty(&trace)?;
+
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent).with_context(|| tf!("profile.write_failed", path.display()))?;
+    }
+    fs::write(path, content).with_context(|| tf!("profile.write_failed", path.display()))?;
+
+    Ok(())
+}
+
+/// 根据运行记录生成 Chrome Trace
+fn build_chrome_trace(run: &RunRecord) -> ChromeTrace {
+    // 时间戳统一转换为相对运行开始的微秒数
+    let to_ts = |ms: u64| ms.saturating_sub(run.started_at) * 1000;
+
+    let mut events = vec![
+        TraceEvent::metadata("process_name", STAGE_LANE_TID, format!("monox run {}", run.command)),
+        TraceEvent::metadata("thread_name", STAGE_LANE_TID, "stages".to_string()),
+    ];
+
+    // 阶段：区间事件 + 开始/结束瞬时事件
+    for stage in &run.stages {
+        events.push(TraceEvent {
+            name: format!("stage {}", stage.stage),
+            cat: Some("stage"),
+            ph: "X",
+            ts: to_ts(stage.started_at),
+            dur: Some(stage.finished_at.saturating_sub(stage.started_at) * 1000),
+            pid: TRACE_PID,
+            tid: STAGE_LANE_TID,
+            s: None,
+            args: json!({ "tasks": stage.tasks }),
+        });
+        events.push(TraceEvent::instant(
+            format!("stage {} start", stage.stage),
+            "stage",
+            to_ts(stage.started_at),
+            STAGE_LANE_TID,
+            true,
+        ));
+        events.push(TraceEvent::instant(
+            format!("stage {} end", stage.stage),
+            "stage",
+            to_ts(stage.finished_at),
+            STAGE_LANE_TID,
+            true,
+        ));
+    }
+
+    // 任务：按并发槽位分配泳道
+    let lanes = assign_lanes(&run.tasks);
+    let lane_count = lanes.iter().flatten().copied().max().unwrap_or(0);
+    for slot in 1..=lane_count {
+        events.push(TraceEvent::metadata("thread_name", slot, format!("slot {}", slot)));
+    }
+
+    for (task, lane) in run.tasks.iter().zip(lanes) {
+        let (Some(lane), Some(started_at)) = (lane, task.started_at) else {
+            // 未实际执行的任务（跳过、命中缓存）记为阶段泳道上的瞬时事件
+            let (name, cat) = match task.cache_status {
+                CacheStatus::Hit => (format!("cache hit: {}", task.key()), "cache"),
+                _ => (format!("skipped: {}", task.key()), "skip"),
+            };
+            let ts = task.ended_at.or(task.started_at).map(to_ts).unwrap_or(0);
+            events.push(TraceEvent::instant(name, cat, ts, STAGE_LANE_TID, false));
+            continue;
+        };
+
+        let ended_at = task.ended_at.unwrap_or(started_at);
+        events.push(TraceEvent {
+            name: task.key(),
+            cat: Some("task"),
+            ph: "X",
+            ts: to_ts(started_at),
+            dur: Some(ended_at.saturating_sub(started_at) * 1000),
+            pid: TRACE_PID,
+            tid: lane,
+            s: None,
+            args: json!({
+                "package": task.package,
+                "stage": task.stage,
+                "status": task.status,
+                "exit_code": task.exit_code,
+                "cache": task.cache_status,
+            }),
+        });
+
+        if task.cache_status == CacheStatus::Hit {
+            events.push(TraceEvent::instant(
+                format!("cache hit: {}", task.key()),
+                "cache",
+                to_ts(started_at),
+                lane,
+                false,
+            ));
+        }
+    }
+
+    ChromeTrace {
+        trace_events: events,
+        display_time_unit: "ms",
+        other_data: json!({
+            "run_id": run.id,
+            "command": run.command,
+            "success": run.success,
+            "duration_ms": run.duration_ms(),
+            "host": run.host,
+        }),
+    }
+}
+
+/// 按开始时间贪心分配并发槽位（返回值与任务一一对应，槽位从 1 开始）
+fn assign_lanes(tasks: &[TaskRecord]) -> Vec<Option<usize>> {
+    let mut order: Vec<usize> = (0..tasks.len())
+        .filter(|&i| tasks[i].started_at.is_some() && tasks[i].status != TaskStatus::Skipped)
+        .collect();
+    order.sort_by_key(|&i| tasks[i].started_at);
+
+    let mut lanes = vec![None; tasks.len()];
+    // 每个槽位当前占用的结束时间
+    let mut lane_ends: Vec<u64> = Vec::new();
+
+    for i in order {
+        let started_at = tasks[i].started_at.unwrap_or(0);
+        let ended_at = tasks[i].ended_at.unwrap_or(started_at);
+
+        let slot = match lane_ends.iter().position(|&end| end <= started_at) {
+            Some(slot) => {
+                lane_ends[slot] = ended_at;
+                slot
+            }
+            None => {
+                lane_ends.push(ended_at);
+                lane_ends.len() - 1
+            }
+        };
+        lanes[i] = Some(slot + 1);
+    }
+
+    lanes
+}
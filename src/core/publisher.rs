@@ -0,0 +1,257 @@
+// ============================================================================
+// MonoX - 发布器
+// ============================================================================
+//
+// 文件: src/core/publisher.rs
+// 职责: 按依赖顺序打包并发布 registry 上尚不存在的工作区包版本
+// 边界:
+//   - ✅ 按构建阶段计算发布顺序，跳过私有包和已发布的版本
+//   - ✅ 打包时将 workspace: 协议改写为实际版本，打包后恢复 package.json
+//   - ✅ 调用 npm pack / npm publish
+//   - ❌ 不应包含版本计算逻辑
+//   - ❌ 不应包含用户交互
+//   - ❌ 不应包含 CLI 参数处理
+//
+// ============================================================================
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::core::analyzer::DependencyAnalyzer;
+use crate::core::installer::ManifestBackup;
+use crate::core::registry::RegistryClient;
+use crate::models::config::Config;
+use crate::tf;
+use crate::utils::json_edit::{self, JsonDocument};
+
+/// 打包时需要改写 workspace: 协议的依赖字段
+const DEP_FIELDS: &[&str] =
+    &["dependencies", "devDependencies", "peerDependencies", "optionalDependencies"];
+
+/// 打包和发布使用的命令
+const NPM: &str = "npm";
+
+/// 包的发布状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PublishStatus {
+    /// 需要发布
+    Pending,
+    /// 该版本已在 registry 上
+    Published,
+    /// 私有包
+    Private,
+}
+
+/// 打包时的依赖范围改写
+#[derive(Debug, Clone, Serialize)]
+pub struct ProtocolRewrite {
+    /// 依赖的工作区包名
+    pub dependency: String,
+    /// 依赖类型
+    pub dep_type: String,
+    /// 原版本规范
+    pub from: String,
+    /// 打包后的版本规范
+    pub to: String,
+}
+
+/// 待发布的包
+#[derive(Debug, Clone, Serialize)]
+pub struct PublishTarget {
+    /// 包名
+    pub name: String,
+    /// 本地版本
+    pub version: String,
+    /// 所在构建阶段（从 1 开始）
+    pub stage: usize,
+    /// 发布状态
+    pub status: PublishStatus,
+    /// 发布到的 registry
+    pub registry: String,
+    /// workspace: 协议改写
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub rewrites: Vec<ProtocolRewrite>,
+    /// 包目录
+    #[serde(skip)]
+    dir: PathBuf,
+}
+
+/// 发布器
+pub struct Publisher {
+    /// 工作区根目录
+    workspace_root: PathBuf,
+    /// registry 客户端
+    registry: RegistryClient,
+    /// 发布标签
+    tag: Option<String>,
+}
+
+impl Publisher {
+    /// 创建发布器
+    pub fn new(workspace_root: PathBuf, registry: RegistryClient, tag: Option<String>) -> Self {
+        Self { workspace_root, registry, tag }
+    }
+
+    /// 按构建阶段列出所有包及其发布状态
+    pub async fn plan(&self) -> Result<Vec<PublishTarget>> {
+        let mut analyzer = DependencyAnalyzer::new(self.workspace_root.clone())
+            .with_verbose(Config::get_verbose());
+        let analysis = analyzer.analyze_workspace()?;
+        if !analysis.circular_dependencies.is_empty() {
+            anyhow::bail!(tf!("publish.circular", analysis.circular_dependencies.len()));
+        }
+
+        let versions: HashMap<&str, &str> =
+            analysis.packages.iter().map(|p| (p.name.as_str(), p.version.as_str())).collect();
+
+        let mut targets = Vec::new();
+        for (index, stage) in analysis.stages.iter().enumerate() {
+            let mut stage: Vec<_> = stage.iter().collect();
+            stage.sort_by(|a, b| a.name.cmp(&b.name));
+
+            for package in stage {
+                let manifest =
+                    JsonDocument::load(&package.absolute_path.join("package.json"))?.value()?;
+                let status = if manifest["private"].as_bool() == Some(true) {
+                    PublishStatus::Private
+                } else if self.is_published(&package.name, &package.version).await? {
+                    PublishStatus::Published
+                } else {
+                    PublishStatus::Pending
+                };
+
+                targets.push(PublishTarget {
+                    name: package.name.clone(),
+                    version: package.version.clone(),
+                    stage: index + 1,
+                    status,
+                    registry: self.registry.registry_for(&package.name).to_string(),
+                    rewrites: protocol_rewrites(&package.name, &manifest, &versions)?,
+                    dir: package.absolute_path.clone(),
+                });
+            }
+        }
+        Ok(targets)
+    }
+
+    /// 打包并发布一个包
+    pub fn publish(&self, target: &PublishTarget) -> Result<()> {
+        let tarball = self.pack(target)?;
+        let outcome = self.run_publish(target, &tarball);
+        let _ = fs::remove_file(&tarball);
+        outcome
+    }
+
+    /// 改写 workspace: 协议后打包，打包完成后恢复 package.json
+    fn pack(&self, target: &PublishTarget) -> Result<PathBuf> {
+        let manifest_path = target.dir.join("package.json");
+        let backup = ManifestBackup::capture(&target.dir, std::slice::from_ref(&manifest_path))?;
+
+        let mut document = JsonDocument::load(&manifest_path)?;
+        for rewrite in &target.rewrites {
+            document.set_string(
+                &[&rewrite.dep_type, &rewrite.dependency],
+                &rewrite.from,
+                &rewrite.to,
+            )?;
+        }
+        json_edit::commit(std::slice::from_ref(&document))?;
+
+        let output = Command::new(NPM).arg("pack").current_dir(&target.dir).output();
+        backup.restore()?;
+
+        let output = output.with_context(|| tf!("publish.spawn_failed", NPM))?;
+        if !output.status.success() {
+            anyhow::bail!(tf!("publish.pack_failed", target.name, error_output(&output.stderr)));
+        }
+
+        // npm pack 在标准输出的最后一行打印生成的文件名
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let file_name = stdout.lines().rev().map(str::trim).find(|line| !line.is_empty());
+        match file_name {
+            Some(file_name) => Ok(target.dir.join(file_name)),
+            None => anyhow::bail!(tf!("publish.pack_failed", target.name, stdout.trim())),
+        }
+    }
+
+    /// 在工作区根目录发布打包文件（与 registry 客户端读取同一个 .npmrc）
+    fn run_publish(&self, target: &PublishTarget, tarball: &Path) -> Result<()> {
+        let mut command = Command::new(NPM);
+        command.arg("publish").arg(tarball).arg("--registry").arg(&target.registry);
+        if let Some(tag) = &self.tag {
+            command.arg("--tag").arg(tag);
+        }
+
+        let output = command
+            .current_dir(&self.workspace_root)
+            .output()
+            .with_context(|| tf!("publish.spawn_failed", NPM))?;
+        if !output.status.success() {
+            anyhow::bail!(tf!("publish.publish_failed", target.name, error_output(&output.stderr)));
+        }
+        Ok(())
+    }
+
+    /// registry 上是否已有该版本
+    ///
+    /// 只有 registry 返回 404 时才视为未发布；网络错误、认证失败等直接返回错误，
+    /// 不使用缓存中的旧结果，避免重复发布或漏发布。
+    async fn is_published(&self, name: &str, version: &str) -> Result<bool> {
+        let packument = self.registry.packument(name).await?;
+        Ok(packument.is_some_and(|packument| packument.versions.contains_key(version)))
+    }
+}
+
+/// npm 的错误输出（去掉 npm notice 等提示行，没有错误行时返回全部输出）
+fn error_output(stderr: &[u8]) -> String {
+    let stderr = String::from_utf8_lossy(stderr);
+    let errors: Vec<&str> = stderr
+        .lines()
+        .filter(|line| line.starts_with("npm error") || line.starts_with("npm ERR!"))
+        .collect();
+    if errors.is_empty() {
+        stderr.trim().to_string()
+    } else {
+        errors.join("\n")
+    }
+}
+
+/// 打包时 workspace: 协议的改写（与 pnpm 发布时的规则一致）
+fn protocol_rewrites(
+    package: &str,
+    manifest: &serde_json::Value,
+    versions: &HashMap<&str, &str>,
+) -> Result<Vec<ProtocolRewrite>> {
+    let mut rewrites = Vec::new();
+    for field in DEP_FIELDS {
+        let Some(deps) = manifest[field].as_object() else {
+            continue;
+        };
+        for (dependency, spec) in deps {
+            let Some(range) = spec.as_str().and_then(|spec| spec.strip_prefix("workspace:")) else {
+                continue;
+            };
+            let Some(version) = versions.get(dependency.as_str()) else {
+                anyhow::bail!(tf!("publish.unknown_workspace_dependency", package, dependency));
+            };
+
+            let to = match range {
+                "*" | "" => version.to_string(),
+                "^" | "~" => format!("{}{}", range, version),
+                range => range.to_string(),
+            };
+            rewrites.push(ProtocolRewrite {
+                dependency: dependency.clone(),
+                dep_type: field.to_string(),
+                from: format!("workspace:{}", range),
+                to,
+            });
+        }
+    }
+    Ok(rewrites)
+}
@@ -0,0 +1,533 @@
+// ============================================================================
+// MonoX - Registry 客户端
+// ============================================================================
+//
+// 文件: src/core/registry.rs
+// 职责: 直接请求 npm registry 获取包元数据，替代 npm view 子进程
+// 边界:
+//   - ✅ registry 地址解析（monox.toml > 环境变量 > .npmrc > 官方源）
+//   - ✅ .npmrc 作用域 registry 和认证信息读取
+//   - ✅ 精简版和完整版 packument 请求
+//   - ✅ 本地磁盘缓存和 ETag 条件请求
+//   - ✅ 离线模式（仅使用缓存，缓存缺失时报错）
+//   - ✅ 同一进程内相同包的请求去重
+//   - ❌ 不应包含版本比较和过期判断逻辑
+//   - ❌ 不应包含 UI 显示逻辑
+//   - ❌ 不应包含 CLI 相关逻辑
+//
+// ============================================================================
+
+use anyhow::{Context, Result};
+use reqwest::header::{ACCEPT, AUTHORIZATION, ETAG, IF_NONE_MATCH};
+use reqwest::StatusCode;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::OnceCell;
+
+use crate::core::history::MONOX_DIR;
+use crate::models::config::Config;
+use crate::models::registry::{CachedPackument, Packument};
+use crate::tf;
+use crate::utils::performance::now_millis;
+
+/// npm 官方源
+pub const DEFAULT_REGISTRY: &str = "https://registry.npmjs.org/";
+
+/// 缓存目录名（位于 .monox 下）
+const CACHE_DIR: &str = "registry-cache";
+
+/// 精简版 packument 的 Accept 头
+const ABBREVIATED_ACCEPT: &str =
+    "application/vnd.npm.install-v1+json; q=1.0, application/json; q=0.8, */*";
+
+/// 完整版 packument 的 Accept 头
+const FULL_ACCEPT: &str = "application/json";
+
+/// 请求超时时间
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 单个包的请求结果（并发请求共享）
+type PackumentCell = Arc<OnceCell<Option<Arc<Packument>>>>;
+
+/// registry 认证方式
+#[derive(Debug, Clone)]
+enum RegistryAuth {
+    /// _authToken
+    Bearer(String),
+    /// _auth（已 base64 编码的 用户名:密码）
+    Basic(String),
+}
+
+/// .npmrc 中与 registry 相关的配置
+#[derive(Debug, Clone, Default)]
+struct NpmrcConfig {
+    /// 默认 registry
+    registry: Option<String>,
+    /// 作用域 registry（"@scope" -> 地址）
+    scopes: HashMap<String, String>,
+    /// 认证信息（"//host/path/" -> 认证，空字符串表示默认 registry）
+    auth: HashMap<String, RegistryAuth>,
+}
+
+impl NpmrcConfig {
+    /// 解析 .npmrc 内容（支持 ${ENV} 变量展开）
+    fn parse(content: &str) -> Self {
+        let mut config = Self::default();
+
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let key = key.trim();
+            let value = expand_env(value.trim().trim_matches('"').trim_matches('\''));
+
+            if key == "registry" {
+                config.registry = Some(value);
+            } else if let Some(scope) = key.strip_suffix(":registry") {
+                config.scopes.insert(scope.to_string(), value);
+            } else if let Some(prefix) = key.strip_suffix(":_authToken") {
+                config.auth.insert(normalize_auth_key(prefix), RegistryAuth::Bearer(value));
+            } else if let Some(prefix) = key.strip_suffix(":_auth") {
+                config.auth.insert(normalize_auth_key(prefix), RegistryAuth::Basic(value));
+            } else if key == "_authToken" {
+                config.auth.insert(String::new(), RegistryAuth::Bearer(value));
+            } else if key == "_auth" {
+                config.auth.insert(String::new(), RegistryAuth::Basic(value));
+            }
+        }
+
+        config
+    }
+
+    /// 合并配置（other 优先）
+    fn merge(mut self, other: Self) -> Self {
+        if other.registry.is_some() {
+            self.registry = other.registry;
+        }
+        self.scopes.extend(other.scopes);
+        self.auth.extend(other.auth);
+        self
+    }
+}
+
+/// npm registry 客户端
+pub struct RegistryClient {
+    /// HTTP 客户端
+    http: reqwest::Client,
+    /// 默认 registry 地址（以 / 结尾）
+    registry: String,
+    /// 作用域 registry
+    scopes: HashMap<String, String>,
+    /// 认证信息
+    auth: HashMap<String, RegistryAuth>,
+    /// 缓存目录
+    cache_dir: PathBuf,
+    /// 离线模式
+    offline: bool,
+    /// 进程内请求结果（相同包只请求一次）
+    memo: Mutex<HashMap<String, PackumentCell>>,
+}
+
+impl RegistryClient {
+    /// 根据 monox.toml、环境变量和 .npmrc 创建客户端
+    pub fn from_config(workspace_root: &Path) -> Result<Self> {
+        let config = Config::get_registry_config();
+
+        // 用户级 .npmrc 优先级低于项目级
+        let user_npmrc =
+            home_dir().map(|home| load_npmrc(&home.join(".npmrc"))).unwrap_or_default();
+        let npmrc = user_npmrc.merge(load_npmrc(&workspace_root.join(".npmrc")));
+
+        let registry = Some(config.url)
+            .filter(|url| !url.is_empty())
+            .or_else(|| std::env::var("npm_config_registry").ok())
+            .or_else(|| std::env::var("NPM_CONFIG_REGISTRY").ok())
+            .or(npmrc.registry.clone())
+            .unwrap_or_else(|| DEFAULT_REGISTRY.to_string());
+
+        Self::new(&registry, npmrc, workspace_root.join(MONOX_DIR).join(CACHE_DIR), config.offline)
+    }
+
+    /// 使用已解析的 registry 地址和 .npmrc 配置创建客户端
+    fn new(registry: &str, npmrc: NpmrcConfig, cache_dir: PathBuf, offline: bool) -> Result<Self> {
+        let http = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .user_agent(format!("monox/{}", env!("CARGO_PKG_VERSION")))
+            .build()?;
+
+        Ok(Self {
+            http,
+            registry: with_trailing_slash(registry),
+            scopes: npmrc.scopes.iter().map(|(k, v)| (k.clone(), with_trailing_slash(v))).collect(),
+            auth: npmrc.auth,
+            cache_dir,
+            offline,
+            memo: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// 设置离线模式（true 时覆盖配置）
+    pub fn with_offline(mut self, offline: bool) -> Self {
+        self.offline |= offline;
+        self
+    }
+
+    /// 强制联网查询（发布状态等必须以 registry 当前状态为准的场景，忽略离线配置）
+    pub fn online(mut self) -> Self {
+        self.offline = false;
+        self
+    }
+
+    /// 覆盖默认 registry 地址（作用域 registry 不受影响，与 npm --registry 一致）
+    pub fn with_registry(mut self, registry: Option<String>) -> Self {
+        if let Some(registry) = registry {
+            self.registry = with_trailing_slash(&registry);
+        }
+        self
+    }
+
+    /// 获取包元数据（同一进程内相同包只请求一次）
+    pub async fn packument(&self, name: &str) -> Result<Option<Arc<Packument>>> {
+        self.memoized(name, false).await
+    }
+
+    /// 获取完整版包元数据（包含发布时间、仓库地址，体积较大）
+    pub async fn full_packument(&self, name: &str) -> Result<Option<Arc<Packument>>> {
+        self.memoized(name, true).await
+    }
+
+    /// 按包名和格式去重请求
+    async fn memoized(&self, name: &str, full: bool) -> Result<Option<Arc<Packument>>> {
+        let key = if full { format!("{}#full", name) } else { name.to_string() };
+        let cell = {
+            let mut memo = self.memo.lock().unwrap();
+            Arc::clone(memo.entry(key).or_default())
+        };
+
+        let packument = cell.get_or_try_init(|| self.fetch_packument(name, full)).await?;
+        Ok(packument.clone())
+    }
+
+    /// 请求包元数据（带缓存和 ETag 条件请求）
+    ///
+    /// 只有 registry 明确返回 404 时才视为包不存在；网络错误和离线模式下的缓存缺失都返回错误，
+    /// 避免把过期的缓存或查询失败当作查询结果。
+    async fn fetch_packument(&self, name: &str, full: bool) -> Result<Option<Arc<Packument>>> {
+        let url = format!("{}{}", self.registry_for(name), encode_package_name(name));
+        let cache_path = self.cache_path(&url, full);
+        let cached = read_cache(&cache_path);
+
+        if self.offline {
+            let entry =
+                cached.ok_or_else(|| anyhow::anyhow!(tf!("registry.offline_miss", name)))?;
+            return parse_packument(&url, entry.body).map(Some);
+        }
+
+        let accept = if full { FULL_ACCEPT } else { ABBREVIATED_ACCEPT };
+        let mut request = self.http.get(&url).header(ACCEPT, accept);
+        if let Some(auth) = self.auth_for(&url) {
+            request = request.header(AUTHORIZATION, auth);
+        }
+        if let Some(etag) = cached.as_ref().and_then(|entry| entry.etag.as_deref()) {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+
+        let response = request.send().await.with_context(|| tf!("registry.request_failed", url))?;
+
+        match response.status() {
+            StatusCode::NOT_MODIFIED => match cached {
+                Some(mut entry) => {
+                    entry.fetched_at = now_millis();
+                    let _ = write_cache(&cache_path, &entry);
+                    parse_packument(&url, entry.body).map(Some)
+                }
+                None => anyhow::bail!(tf!("registry.unexpected_status", 304, url)),
+            },
+            StatusCode::NOT_FOUND => Ok(None),
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                anyhow::bail!(tf!("registry.auth_failed", url, response.status().as_u16()))
+            }
+            status if status.is_success() => {
+                let etag =
+                    response.headers().get(ETAG).and_then(|v| v.to_str().ok()).map(String::from);
+                let mut body: serde_json::Value =
+                    response.json().await.with_context(|| tf!("registry.invalid_response", url))?;
+                if full {
+                    strip_full_body(&mut body);
+                }
+
+                let entry = CachedPackument { etag, fetched_at: now_millis(), body };
+                let _ = write_cache(&cache_path, &entry);
+                parse_packument(&url, entry.body).map(Some)
+            }
+            status => anyhow::bail!(tf!("registry.unexpected_status", status.as_u16(), url)),
+        }
+    }
+
+    /// 获取包对应的 registry（作用域 registry 优先）
+    pub fn registry_for(&self, name: &str) -> &str {
+        name.split_once('/')
+            .filter(|(scope, _)| scope.starts_with('@'))
+            .and_then(|(scope, _)| self.scopes.get(scope))
+            .unwrap_or(&self.registry)
+    }
+
+    /// 获取请求地址对应的认证头（按最长前缀匹配）
+    fn auth_for(&self, url: &str) -> Option<String> {
+        let target = url.split_once(':').map(|(_, rest)| rest).unwrap_or(url);
+
+        let auth = self
+            .auth
+            .iter()
+            .filter(|(prefix, _)| !prefix.is_empty() && target.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, auth)| auth)
+            .or_else(|| {
+                // 未限定地址的认证仅用于默认 registry
+                url.starts_with(&self.registry).then(|| self.auth.get("")).flatten()
+            })?;
+
+        Some(match auth {
+            RegistryAuth::Bearer(token) => format!("Bearer {}", token),
+            RegistryAuth::Basic(credentials) => format!("Basic {}", credentials),
+        })
+    }
+
+    /// 缓存文件路径（完整版使用 .full.json 后缀）
+    fn cache_path(&self, url: &str, full: bool) -> PathBuf {
+        let without_scheme = url.split_once("://").map(|(_, rest)| rest).unwrap_or(url);
+        let file_name: String = without_scheme
+            .chars()
+            .map(
+                |c| if c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '@') { c } else { '_' },
+            )
+            .collect();
+        let suffix = if full { "full.json" } else { "json" };
+        self.cache_dir.join(format!("{}.{}", file_name, suffix))
+    }
+}
+
+/// 加载 .npmrc（文件不存在或无法读取时返回空配置）
+fn load_npmrc(path: &Path) -> NpmrcConfig {
+    fs::read_to_string(path).map(|content| NpmrcConfig::parse(&content)).unwrap_or_default()
+}
+
+/// 用户主目录
+fn home_dir() -> Option<PathBuf> {
+    std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE")).map(PathBuf::from)
+}
+
+/// 展开 ${VAR} 形式的环境变量（未定义时替换为空）
+fn expand_env(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find("${") {
+        let Some(end) = rest[start..].find('}') else {
+            break;
+        };
+        result.push_str(&rest[..start]);
+        result.push_str(&std::env::var(&rest[start + 2..start + end]).unwrap_or_default());
+        rest = &rest[start + end + 1..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// 规范化认证键（"//host/path" -> "//host/path/"）
+fn normalize_auth_key(prefix: &str) -> String {
+    let prefix =
+        prefix.strip_prefix("https:").or_else(|| prefix.strip_prefix("http:")).unwrap_or(prefix);
+    with_trailing_slash(prefix)
+}
+
+/// 确保地址以 / 结尾
+fn with_trailing_slash(url: &str) -> String {
+    if url.ends_with('/') {
+        url.to_string()
+    } else {
+        format!("{}/", url)
+    }
+}
+
+/// 编码包名（作用域包的 / 需要转义）
+fn encode_package_name(name: &str) -> String {
+    name.replace('/', "%2f")
+}
+
+/// 解析缓存中的包元数据
+fn parse_packument(url: &str, body: serde_json::Value) -> Result<Arc<Packument>> {
+    let packument: Packument =
+        serde_json::from_value(body).with_context(|| tf!("registry.invalid_response", url))?;
+    Ok(Arc::new(packument))
+}
+
+/// 去掉完整版元数据中用不到的大字段（readme、各版本的 manifest 等）
+fn strip_full_body(body: &mut serde_json::Value) {
+    if let Some(object) = body.as_object_mut() {
+        object.remove("readme");
+        object.remove("users");
+    }
+    if let Some(versions) = body["versions"].as_object_mut() {
+        for manifest in versions.values_mut().filter_map(|v| v.as_object_mut()) {
+            manifest.retain(|key, _| matches!(key.as_str(), "version" | "deprecated"));
+        }
+    }
+}
+
+/// 读取缓存（不存在或损坏时返回 None）
+fn read_cache(path: &Path) -> Option<CachedPackument> {
+    let content = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// 写入缓存
+fn write_cache(path: &Path, entry: &CachedPackument) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, serde_json::to_string(entry)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_registry::{packument_body, MockRegistry, MockRequest, MockResponse};
+    use std::net::TcpListener;
+
+    /// 总是返回指定包元数据的处理函数
+    fn serve(name: &'static str, version: &'static str) -> impl Fn(&MockRequest) -> MockResponse {
+        move |_| (200, Vec::new(), packument_body(name, version))
+    }
+
+    fn client(registry: &str, npmrc: &str, cache_dir: &Path, offline: bool) -> RegistryClient {
+        RegistryClient::new(registry, NpmrcConfig::parse(npmrc), cache_dir.to_path_buf(), offline)
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn scoped_registry_uses_its_own_auth_token() {
+        let default = MockRegistry::start(serve("lodash", "4.17.21"));
+        let scoped = MockRegistry::start(serve("@acme/ui", "1.2.0"));
+        let scoped_host = scoped.url.trim_start_matches("http:");
+        let npmrc = format!(
+            "@acme:registry={}\n{}:_authToken=scoped-token\n_auth=dXNlcjpwYXNz\n",
+            scoped.url, scoped_host
+        );
+        let cache = tempfile::tempdir().unwrap();
+        let client = client(&default.url, &npmrc, cache.path(), false);
+
+        let scoped_packument = client.packument("@acme/ui").await.unwrap().unwrap();
+        let default_packument = client.packument("lodash").await.unwrap().unwrap();
+        assert_eq!(scoped_packument.latest(), Some("1.2.0"));
+        assert_eq!(default_packument.latest(), Some("4.17.21"));
+
+        let scoped_requests = scoped.requests();
+        assert_eq!(scoped_requests.len(), 1);
+        assert_eq!(scoped_requests[0].path, "/@acme%2fui");
+        assert_eq!(
+            scoped_requests[0].headers.get("authorization").map(String::as_str),
+            Some("Bearer scoped-token")
+        );
+
+        // 未限定地址的 _auth 只发送给默认 registry
+        let default_requests = default.requests();
+        assert_eq!(default_requests.len(), 1);
+        assert_eq!(
+            default_requests[0].headers.get("authorization").map(String::as_str),
+            Some("Basic dXNlcjpwYXNz")
+        );
+    }
+
+    #[tokio::test]
+    async fn unscoped_auth_is_not_sent_to_scoped_registry() {
+        let default = MockRegistry::start(serve("lodash", "4.17.21"));
+        let scoped = MockRegistry::start(serve("@acme/ui", "1.2.0"));
+        let npmrc = format!("@acme:registry={}\n_authToken=default-token\n", scoped.url);
+        let cache = tempfile::tempdir().unwrap();
+        let client = client(&default.url, &npmrc, cache.path(), false);
+
+        client.packument("@acme/ui").await.unwrap();
+        client.packument("lodash").await.unwrap();
+
+        assert!(!scoped.requests()[0].headers.contains_key("authorization"));
+        assert_eq!(
+            default.requests()[0].headers.get("authorization").map(String::as_str),
+            Some("Bearer default-token")
+        );
+    }
+
+    #[tokio::test]
+    async fn revalidates_cache_with_etag() {
+        let registry = MockRegistry::start(|request| {
+            if request.headers.get("if-none-match").map(String::as_str) == Some("\"v1\"") {
+                (304, Vec::new(), String::new())
+            } else {
+                (200, vec![("ETag", "\"v1\"".to_string())], packument_body("lodash", "4.17.21"))
+            }
+        });
+        let cache = tempfile::tempdir().unwrap();
+
+        let first = client(&registry.url, "", cache.path(), false);
+        assert_eq!(first.packument("lodash").await.unwrap().unwrap().latest(), Some("4.17.21"));
+
+        // 新的客户端没有进程内缓存，使用磁盘缓存的 ETag 发起条件请求
+        let second = client(&registry.url, "", cache.path(), false);
+        assert_eq!(second.packument("lodash").await.unwrap().unwrap().latest(), Some("4.17.21"));
+
+        let requests = registry.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(!requests[0].headers.contains_key("if-none-match"));
+        assert_eq!(requests[1].headers.get("if-none-match").map(String::as_str), Some("\"v1\""));
+    }
+
+    #[tokio::test]
+    async fn offline_reads_only_from_cache() {
+        let registry = MockRegistry::start(serve("lodash", "4.17.21"));
+        let cache = tempfile::tempdir().unwrap();
+
+        client(&registry.url, "", cache.path(), false).packument("lodash").await.unwrap();
+        assert_eq!(registry.requests().len(), 1);
+
+        let offline = client(&registry.url, "", cache.path(), true);
+        let cached = offline.packument("lodash").await.unwrap().unwrap();
+        assert_eq!(cached.latest(), Some("4.17.21"));
+
+        // 缓存缺失时报错，而不是当作包不存在
+        assert!(offline.packument("react").await.is_err());
+        assert_eq!(registry.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn not_found_is_reported_as_missing_package() {
+        let registry = MockRegistry::start(|_| (404, Vec::new(), "{}".to_string()));
+        let cache = tempfile::tempdir().unwrap();
+        let client = client(&registry.url, "", cache.path(), false);
+
+        assert!(client.packument("no-such-package").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn network_error_does_not_fall_back_to_stale_cache() {
+        // 绑定后立即释放端口，得到一个无人监听的地址
+        let url = {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            format!("http://{}/", listener.local_addr().unwrap())
+        };
+        let cache = tempfile::tempdir().unwrap();
+        let client = client(&url, "", cache.path(), false);
+
+        let cache_path = client.cache_path(&format!("{}lodash", url), false);
+        let body = serde_json::from_str(&packument_body("lodash", "4.17.21")).unwrap();
+        write_cache(&cache_path, &CachedPackument { etag: None, fetched_at: 0, body }).unwrap();
+
+        assert!(client.packument("lodash").await.is_err());
+    }
+}
@@ -0,0 +1,174 @@
+// ============================================================================
+// MonoX - 运行报告导出
+// ============================================================================
+//
+// 文件: src/core/report.rs
+// 职责: 将运行结果导出为 JSON / JUnit XML 报告
+// 边界:
+//   - ✅ 报告输出目标解析（格式=路径）
+//   - ✅ JSON 报告生成
+//   - ✅ JUnit XML 报告生成（按阶段分组，供 CI 展示）
+//   - ✅ 报告文件写入
+//   - ❌ 不应包含任务执行逻辑
+//   - ❌ 不应包含 UI 显示逻辑
+//   - ❌ 不应包含 CLI 相关逻辑
+//
+// ============================================================================
+
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::models::report::{ReportFormat, ReportTarget, RunReport, TaskReport};
+use crate::models::task::SkipReason;
+use crate::tf;
+use crate::utils::performance::format_timestamp;
+
+/// 解析报告输出目标列表（如: json=report.json, junit=junit.xml）
+pub fn parse_report_targets(values: &[String]) -> Result<Vec<ReportTarget>> {
+    values
+        .iter()
+        .map(|value| {
+            let (format, path) = value
+                .split_once('=')
+                .filter(|(_, path)| !path.is_empty())
+                .ok_or_else(|| anyhow::anyhow!(tf!("report.invalid_target", value)))?;
+
+            let format = match format {
+                "json" => ReportFormat::Json,
+                "junit" => ReportFormat::Junit,
+                _ => anyhow::bail!(tf!("report.unsupported_format", format)),
+            };
+
+            Ok(ReportTarget { format, path: PathBuf::from(path) })
+        })
+        .collect()
+}
+
+/// 写入运行报告
+pub fn write_report(target: &ReportTarget, report: &RunReport) -> Result<()> {
+    let content = match target.format {
+        ReportFormat::Json => serde_json::to_string_pretty(report)?,
+        ReportFormat::Junit => render_junit(report),
+    };
+
+    write_file(&target.path, &content)
+}
+
+/// 写入文件（自动创建父目录）
+fn write_file(path: &Path, content: &str) -> Result<()> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent).with_context(|| tf!("report.write_failed", path.display()))?;
+    }
+    fs::write(path, content).with_context(|| tf!("report.write_failed", path.display()))?;
+    Ok(())
+}
+
+/// 生成 JUnit XML（每个阶段一个 testsuite，每个包一个 testcase）
+fn render_junit(report: &RunReport) -> String {
+    let mut stages: BTreeMap<usize, Vec<&TaskReport>> = BTreeMap::new();
+    for task in &report.tasks {
+        stages.entry(task.record.stage).or_default().push(task);
+    }
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        "<testsuites name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{}\">",
+        escape_xml(&format!("monox run {}", report.command)),
+        report.tasks.len(),
+        report.failed,
+        report.skipped,
+        format_seconds(report.duration_ms)
+    );
+
+    for (stage, tasks) in &stages {
+        let stage_duration: u64 = tasks.iter().filter_map(|t| t.record.duration_ms).sum();
+        let stage_started_at =
+            tasks.iter().filter_map(|t| t.record.started_at).min().unwrap_or(report.started_at);
+
+        let _ = writeln!(
+            xml,
+            "  <testsuite name=\"stage {}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{}\" timestamp=\"{}\">",
+            stage,
+            tasks.len(),
+            tasks.iter().filter(|t| t.is_failed()).count(),
+            tasks.iter().filter(|t| t.is_skipped()).count(),
+            format_seconds(stage_duration),
+            format_timestamp(stage_started_at).replace(' ', "T")
+        );
+
+        for task in tasks {
+            render_testcase(&mut xml, task);
+        }
+
+        xml.push_str("  </testsuite>\n");
+    }
+
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+/// 生成单个 testcase 节点
+fn render_testcase(xml: &mut String, task: &TaskReport) {
+    let record = &task.record;
+    let _ = writeln!(
+        xml,
+        "    <testcase classname=\"{}\" name=\"{}\" time=\"{}\">",
+        escape_xml(&record.package),
+        escape_xml(&record.command),
+        format_seconds(record.duration_ms.unwrap_or(0))
+    );
+
+    if task.is_failed() {
+        let exit_code = record.exit_code.map(|code| code.to_string()).unwrap_or_default();
+        let _ = writeln!(
+            xml,
+            "      <failure message=\"exit code {}\" type=\"exit_code\">{}</failure>",
+            escape_xml(&exit_code),
+            escape_xml(&task.stderr)
+        );
+    } else if task.is_skipped() {
+        let reason = match record.skip_reason {
+            Some(SkipReason::ScriptNotFound) => "script_not_found",
+            Some(SkipReason::UpstreamFailed) => "upstream_failed",
+            Some(SkipReason::Cancelled) => "cancelled",
+            None => "skipped",
+        };
+        let _ = writeln!(xml, "      <skipped message=\"{}\"/>", reason);
+    }
+
+    if !task.stdout.is_empty() {
+        let _ = writeln!(xml, "      <system-out>{}</system-out>", escape_xml(&task.stdout));
+    }
+    if !task.stderr.is_empty() {
+        let _ = writeln!(xml, "      <system-err>{}</system-err>", escape_xml(&task.stderr));
+    }
+
+    xml.push_str("    </testcase>\n");
+}
+
+/// 毫秒转换为 JUnit 使用的秒数
+fn format_seconds(ms: u64) -> String {
+    format!("{:.3}", ms as f64 / 1000.0)
+}
+
+/// XML 转义（同时去除 XML 1.0 不允许的控制字符，如 ANSI 颜色码）
+fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\t' | '\n' | '\r' => escaped.push(c),
+            c if (c as u32) < 0x20 => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,601 @@
+// ============================================================================
+// MonoX - 依赖更新器
+// ============================================================================
+//
+// 文件: src/core/updater.rs
+// 职责: 根据更新级别和 [update] 策略为工作区依赖生成更新方案
+// 边界:
+//   - ✅ 按包名、依赖类型筛选依赖使用
+//   - ✅ 按更新级别和发布标签选择目标版本
+//   - ✅ 固定、忽略依赖和允许范围等策略处理
+//   - ✅ 保留原有范围前缀生成新的版本规范
+//   - ✅ 目标版本的发布信息（发布时间、弃用、跳过的版本、本地 CHANGELOG 摘要）
+//   - ❌ 不应包含 package.json 写入逻辑
+//   - ❌ 不应包含用户交互
+//   - ❌ 不应包含国际化文本
+//
+// ============================================================================
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::core::checker::{should_skip_dependency, UpdateLevel};
+use crate::core::registry::RegistryClient;
+use crate::models::config::{matches_any, RegistryConfig, UpdateConfig};
+use crate::models::registry::Packument;
+use crate::utils::semver_range::NpmVersionSpec;
+
+/// 本地 CHANGELOG 文件名（按顺序查找）
+const CHANGELOG_FILES: &[&str] = &["CHANGELOG.md", "changelog.md", "Changelog.md", "HISTORY.md"];
+
+/// 每个版本保留的 CHANGELOG 摘要行数
+const MAX_CHANGELOG_LINES: usize = 5;
+
+/// 所有参与更新的依赖字段
+const ALL_DEP_FIELDS: &[&str] = &["dependencies", "devDependencies", "peerDependencies"];
+
+/// 依赖类型 (prod, dev, peer) 对应的 package.json 字段
+pub fn dep_fields(dep_type: Option<&str>) -> &'static [&'static str] {
+    match dep_type {
+        Some("prod") => &["dependencies"],
+        Some("dev") => &["devDependencies"],
+        Some("peer") => &["peerDependencies"],
+        _ => ALL_DEP_FIELDS,
+    }
+}
+
+/// 更新选项
+#[derive(Debug, Clone)]
+pub struct UpdateOptions {
+    /// 只更新指定依赖（为空时检查所有依赖）
+    pub dependency: Option<String>,
+    /// 指定目标版本规范
+    pub version: Option<String>,
+    /// 允许的最高更新级别
+    pub target: UpdateLevel,
+    /// 工作区包名过滤（支持通配符，为空时不过滤）
+    pub packages: Vec<String>,
+    /// 参与更新的依赖字段
+    pub dep_fields: &'static [&'static str],
+}
+
+/// 待执行的依赖更新
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateCandidate {
+    /// 依赖名
+    pub dependency: String,
+    /// 所在的包名
+    pub package: String,
+    /// 依赖类型
+    pub dep_type: String,
+    /// 原版本规范
+    pub old_spec: String,
+    /// 新版本规范
+    pub new_spec: String,
+    /// 更新级别
+    pub level: UpdateLevel,
+    /// 目标版本的发布信息（无法获取时为空）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release: Option<ReleaseInfo>,
+    /// 所在包的目录（用于查找本地 CHANGELOG）
+    #[serde(skip)]
+    pub package_dir: PathBuf,
+}
+
+/// 目标版本的发布信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseInfo {
+    /// 目标版本发布时间
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub published: Option<String>,
+    /// 源码仓库地址
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<String>,
+    /// 目标版本的弃用说明
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<String>,
+    /// 是否可能包含破坏性变更（主版本更新、0.x 次版本更新或 CHANGELOG 提及）
+    pub breaking: bool,
+    /// 当前版本和目标版本之间跳过的正式版本
+    pub skipped: Vec<SkippedVersion>,
+    /// 本地 CHANGELOG 路径
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub changelog_path: Option<String>,
+    /// 本地 CHANGELOG 中目标范围内的条目
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changelog: Vec<ChangelogEntry>,
+}
+
+/// 跳过的版本
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkippedVersion {
+    /// 版本号
+    pub version: String,
+    /// 发布时间
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub published: Option<String>,
+    /// 是否已弃用
+    pub deprecated: bool,
+}
+
+/// CHANGELOG 条目摘要
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangelogEntry {
+    /// 版本号
+    pub version: String,
+    /// 是否提及破坏性变更
+    pub breaking: bool,
+    /// 摘要内容（前几行）
+    pub lines: Vec<String>,
+}
+
+/// 因策略未更新的依赖
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HeldUpdate {
+    /// 依赖名
+    pub dependency: String,
+    /// 所在的包名
+    pub package: String,
+    /// 当前版本规范
+    pub spec: String,
+    /// 可用的新版本
+    pub available: String,
+    /// 保留原因
+    pub reason: HoldReason,
+}
+
+/// 保留原因
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HoldReason {
+    /// 依赖被固定版本
+    Pinned,
+    /// 新版本不在允许的范围内
+    NotAllowed(String),
+}
+
+/// 更新方案
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdatePlan {
+    /// 待执行的更新
+    pub updates: Vec<UpdateCandidate>,
+    /// 因策略未更新的依赖
+    pub held: Vec<HeldUpdate>,
+}
+
+/// 单个依赖的一次使用
+#[derive(Debug, Clone)]
+struct DependencyUsage {
+    dependency: String,
+    package: String,
+    package_dir: PathBuf,
+    dep_type: String,
+    spec: String,
+}
+
+/// 依赖更新器
+pub struct DependencyUpdater {
+    /// registry 客户端
+    registry: Arc<RegistryClient>,
+    /// 依赖源配置（发布标签）
+    registry_config: RegistryConfig,
+    /// 更新策略
+    policy: UpdateConfig,
+}
+
+impl DependencyUpdater {
+    /// 创建新的依赖更新器
+    pub fn new(
+        registry: RegistryClient,
+        registry_config: RegistryConfig,
+        policy: UpdateConfig,
+    ) -> Self {
+        Self { registry: Arc::new(registry), registry_config, policy }
+    }
+
+    /// 为给定的 package.json 文件生成更新方案
+    pub async fn plan(
+        &self,
+        package_files: &[PathBuf],
+        options: &UpdateOptions,
+    ) -> Result<UpdatePlan> {
+        let usages = self.collect_usages(package_files, options)?;
+        let mut plan = UpdatePlan::default();
+
+        if let Some(version) = &options.version {
+            for usage in usages {
+                self.plan_explicit(usage, version, &mut plan);
+            }
+            self.attach_release_info(&mut plan).await;
+            return Ok(plan);
+        }
+
+        let packuments = self.fetch_packuments(&usages).await?;
+        for usage in usages {
+            let Some(spec) = NpmVersionSpec::parse(&usage.spec) else {
+                continue;
+            };
+            let Some(current) = spec.min_version() else {
+                continue;
+            };
+            let registry_name = spec.alias.as_deref().unwrap_or(&usage.dependency);
+            let Some(packument) = packuments.get(registry_name) else {
+                continue;
+            };
+
+            let dist_tag = self.registry_config.dist_tag_for(&usage.dependency);
+            let Some(best) = select_version(packument, &current, options.target, None, dist_tag)
+            else {
+                continue;
+            };
+
+            if self.policy.is_pinned(&usage.dependency) {
+                plan.held.push(held(usage, best.to_string(), HoldReason::Pinned));
+                continue;
+            }
+
+            let allowed = self.allowed_range(&usage.dependency);
+            let chosen = match &allowed {
+                Some((range, allowed_spec)) => {
+                    match select_version(
+                        packument,
+                        &current,
+                        options.target,
+                        Some(allowed_spec),
+                        dist_tag,
+                    ) {
+                        Some(version) => version,
+                        None => {
+                            let reason = HoldReason::NotAllowed(range.clone());
+                            plan.held.push(held(usage, best.to_string(), reason));
+                            continue;
+                        }
+                    }
+                }
+                None => best,
+            };
+
+            plan.updates.push(UpdateCandidate {
+                new_spec: rewrite_spec(&usage.spec, &chosen),
+                level: UpdateLevel::between(&current, &chosen),
+                dependency: usage.dependency,
+                package: usage.package,
+                dep_type: usage.dep_type,
+                old_spec: usage.spec,
+                release: None,
+                package_dir: usage.package_dir,
+            });
+        }
+
+        self.attach_release_info(&mut plan).await;
+        Ok(plan)
+    }
+
+    /// 使用指定版本生成更新（版本规范由调用方校验）
+    fn plan_explicit(&self, usage: DependencyUsage, version: &str, plan: &mut UpdatePlan) {
+        let Some(target) = NpmVersionSpec::parse(version).and_then(|spec| spec.min_version())
+        else {
+            return;
+        };
+
+        if usage.spec == version {
+            return;
+        }
+        if self.policy.is_pinned(&usage.dependency) {
+            plan.held.push(held(usage, version.to_string(), HoldReason::Pinned));
+            return;
+        }
+        if let Some((range, allowed_spec)) = self.allowed_range(&usage.dependency) {
+            if !allowed_spec.matches(&target) {
+                plan.held.push(held(usage, version.to_string(), HoldReason::NotAllowed(range)));
+                return;
+            }
+        }
+
+        let current = NpmVersionSpec::parse(&usage.spec).and_then(|spec| spec.min_version());
+        plan.updates.push(UpdateCandidate {
+            level: current
+                .map(|current| UpdateLevel::between(&current, &target))
+                .unwrap_or(UpdateLevel::Major),
+            new_spec: version.to_string(),
+            dependency: usage.dependency,
+            package: usage.package,
+            dep_type: usage.dep_type,
+            old_spec: usage.spec,
+            release: None,
+            package_dir: usage.package_dir,
+        });
+    }
+
+    /// 收集需要检查的依赖使用
+    fn collect_usages(
+        &self,
+        package_files: &[PathBuf],
+        options: &UpdateOptions,
+    ) -> Result<Vec<DependencyUsage>> {
+        let mut usages = Vec::new();
+
+        for package_file in package_files {
+            let content = fs::read_to_string(package_file)?;
+            let package_json: serde_json::Value = serde_json::from_str(&content)?;
+            let package_name = package_json["name"].as_str().unwrap_or("unknown");
+
+            if !options.packages.is_empty() && !matches_any(&options.packages, package_name) {
+                continue;
+            }
+
+            for dep_type in options.dep_fields {
+                let Some(deps) = package_json[dep_type].as_object() else {
+                    continue;
+                };
+                for (dep_name, version_value) in deps {
+                    let spec = version_value.as_str().unwrap_or("");
+
+                    match &options.dependency {
+                        Some(dependency) if dependency != dep_name => continue,
+                        // 忽略列表只作用于批量更新，显式指定的依赖仍会更新
+                        None if self.policy.is_ignored(dep_name) => continue,
+                        _ => {}
+                    }
+                    if should_skip_dependency(spec) {
+                        continue;
+                    }
+
+                    usages.push(DependencyUsage {
+                        dependency: dep_name.clone(),
+                        package: package_name.to_string(),
+                        package_dir: package_file.parent().unwrap_or(Path::new(".")).to_path_buf(),
+                        dep_type: dep_type.to_string(),
+                        spec: spec.to_string(),
+                    });
+                }
+            }
+        }
+
+        Ok(usages)
+    }
+
+    /// 并发获取所有依赖的包元数据
+    async fn fetch_packuments(
+        &self,
+        usages: &[DependencyUsage],
+    ) -> Result<HashMap<String, Arc<Packument>>> {
+        let mut names: Vec<String> = usages
+            .iter()
+            .filter_map(|usage| {
+                let spec = NpmVersionSpec::parse(&usage.spec)?;
+                Some(spec.alias.unwrap_or_else(|| usage.dependency.clone()))
+            })
+            .collect();
+        names.sort();
+        names.dedup();
+
+        let mut tasks = tokio::task::JoinSet::new();
+        for name in names {
+            let registry = Arc::clone(&self.registry);
+            tasks.spawn(async move {
+                let packument = registry.packument(&name).await;
+                (name, packument)
+            });
+        }
+
+        let mut packuments = HashMap::new();
+        while let Some(joined) = tasks.join_next().await {
+            let (name, packument) = joined?;
+            if let Some(packument) = packument? {
+                packuments.insert(name, packument);
+            }
+        }
+        Ok(packuments)
+    }
+
+    /// 获取完整元数据并补充发布信息（获取失败时跳过，不影响更新）
+    async fn attach_release_info(&self, plan: &mut UpdatePlan) {
+        let names: BTreeSet<String> = plan
+            .updates
+            .iter()
+            .map(|candidate| registry_name(&candidate.dependency, &candidate.old_spec))
+            .collect();
+
+        let mut tasks = tokio::task::JoinSet::new();
+        for name in names {
+            let registry = Arc::clone(&self.registry);
+            tasks.spawn(async move {
+                let packument = registry.full_packument(&name).await;
+                (name, packument)
+            });
+        }
+
+        let mut packuments = HashMap::new();
+        while let Some(joined) = tasks.join_next().await {
+            if let Ok((name, Ok(Some(packument)))) = joined {
+                packuments.insert(name, packument);
+            }
+        }
+
+        for candidate in &mut plan.updates {
+            let name = registry_name(&candidate.dependency, &candidate.old_spec);
+            if let Some(packument) = packuments.get(&name) {
+                candidate.release = release_info(packument, candidate);
+            }
+        }
+    }
+
+    /// 获取依赖允许的版本范围
+    fn allowed_range(&self, name: &str) -> Option<(String, NpmVersionSpec)> {
+        let range = self.policy.allowed.get(name)?;
+        NpmVersionSpec::parse(range).map(|spec| (range.clone(), spec))
+    }
+}
+
+/// 创建保留记录
+fn held(usage: DependencyUsage, available: String, reason: HoldReason) -> HeldUpdate {
+    HeldUpdate {
+        dependency: usage.dependency,
+        package: usage.package,
+        spec: usage.spec,
+        available,
+        reason,
+    }
+}
+
+/// 在更新级别、允许范围和发布标签限制内选择最高版本
+fn select_version(
+    packument: &Packument,
+    current: &semver::Version,
+    target: UpdateLevel,
+    allowed: Option<&NpmVersionSpec>,
+    dist_tag: &str,
+) -> Option<semver::Version> {
+    // 不超过发布标签指向的版本，未发布该标签时回退到 latest
+    let ceiling = packument
+        .dist_tags
+        .get(dist_tag)
+        .map(|version| version.as_str())
+        .or_else(|| packument.latest())
+        .and_then(|version| semver::Version::parse(version).ok())?;
+
+    packument
+        .versions
+        .keys()
+        .filter_map(|version| semver::Version::parse(version).ok())
+        .filter(|version| version > current && version <= &ceiling)
+        .filter(|version| version.pre.is_empty() || version == &ceiling)
+        .filter(|version| UpdateLevel::between(current, version) <= target)
+        .filter(|version| allowed.is_none_or(|spec| spec.matches(version)))
+        .max()
+}
+
+/// 保留原规范的范围前缀（^、~ 或精确版本）生成新规范
+pub fn rewrite_spec(old_spec: &str, version: &semver::Version) -> String {
+    let old_spec = old_spec.trim();
+
+    // npm:<name>@<range> 保留别名部分
+    let split_at = old_spec
+        .strip_prefix("npm:")
+        .and_then(|aliased| aliased.get(1..)?.find('@').map(|i| "npm:".len() + i + 2));
+    let (prefix, range) = match split_at {
+        Some(i) => old_spec.split_at(i),
+        None => ("", old_spec),
+    };
+
+    let operator = if range.starts_with('^') {
+        "^"
+    } else if range.starts_with('~') {
+        "~"
+    } else if semver::Version::parse(range.trim_start_matches(['=', 'v'])).is_ok() {
+        ""
+    } else {
+        "^"
+    };
+
+    format!("{}{}{}", prefix, operator, version)
+}
+
+/// registry 中的真实包名（npm: 别名取别名指向的包）
+fn registry_name(dependency: &str, spec: &str) -> String {
+    NpmVersionSpec::parse(spec)
+        .and_then(|spec| spec.alias)
+        .unwrap_or_else(|| dependency.to_string())
+}
+
+/// 生成目标版本的发布信息
+fn release_info(packument: &Packument, candidate: &UpdateCandidate) -> Option<ReleaseInfo> {
+    let current = NpmVersionSpec::parse(&candidate.old_spec)?.min_version()?;
+    let target = NpmVersionSpec::parse(&candidate.new_spec)?.min_version()?;
+
+    let mut skipped: Vec<(semver::Version, &str)> = packument
+        .versions
+        .keys()
+        .filter_map(|key| semver::Version::parse(key).ok().map(|version| (version, key.as_str())))
+        .filter(|(version, _)| version > &current && version < &target && version.pre.is_empty())
+        .collect();
+    skipped.sort();
+
+    let changelog_path = find_changelog(&candidate.package_dir, &candidate.dependency);
+    let changelog = changelog_path
+        .as_ref()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .map(|content| parse_changelog(&content, &current, &target))
+        .unwrap_or_default();
+
+    // 0.x 版本的次版本更新按 semver 约定同样视为破坏性变更
+    let breaking = candidate.level == UpdateLevel::Major
+        || (target.major == 0 && target.minor != current.minor)
+        || changelog.iter().any(|entry| entry.breaking);
+
+    let target_key = target.to_string();
+    Some(ReleaseInfo {
+        published: packument.time.get(&target_key).cloned(),
+        repository: packument.repository_url(),
+        deprecated: packument.versions.get(&target_key).and_then(|v| v.deprecated.clone()),
+        breaking,
+        skipped: skipped
+            .into_iter()
+            .map(|(_, key)| SkippedVersion {
+                version: key.to_string(),
+                published: packument.time.get(key).cloned(),
+                deprecated: packument.versions[key].deprecated.is_some(),
+            })
+            .collect(),
+        changelog_path: changelog_path.map(|path| path.display().to_string()),
+        changelog,
+    })
+}
+
+/// 按 node 模块解析规则向上查找已安装依赖的 CHANGELOG
+fn find_changelog(package_dir: &Path, dependency: &str) -> Option<PathBuf> {
+    package_dir
+        .ancestors()
+        .flat_map(|dir| {
+            let module_dir = dir.join("node_modules").join(dependency);
+            CHANGELOG_FILES.iter().map(move |file| module_dir.join(file))
+        })
+        .find(|path| path.is_file())
+}
+
+/// 提取 (current, target] 范围内版本的 CHANGELOG 条目
+fn parse_changelog(
+    content: &str,
+    current: &semver::Version,
+    target: &semver::Version,
+) -> Vec<ChangelogEntry> {
+    // 匹配 "## 1.2.3"、"## [1.2.3] - 日期"、"# v1.2.3" 等版本标题
+    let heading = regex::Regex::new(r"^#{1,4}\s*\[?v?(\d+\.\d+\.\d+(?:-[0-9A-Za-z.-]+)?)")
+        .expect("valid changelog heading pattern");
+
+    let mut entries = Vec::new();
+    let mut entry: Option<ChangelogEntry> = None;
+
+    for line in content.lines() {
+        if let Some(captures) = heading.captures(line) {
+            entries.extend(entry.take());
+            if let Ok(version) = semver::Version::parse(&captures[1]) {
+                if &version > current && &version <= target {
+                    entry = Some(ChangelogEntry {
+                        version: version.to_string(),
+                        breaking: false,
+                        lines: Vec::new(),
+                    });
+                }
+            }
+            continue;
+        }
+
+        if let Some(entry) = entry.as_mut() {
+            let text = line.trim().trim_start_matches('#').trim();
+            if text.to_ascii_lowercase().contains("breaking") {
+                entry.breaking = true;
+            }
+            if !text.is_empty() && entry.lines.len() < MAX_CHANGELOG_LINES {
+                entry.lines.push(text.to_string());
+            }
+        }
+    }
+    entries.extend(entry);
+    entries
+}
@@ -0,0 +1,735 @@
+// ============================================================================
+// MonoX - 版本发布器
+// ============================================================================
+//
+// 文件: src/core/versioner.rs
+// 职责: 计算工作区包的版本升级方案并写入 package.json
+// 边界:
+//   - ✅ 版本升级计算（patch、minor、major、prerelease、指定版本）
+//   - ✅ 固定模式和固定组的版本同步
+//   - ✅ 沿依赖图向依赖方级联升级，并同步依赖方声明的版本范围
+//   - ✅ 由变更文件计算各包的升级方案
+//   - ✅ 版本号和版本范围的写入
+//   - ❌ 不应包含 git 操作
+//   - ❌ 不应包含用户交互
+//   - ❌ 不应包含 CLI 参数处理
+//
+// ============================================================================
+
+use anyhow::Result;
+use semver::{Prerelease, Version};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+
+use crate::core::analyzer::DependencyAnalyzer;
+use crate::core::changes::{ChangeFile, ChangeType};
+use crate::core::checker::should_skip_dependency;
+use crate::core::updater;
+use crate::models::config::{matches_any, Config, VersionConfig};
+use crate::tf;
+use crate::utils::json_edit::{self, JsonDocument, TextFile};
+use crate::utils::semver_range::NpmVersionSpec;
+
+/// 需要同步版本范围的依赖字段
+const DEP_FIELDS: &[&str] =
+    &["dependencies", "devDependencies", "peerDependencies", "optionalDependencies"];
+
+/// 会进入发布产物的依赖字段（依赖方需要随之发布新版本）
+const RUNTIME_DEP_FIELDS: &[&str] = &["dependencies", "peerDependencies", "optionalDependencies"];
+
+/// 版本升级方式
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BumpKind {
+    /// 补丁版本
+    Patch,
+    /// 次版本
+    Minor,
+    /// 主版本
+    Major,
+    /// 预发布版本
+    Prerelease,
+    /// 指定版本
+    Exact(Version),
+}
+
+impl BumpKind {
+    /// 解析升级方式（patch、minor、major、prerelease 或版本号）
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "patch" => Some(BumpKind::Patch),
+            "minor" => Some(BumpKind::Minor),
+            "major" => Some(BumpKind::Major),
+            "prerelease" => Some(BumpKind::Prerelease),
+            _ => Version::parse(value.trim_start_matches('v')).ok().map(BumpKind::Exact),
+        }
+    }
+
+    /// 计算升级后的版本（与 npm version 规则一致，预发布版本升级时先去掉预发布标识）
+    pub fn apply(&self, current: &Version, preid: Option<&str>) -> Version {
+        let released = current.pre.is_empty();
+        match self {
+            BumpKind::Major if !released && current.minor == 0 && current.patch == 0 => {
+                Version::new(current.major, 0, 0)
+            }
+            BumpKind::Major => Version::new(current.major + 1, 0, 0),
+            BumpKind::Minor if !released && current.patch == 0 => {
+                Version::new(current.major, current.minor, 0)
+            }
+            BumpKind::Minor => Version::new(current.major, current.minor + 1, 0),
+            BumpKind::Patch if !released => {
+                Version::new(current.major, current.minor, current.patch)
+            }
+            BumpKind::Patch => Version::new(current.major, current.minor, current.patch + 1),
+            BumpKind::Prerelease => next_prerelease(current, preid),
+            BumpKind::Exact(version) => version.clone(),
+        }
+    }
+}
+
+/// 下一个预发布版本：正式版本先升级补丁号，同一标识递增末尾序号，标识变化时从 0 开始
+fn next_prerelease(current: &Version, preid: Option<&str>) -> Version {
+    let mut next = Version::new(current.major, current.minor, current.patch);
+    let pre = if current.pre.is_empty() {
+        next.patch += 1;
+        prerelease_string(preid, 0)
+    } else {
+        let identifiers: Vec<&str> = current.pre.as_str().split('.').collect();
+        let (prefix, number) = match identifiers.last().and_then(|last| last.parse::<u64>().ok()) {
+            Some(number) => (identifiers[..identifiers.len() - 1].join("."), number + 1),
+            None => (current.pre.to_string(), 0),
+        };
+        match preid {
+            Some(preid) if preid != prefix => prerelease_string(Some(preid), 0),
+            _ if prefix.is_empty() => number.to_string(),
+            _ => format!("{}.{}", prefix, number),
+        }
+    };
+    next.pre = Prerelease::new(&pre).unwrap_or(Prerelease::EMPTY);
+    next
+}
+
+/// 预发布版本的标识（去掉末尾序号，如 1.0.0-beta.2 为 beta），正式版本或只有序号时返回 None
+fn prerelease_id(version: &Version) -> Option<String> {
+    let identifiers: Vec<&str> = version.pre.as_str().split('.').collect();
+    let end = match identifiers.last() {
+        Some(last) if last.parse::<u64>().is_ok() => identifiers.len() - 1,
+        _ => identifiers.len(),
+    };
+    Some(identifiers[..end].join(".")).filter(|id| !id.is_empty())
+}
+
+/// 预发布标识字符串（如 alpha.0，未指定标识时只有序号）
+fn prerelease_string(preid: Option<&str>, number: u64) -> String {
+    match preid {
+        Some(preid) if !preid.is_empty() => format!("{}.{}", preid, number),
+        _ => number.to_string(),
+    }
+}
+
+/// 依赖方的级联方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cascade {
+    /// 所有以运行时依赖引用升级包的依赖方都升级补丁版本，并同步版本范围
+    Always,
+    /// 只有声明的版本范围不再包含新版本时才升级依赖方并改写范围
+    OutOfRange,
+}
+
+/// 升级原因
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BumpReason {
+    /// 直接选择
+    Selected,
+    /// 与该包在同一固定组
+    Group(String),
+    /// 依赖的工作区包升级了版本
+    Dependency(String),
+}
+
+/// 包的版本升级
+#[derive(Debug, Clone, Serialize)]
+pub struct PackageBump {
+    /// 包名
+    pub name: String,
+    /// 当前版本
+    pub current: String,
+    /// 新版本
+    pub next: String,
+    /// 升级原因
+    pub reason: BumpReason,
+    /// package.json 路径
+    #[serde(skip)]
+    manifest: PathBuf,
+}
+
+impl PackageBump {
+    /// package.json 路径
+    pub fn manifest(&self) -> &Path {
+        &self.manifest
+    }
+}
+
+/// 依赖方的版本范围更新
+#[derive(Debug, Clone, Serialize)]
+pub struct RangeUpdate {
+    /// 声明依赖的包名
+    pub package: String,
+    /// 依赖的工作区包名
+    pub dependency: String,
+    /// 依赖类型
+    pub dep_type: String,
+    /// 原版本范围
+    pub old_spec: String,
+    /// 新版本范围
+    pub new_spec: String,
+    /// package.json 路径
+    #[serde(skip)]
+    manifest: PathBuf,
+}
+
+/// 版本发布方案
+#[derive(Debug, Clone, Serialize)]
+pub struct VersionPlan {
+    /// 版本升级（按包名排序）
+    pub bumps: Vec<PackageBump>,
+    /// 版本范围更新
+    pub ranges: Vec<RangeUpdate>,
+    /// 需要创建的标签
+    pub tags: Vec<String>,
+}
+
+/// 工作区包清单
+#[derive(Debug, Clone)]
+struct Manifest {
+    /// 包名
+    name: String,
+    /// package.json 路径
+    path: PathBuf,
+    /// 声明的版本
+    raw_version: String,
+    /// 解析后的版本
+    version: Option<Version>,
+    /// 对其他工作区包的依赖 (依赖字段, 包名, 版本规范)
+    dependencies: Vec<(String, String, String)>,
+}
+
+/// 版本发布器
+pub struct Versioner {
+    /// 工作区包清单
+    manifests: Vec<Manifest>,
+    /// 版本发布配置
+    config: VersionConfig,
+}
+
+impl Versioner {
+    /// 读取工作区包和它们之间的依赖
+    pub fn new(workspace_root: PathBuf, config: VersionConfig) -> Result<Self> {
+        let mut analyzer =
+            DependencyAnalyzer::new(workspace_root).with_verbose(Config::get_verbose());
+        let analysis = analyzer.analyze_workspace()?;
+
+        let names: HashSet<&str> = analysis.packages.iter().map(|p| p.name.as_str()).collect();
+        let mut manifests = Vec::new();
+        for package in &analysis.packages {
+            let path = package.absolute_path.join("package.json");
+            let package_json = JsonDocument::load(&path)?.value()?;
+
+            let mut dependencies = Vec::new();
+            for field in DEP_FIELDS {
+                let Some(deps) = package_json[field].as_object() else {
+                    continue;
+                };
+                for (dep_name, spec) in deps {
+                    if let Some(spec) = spec.as_str() {
+                        if names.contains(dep_name.as_str()) {
+                            dependencies.push((
+                                field.to_string(),
+                                dep_name.clone(),
+                                spec.to_string(),
+                            ));
+                        }
+                    }
+                }
+            }
+
+            manifests.push(Manifest {
+                name: package.name.clone(),
+                path,
+                raw_version: package.version.clone(),
+                version: Version::parse(&package.version).ok(),
+                dependencies,
+            });
+        }
+        manifests.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(Self { manifests, config })
+    }
+
+    /// 按包名模式选择包（支持通配符），为空时选择所有包
+    pub fn select(&self, patterns: &[String]) -> Result<Vec<String>> {
+        if patterns.is_empty() {
+            return Ok(self.manifests.iter().map(|m| m.name.clone()).collect());
+        }
+
+        let mut selected = Vec::new();
+        for pattern in patterns {
+            let matched: Vec<String> = self
+                .manifests
+                .iter()
+                .filter(|m| matches_any(std::slice::from_ref(pattern), &m.name))
+                .map(|m| m.name.clone())
+                .collect();
+            if matched.is_empty() {
+                anyhow::bail!(tf!("version.package_not_found", pattern));
+            }
+            selected.extend(matched);
+        }
+        selected.sort();
+        selected.dedup();
+        Ok(selected)
+    }
+
+    /// 计算版本发布方案
+    pub fn plan(
+        &self,
+        kind: &BumpKind,
+        selected: &[String],
+        preid: Option<&str>,
+    ) -> Result<VersionPlan> {
+        let requests: Vec<(String, BumpKind)> =
+            selected.iter().map(|name| (name.clone(), kind.clone())).collect();
+        self.plan_requests(&requests, preid, Cascade::Always)
+    }
+
+    /// 由变更文件计算版本发布方案（同一个包取最高的升级类型）
+    pub fn plan_changes(&self, changes: &[ChangeFile]) -> Result<VersionPlan> {
+        let mut types: BTreeMap<&str, ChangeType> = BTreeMap::new();
+        for change in changes {
+            for release in &change.releases {
+                if !self.manifests.iter().any(|m| m.name == release.package) {
+                    anyhow::bail!(tf!("change.unknown_package", release.package, change.id));
+                }
+                let bump = types.entry(release.package.as_str()).or_insert(release.bump);
+                *bump = (*bump).max(release.bump);
+            }
+        }
+
+        let requests: Vec<(String, BumpKind)> =
+            types.into_iter().map(|(name, bump)| (name.to_string(), bump.bump_kind())).collect();
+        self.plan_requests(&requests, None, Cascade::OutOfRange)
+    }
+
+    /// 按包计算版本发布方案
+    fn plan_requests(
+        &self,
+        requests: &[(String, BumpKind)],
+        preid: Option<&str>,
+        cascade: Cascade,
+    ) -> Result<VersionPlan> {
+        let index: HashMap<&str, usize> =
+            self.manifests.iter().enumerate().map(|(i, m)| (m.name.as_str(), i)).collect();
+        let groups = self.groups();
+
+        let mut planned: BTreeMap<usize, (Version, BumpReason)> = BTreeMap::new();
+        let mut queue = VecDeque::new();
+
+        // 直接选择的包（固定组以组内最高版本为基准）
+        for (name, kind) in requests {
+            let Some(&i) = index.get(name.as_str()) else {
+                anyhow::bail!(tf!("version.package_not_found", name));
+            };
+            let current = self.current_version(i)?;
+            let next = kind.apply(&self.group_base(i, &groups)?, preid);
+            if next <= *current {
+                anyhow::bail!(tf!("version.not_greater", name, next, current));
+            }
+            planned.insert(i, (next, BumpReason::Selected));
+            queue.push_back(i);
+        }
+
+        while let Some(i) = queue.pop_front() {
+            let version = planned[&i].0.clone();
+            let name = &self.manifests[i].name;
+            let trigger_preid = prerelease_id(&version);
+
+            // 固定组内的包同步为组内最高的新版本
+            for group in groups.iter().filter(|group| group.contains(&i)) {
+                let (holder, target) = group
+                    .iter()
+                    .filter_map(|&member| planned.get(&member).map(|(v, _)| (member, v.clone())))
+                    .max_by(|(_, a), (_, b)| a.cmp(b))
+                    .unwrap_or((i, version.clone()));
+                let source = self.manifests[holder].name.clone();
+                for &member in group {
+                    if planned.get(&member).map(|(v, _)| v) != Some(&target) {
+                        planned.insert(member, (target.clone(), BumpReason::Group(source.clone())));
+                        queue.push_back(member);
+                    }
+                }
+            }
+
+            // 以运行时依赖引用该包的依赖方升级补丁版本，触发方是预发布版本时以相同标识升级预发布版本
+            for (j, dependent) in self.manifests.iter().enumerate() {
+                if planned.contains_key(&j) || dependent.version.is_none() {
+                    continue;
+                }
+                let depends = dependent.dependencies.iter().any(|(field, dep_name, spec)| {
+                    dep_name == name
+                        && RUNTIME_DEP_FIELDS.contains(&field.as_str())
+                        && (cascade == Cascade::Always || !spec_includes(spec, &version))
+                });
+                if depends {
+                    let base = self.group_base(j, &groups)?;
+                    let next = if version.pre.is_empty() {
+                        BumpKind::Patch.apply(&base, preid)
+                    } else {
+                        BumpKind::Prerelease.apply(&base, preid.or(trigger_preid.as_deref()))
+                    };
+                    planned.insert(j, (next, BumpReason::Dependency(name.clone())));
+                    queue.push_back(j);
+                }
+            }
+        }
+
+        let bumps: Vec<PackageBump> = planned
+            .iter()
+            .map(|(&i, (next, reason))| PackageBump {
+                name: self.manifests[i].name.clone(),
+                current: self.manifests[i].raw_version.clone(),
+                next: next.to_string(),
+                reason: reason.clone(),
+                manifest: self.manifests[i].path.clone(),
+            })
+            .collect();
+
+        // 所有引用了升级包的依赖方同步版本范围（包括 devDependencies）
+        let mut ranges = Vec::new();
+        for manifest in &self.manifests {
+            for (field, dep_name, spec) in &manifest.dependencies {
+                let Some((next, _)) = index.get(dep_name.as_str()).and_then(|i| planned.get(i))
+                else {
+                    continue;
+                };
+                if cascade == Cascade::OutOfRange && spec_includes(spec, next) {
+                    continue;
+                }
+                if let Some(new_spec) = cascade_spec(dep_name, spec, next) {
+                    if new_spec != *spec {
+                        ranges.push(RangeUpdate {
+                            package: manifest.name.clone(),
+                            dependency: dep_name.clone(),
+                            dep_type: field.clone(),
+                            old_spec: spec.clone(),
+                            new_spec,
+                            manifest: manifest.path.clone(),
+                        });
+                    }
+                }
+            }
+        }
+
+        let mut tags: Vec<String> =
+            bumps.iter().map(|bump| self.config.tag_name(&bump.name, &bump.next)).collect();
+        tags.dedup();
+
+        Ok(VersionPlan { bumps, ranges, tags })
+    }
+
+    /// 写入版本号和版本范围，与给定的文本文件（如 CHANGELOG）一起原子写入，返回修改的文件路径
+    pub fn apply(&self, plan: &VersionPlan, files: &[TextFile]) -> Result<Vec<PathBuf>> {
+        let mut documents: BTreeMap<PathBuf, JsonDocument> = BTreeMap::new();
+
+        for bump in &plan.bumps {
+            let document = document_for(&mut documents, &bump.manifest)?;
+            document.set_string(&["version"], &bump.current, &bump.next)?;
+        }
+        for range in &plan.ranges {
+            let document = document_for(&mut documents, &range.manifest)?;
+            document.set_string(
+                &[&range.dep_type, &range.dependency],
+                &range.old_spec,
+                &range.new_spec,
+            )?;
+        }
+
+        let documents: Vec<JsonDocument> = documents.into_values().collect();
+        json_edit::commit_with(&documents, files)?;
+        Ok(documents
+            .iter()
+            .filter(|doc| doc.is_modified())
+            .map(|doc| doc.path().to_path_buf())
+            .chain(
+                files
+                    .iter()
+                    .filter(|file| file.is_modified())
+                    .map(|file| file.path().to_path_buf()),
+            )
+            .collect())
+    }
+
+    /// 固定组（固定模式下所有包为一组），每组为清单下标列表
+    fn groups(&self) -> Vec<Vec<usize>> {
+        let all: Vec<usize> = (0..self.manifests.len()).collect();
+        if self.config.is_fixed_mode() {
+            return vec![all];
+        }
+
+        self.config
+            .fixed
+            .iter()
+            .map(|patterns| {
+                all.iter()
+                    .copied()
+                    .filter(|&i| matches_any(patterns, &self.manifests[i].name))
+                    .collect()
+            })
+            .filter(|group: &Vec<usize>| group.len() > 1)
+            .collect()
+    }
+
+    /// 升级基准版本：所在固定组的最高版本，不在组内时为自身版本
+    fn group_base(&self, i: usize, groups: &[Vec<usize>]) -> Result<Version> {
+        let mut base = self.current_version(i)?.clone();
+        for group in groups.iter().filter(|group| group.contains(&i)) {
+            for &member in group {
+                if let Some(version) = &self.manifests[member].version {
+                    base = base.max(version.clone());
+                }
+            }
+        }
+        Ok(base)
+    }
+
+    /// 包的当前版本，无法解析时返回错误
+    fn current_version(&self, i: usize) -> Result<&Version> {
+        let manifest = &self.manifests[i];
+        manifest.version.as_ref().ok_or_else(|| {
+            anyhow::anyhow!(tf!("version.invalid_version", manifest.name, manifest.raw_version))
+        })
+    }
+}
+
+/// 获取或加载 package.json 文档
+fn document_for<'a>(
+    documents: &'a mut BTreeMap<PathBuf, JsonDocument>,
+    path: &PathBuf,
+) -> Result<&'a mut JsonDocument> {
+    if !documents.contains_key(path) {
+        documents.insert(path.clone(), JsonDocument::load(path)?);
+    }
+    Ok(documents.get_mut(path).expect("document loaded"))
+}
+
+/// 依赖方声明的版本范围是否包含新版本（workspace:*、file: 等发布时替换或不校验的写法视为包含）
+fn spec_includes(spec: &str, version: &Version) -> bool {
+    let range = spec.strip_prefix("workspace:").unwrap_or(spec);
+    if matches!(range, "*" | "^" | "~") || should_skip_dependency(range) {
+        return true;
+    }
+    NpmVersionSpec::parse(range).is_none_or(|parsed| parsed.matches(version))
+}
+
+/// 依赖方的新版本范围，无需修改时返回 None
+fn cascade_spec(dependency: &str, spec: &str, version: &Version) -> Option<String> {
+    // workspace:*、workspace:^、workspace:~ 发布时由包管理器替换为实际版本
+    if let Some(range) = spec.strip_prefix("workspace:") {
+        if matches!(range, "*" | "^" | "~") {
+            return None;
+        }
+        return Some(format!("workspace:{}", updater::rewrite_spec(range, version)));
+    }
+
+    // file:、link:、git、dist-tag 和指向其他包的 npm: 别名不做修改
+    if should_skip_dependency(spec) {
+        return None;
+    }
+    let parsed = NpmVersionSpec::parse(spec)?;
+    if parsed.alias.as_deref().is_some_and(|alias| alias != dependency) {
+        return None;
+    }
+    Some(updater::rewrite_spec(spec, version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::changes::ChangeRelease;
+
+    fn v(version: &str) -> Version {
+        Version::parse(version).unwrap()
+    }
+
+    fn config(mode: &str, fixed: &[&[&str]]) -> VersionConfig {
+        VersionConfig {
+            mode: mode.to_string(),
+            fixed: fixed
+                .iter()
+                .map(|group| group.iter().map(|name| name.to_string()).collect())
+                .collect(),
+            preid: String::new(),
+            commit_message: String::new(),
+            tag_format: String::new(),
+        }
+    }
+
+    /// 测试包：(包名, 版本, [(依赖字段, 依赖包名, 版本规范)])
+    type TestPackage<'a> = (&'a str, &'a str, &'a [(&'a str, &'a str, &'a str)]);
+
+    /// 构造版本发布器
+    fn workspace(config: VersionConfig, packages: &[TestPackage]) -> Versioner {
+        let manifests = packages
+            .iter()
+            .map(|(name, version, deps)| Manifest {
+                name: name.to_string(),
+                path: PathBuf::from(format!("packages/{}/package.json", name)),
+                raw_version: version.to_string(),
+                version: Version::parse(version).ok(),
+                dependencies: deps
+                    .iter()
+                    .map(|(field, dep, spec)| {
+                        (field.to_string(), dep.to_string(), spec.to_string())
+                    })
+                    .collect(),
+            })
+            .collect();
+        Versioner { manifests, config }
+    }
+
+    fn next_versions(plan: &VersionPlan) -> Vec<(&str, &str)> {
+        plan.bumps.iter().map(|bump| (bump.name.as_str(), bump.next.as_str())).collect()
+    }
+
+    fn change(releases: &[(&str, ChangeType)]) -> ChangeFile {
+        ChangeFile {
+            id: "change".to_string(),
+            releases: releases
+                .iter()
+                .map(|(package, bump)| ChangeRelease { package: package.to_string(), bump: *bump })
+                .collect(),
+            summary: String::new(),
+            path: PathBuf::new(),
+        }
+    }
+
+    #[test]
+    fn bump_kind_apply_follows_npm_version() {
+        assert_eq!(BumpKind::Patch.apply(&v("1.2.3"), None), v("1.2.4"));
+        assert_eq!(BumpKind::Minor.apply(&v("1.2.3"), None), v("1.3.0"));
+        assert_eq!(BumpKind::Major.apply(&v("1.2.3"), None), v("2.0.0"));
+        assert_eq!(BumpKind::Exact(v("3.0.0")).apply(&v("1.2.3"), None), v("3.0.0"));
+
+        // 预发布版本升级时先去掉预发布标识
+        assert_eq!(BumpKind::Patch.apply(&v("1.2.3-beta.1"), None), v("1.2.3"));
+        assert_eq!(BumpKind::Minor.apply(&v("1.3.0-beta.1"), None), v("1.3.0"));
+        assert_eq!(BumpKind::Minor.apply(&v("1.2.3-beta.1"), None), v("1.3.0"));
+        assert_eq!(BumpKind::Major.apply(&v("2.0.0-rc.0"), None), v("2.0.0"));
+        assert_eq!(BumpKind::Major.apply(&v("2.1.0-rc.0"), None), v("3.0.0"));
+    }
+
+    #[test]
+    fn next_prerelease_handles_preid_and_numeric_suffix() {
+        let pre = |current: &str, preid| BumpKind::Prerelease.apply(&v(current), preid);
+
+        // 正式版本先升级补丁号
+        assert_eq!(pre("1.2.3", None), v("1.2.4-0"));
+        assert_eq!(pre("1.2.3", Some("alpha")), v("1.2.4-alpha.0"));
+        // 同一标识递增末尾序号
+        assert_eq!(pre("1.2.4-alpha.0", Some("alpha")), v("1.2.4-alpha.1"));
+        assert_eq!(pre("1.2.4-alpha.0", None), v("1.2.4-alpha.1"));
+        assert_eq!(pre("1.2.4-3", None), v("1.2.4-4"));
+        // 没有序号时追加序号，标识变化时从 0 开始
+        assert_eq!(pre("1.2.4-alpha", None), v("1.2.4-alpha.0"));
+        assert_eq!(pre("1.2.4-alpha.3", Some("beta")), v("1.2.4-beta.0"));
+    }
+
+    #[test]
+    fn fixed_groups_share_the_highest_version() {
+        let versioner = workspace(
+            config("independent", &[&["core", "utils"]]),
+            &[("core", "1.0.0", &[]), ("utils", "1.2.0", &[]), ("app", "0.1.0", &[])],
+        );
+        let plan = versioner.plan(&BumpKind::Patch, &["core".to_string()], None).unwrap();
+
+        assert_eq!(next_versions(&plan), [("core", "1.2.1"), ("utils", "1.2.1")]);
+        assert_eq!(plan.bumps[1].reason, BumpReason::Group("core".to_string()));
+    }
+
+    #[test]
+    fn fixed_mode_bumps_every_package() {
+        let versioner =
+            workspace(config("fixed", &[]), &[("core", "1.0.0", &[]), ("utils", "1.1.0", &[])]);
+        let plan = versioner.plan(&BumpKind::Minor, &["core".to_string()], None).unwrap();
+
+        assert_eq!(next_versions(&plan), [("core", "1.2.0"), ("utils", "1.2.0")]);
+    }
+
+    #[test]
+    fn explicit_bumps_always_cascade_to_runtime_dependents() {
+        let versioner = workspace(
+            config("independent", &[]),
+            &[
+                ("core", "1.0.0", &[]),
+                ("app", "2.0.0", &[("dependencies", "core", "^1.0.0")]),
+                ("docs", "0.1.0", &[("devDependencies", "core", "^1.0.0")]),
+            ],
+        );
+        let plan = versioner.plan(&BumpKind::Patch, &["core".to_string()], None).unwrap();
+
+        // 范围仍然包含新版本也会升级运行时依赖方并同步范围，开发依赖方只同步范围
+        assert_eq!(next_versions(&plan), [("core", "1.0.1"), ("app", "2.0.1")]);
+        assert_eq!(plan.bumps[1].reason, BumpReason::Dependency("core".to_string()));
+        let ranges: Vec<(&str, &str)> =
+            plan.ranges.iter().map(|r| (r.package.as_str(), r.new_spec.as_str())).collect();
+        assert_eq!(ranges, [("app", "^1.0.1"), ("docs", "^1.0.1")]);
+    }
+
+    #[test]
+    fn prerelease_bumps_cascade_with_the_same_preid() {
+        let versioner = workspace(
+            config("independent", &[]),
+            &[("core", "1.0.0", &[]), ("app", "2.0.0", &[("dependencies", "core", "^1.0.0")])],
+        );
+        let plan =
+            versioner.plan(&BumpKind::Prerelease, &["core".to_string()], Some("beta")).unwrap();
+        assert_eq!(next_versions(&plan), [("core", "1.0.1-beta.0"), ("app", "2.0.1-beta.0")]);
+
+        // 未指定标识时沿用触发方的预发布标识
+        let versioner = workspace(
+            config("independent", &[]),
+            &[("core", "1.0.1-rc.0", &[]), ("app", "2.0.0", &[("dependencies", "core", "^1.0.0")])],
+        );
+        let plan = versioner.plan(&BumpKind::Prerelease, &["core".to_string()], None).unwrap();
+        assert_eq!(next_versions(&plan), [("core", "1.0.1-rc.1"), ("app", "2.0.1-rc.0")]);
+    }
+
+    #[test]
+    fn change_files_cascade_only_out_of_range() {
+        let versioner = workspace(
+            config("independent", &[]),
+            &[
+                ("core", "1.0.0", &[]),
+                ("app", "2.0.0", &[("dependencies", "core", "^1.0.0")]),
+                ("legacy", "0.3.0", &[("dependencies", "core", "~1.0.0")]),
+            ],
+        );
+
+        // 补丁版本仍在两个范围内，依赖方不升级
+        let plan = versioner.plan_changes(&[change(&[("core", ChangeType::Patch)])]).unwrap();
+        assert_eq!(next_versions(&plan), [("core", "1.0.1")]);
+        assert!(plan.ranges.is_empty());
+
+        // 次版本超出 ~1.0.0，只升级 legacy 并改写它的范围
+        let plan = versioner
+            .plan_changes(&[
+                change(&[("core", ChangeType::Patch)]),
+                change(&[("core", ChangeType::Minor)]),
+            ])
+            .unwrap();
+        assert_eq!(next_versions(&plan), [("core", "1.1.0"), ("legacy", "0.3.1")]);
+        let ranges: Vec<(&str, &str)> =
+            plan.ranges.iter().map(|r| (r.package.as_str(), r.new_spec.as_str())).collect();
+        assert_eq!(ranges, [("legacy", "~1.1.0")]);
+    }
+}
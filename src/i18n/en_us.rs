@@ -42,10 +42,9 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
         "Detected {} circular dependencies",
     ),
     ("analyze.circular_detail", "Circular dependency {}: {}"),
-    (
-        "analyze.circular_warning",
-        "Warning: Circular dependencies detected, remaining packages: {}",
-    ),
+    ("analyze.unschedulable", "Cannot schedule the build: no build order exists for these packages: {}. Check them for self-dependencies or dependency cycles"),
+    ("analyze.invalid_monox_tags", "Ignoring the monox field in {}: expected an object whose tags is an array of strings"),
+    ("analyze.unknown_ignore_edge", "[workspace.ignore_edges] lists {} -> {}, but there is no such workspace dependency"),
     // Single package analysis related
     ("analyze.single_package_start", "Starting single package analysis: {}"),
     ("analyze.single_package_found", "Found target package '{}': {}"),
@@ -61,11 +60,15 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("check.circular.start", "Starting circular dependency check..."),
     ("check.circular.none_found", "No circular dependencies found"),
     ("check.circular.found", "Found {} circular dependencies"),
+    ("check.circular.ignored", "{} cycles only go through dependency types that do not order builds: {}"),
     ("check.circular.details", "Circular Dependency Details"),
-    ("check.circular.cycle_header", "Cycle {}:"),
-    ("check.circular.cycle_detail", "  {} {} depends on {}"),
+    ("check.circular.cycle_header", "Cycle {} ({}):"),
+    ("check.circular.cycle_detail", "      {} {} depends on {} ({})"),
     ("check.circular.cycle_simple", "  {}"),
-    ("check.circular.suggestion", "Suggestion: Refactor code to eliminate circular dependencies, which may cause build issues"),
+    ("check.circular.truncated", "  ... only the first {} cycles are listed"),
+    ("check.circular.break_header", "  Remove these {} dependencies to break every cycle:"),
+    ("check.circular.break_edge", "    - {} → {} ({})"),
+    ("check.circular.suggestion", "Suggestion: Remove or move the listed dependencies (dev and peer dependencies are preferred), or leave their types out of [workspace] build_dependency_types"),
     ("check.versions.start", "Starting version conflict check..."),
     ("check.versions.not_implemented", "Version conflict check is not implemented yet"),
     ("check.versions.none_found", "No version conflicts found"),
@@ -73,10 +76,12 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("check.versions.details", "Version Conflicts Details"),
     ("check.versions.processing_package", "Processing package file: {}"),
     ("check.versions.conflicts_found", "Found {} version conflicts"),
-    ("check.versions.conflict_header", "Conflict {}: {}"),
+    ("check.versions.conflict_header", "Conflict {}: {} ({})"),
+    ("check.versions.kind_incompatible", "incompatible ranges"),
+    ("check.versions.kind_compatible", "compatible, written differently"),
     ("check.versions.usage_detail", "  {} uses {} (resolved to {}, {} type)"),
     ("check.versions.version_group", "  Version {}: {}"),
-    ("check.versions.recommended", "  Recommended version: {}"),
+    ("check.versions.recommended", "  Recommended version: {} (satisfies {}/{} usages)"),
     ("check.versions.suggestion", "Suggestion: Run monox fix to automatically resolve version conflicts, or manually unify dependency versions"),
     ("check.outdated.start", "Starting outdated dependency check..."),
     ("check.outdated.checking", "Checking for outdated dependencies..."),
@@ -111,8 +116,97 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
         ("check.outdated.dep_simple_multiple", "  {} {} → {}"),
         ("check.outdated.dep_detail_simple", "  {} {} → {} ({})"),
         ("check.outdated.found_prefix", "Found outdated:"),
+        ("check.outdated.lookup_failed", "Could not look up {} dependencies, their update status is unknown:"),
+    ("check.outdated.lookup_failed_item", "  {}: {}"),
+    ("check.outdated.lookup_timeout", "request timed out"),
+        ("check.outdated.below_level", "{} outdated references below level {} are hidden"),
+        ("check.outdated.col_dependency", "Dependency"),
+        ("check.outdated.col_range", "Range"),
+        ("check.outdated.col_current", "Current"),
+        ("check.outdated.col_wanted", "Wanted"),
+        ("check.outdated.col_latest", "Latest"),
+        ("check.outdated.col_level", "Level"),
+        ("check.outdated.col_used_by", "Used by"),
+        ("check.outdated.col_type", "Type"),
+        ("check.outdated.level.in_range", "in range"),
+        ("check.outdated.level.patch", "patch"),
+        ("check.outdated.level.minor", "minor"),
+        ("check.outdated.level.major", "major"),
+        ("check.outdated.level.prerelease", "prerelease"),
+    ("check.outdated.offline", "Offline mode: using cached registry metadata only, uncached dependencies are reported as lookup failures"),
+    ("check.duplicates.start", "Starting duplicate package check..."),
+    ("check.duplicates.no_lockfile", "No lockfile found in workspace root (pnpm-lock.yaml, package-lock.json or yarn.lock), skipping duplicate check"),
+    ("check.duplicates.none_found", "No duplicate packages found in {} ({} packages installed)"),
+    ("check.duplicates.found", "Found {} packages installed at multiple versions ({})"),
+    ("check.duplicates.details", "Duplicate Packages Details ({})"),
+    ("check.duplicates.package_header", "{}. {}: {} versions, extra {}"),
+    ("check.duplicates.version_line", "  {} ({}) ← {}"),
+    ("check.duplicates.more", "+{} more"),
+    ("check.duplicates.unknown_size", "size unknown"),
+    ("check.duplicates.total_extra", "Estimated extra install size: {} (upper bound of the bundle impact)"),
+    ("check.duplicates.suggestion_npm", "Suggestion: Run npm dedupe, or align the version ranges that pull in older copies"),
+    ("check.duplicates.suggestion_pnpm", "Suggestion: Run pnpm dedupe, or align the version ranges that pull in older copies"),
+    ("check.duplicates.suggestion_yarn", "Suggestion: Run yarn dedupe (yarn 2+), or align the version ranges that pull in older copies"),
+    ("check.protocol.start", "Starting workspace protocol check..."),
+    ("check.protocol.none_found", "All internal dependencies use the expected protocol and match local versions"),
+    ("check.protocol.found", "Found {} internal dependencies with protocol or version problems"),
+    ("check.protocol.details", "Internal Dependency Details"),
+    ("check.protocol.package_header", "Package {}:"),
+    ("check.protocol.issue_line", "  {} {} → {} ({})"),
+    ("check.protocol.dep_type", "    type: {}"),
+    ("check.protocol.kind.missing_protocol", "not using workspace:, local {}"),
+    ("check.protocol.kind.unsupported_protocol", "workspace: is not supported by the package manager, local {}"),
+    ("check.protocol.kind.version_mismatch", "range does not include local {}"),
+    ("check.protocol.suggestion", "Suggestion: Run monox fix --workspace-protocol to rewrite them, the style is set by [workspace] internal_protocol"),
+    ("check.boundaries.start", "Starting architecture boundary check..."),
+    ("check.boundaries.no_rules", "No boundary rules configured, add [[boundaries.rules]] to monox.toml"),
+    ("check.boundaries.unknown_tags", "Boundary rules use tags that no package has: {}"),
+    ("check.boundaries.none_found", "All {} internal dependencies follow the {} boundary rules"),
+    ("check.boundaries.found", "Found {} boundary violations"),
+    ("check.boundaries.details", "Boundary Violation Details"),
+    ("check.boundaries.package_header", "Package {} [{}]:"),
+    ("check.boundaries.violation_line", "  {} {} ({}) breaks rule {}: {}"),
+    ("check.boundaries.kind.denied", "depends on a package matching {}"),
+    ("check.boundaries.kind.not_allowed", "may only depend on packages matching {}"),
+    ("check.boundaries.dependency_tags", "    dependency tags: {}"),
+    ("check.boundaries.rule_number", "#{}"),
+    ("check.boundaries.empty_rule", "Boundary rule {} has neither allow nor deny"),
+    ("check.boundaries.invalid_selector", "Invalid selector \"{}\" in boundary rule {}: {}"),
+    ("check.boundaries.empty_pattern", "empty pattern"),
+    ("check.boundaries.suggestion", "Suggestion: Remove these dependencies, or adjust the package tags or rules under [boundaries] in monox.toml"),
+    ("check.deps_usage.start", "Scanning source imports for dependency usage..."),
+    ("check.deps_usage.none_found", "Scanned {} files in {} packages, every import is declared and every dependency is used"),
+    ("check.deps_usage.found", "Found {} undeclared and {} unused dependencies"),
+    ("check.deps_usage.details", "Dependency Usage Details"),
+    ("check.deps_usage.package_header", "Package {}:"),
+    ("check.deps_usage.undeclared_line", "  {} {} imported but not declared: {}"),
+    ("check.deps_usage.more_locations", " (+{} more)"),
+    ("check.deps_usage.unused_line", "  {} {} ({}) declared but never imported"),
+    ("check.deps_usage.suggestion", "Suggestion: Declare imported packages in package.json and remove unused ones. List tooling-only dependencies in [deps_usage] ignore_unused and import aliases in ignore_undeclared"),
+    ("check.manifest.start", "Checking package.json conventions..."),
+    ("check.manifest.none_found", "Checked {} packages, every package.json follows the manifest rules"),
+    ("check.manifest.found", "Found {} errors and {} warnings in package.json files"),
+    ("check.manifest.unknown_rules", "[manifest.rules] configures rules that do not exist: {}"),
+    ("check.manifest.invalid_script_pattern", "Invalid [manifest] script_pattern '{}': {}"),
+    ("check.manifest.details", "Manifest Issue Details"),
+    ("check.manifest.package_header", "Package {} ({}):"),
+    ("check.manifest.issue_line", "  {} [{}] {}{}"),
+    ("check.manifest.fixable_mark", " (fixable)"),
+    ("check.manifest.fix_line", "      {} {}"),
+    ("check.manifest.fixable", "{} issues can be fixed automatically with 'monox fix --manifest'"),
+    ("check.manifest.suggestion", "Suggestion: Fix the reported fields, or change rule severities in [manifest.rules] (off, warn, error)"),
+    ("check.manifest.rule.missing_field", "missing required field '{}'"),
+    ("check.manifest.rule.duplicate_name", "name is also used by {}"),
+    ("check.manifest.rule.folder_name", "name does not match folder '{}'"),
+    ("check.manifest.rule.engines_mismatch", "engines.node is '{}', workspace uses '{}'"),
+    ("check.manifest.rule.engines_missing", "engines.node is not declared, workspace uses '{}'"),
+    ("check.manifest.rule.private_dependency", "depends on private package {} in {}"),
+    ("check.manifest.rule.missing_files", "publishable package has no 'files' field"),
+    ("check.manifest.rule.missing_exports", "publishable package has no 'exports' field"),
+    ("check.manifest.rule.script_name", "script '{}' does not match '{}'"),
     // Update related
     ("update.missing_package_or_all", "Must specify dependency name or use --all flag"),
+    ("update.interactive_json", "--interactive cannot be used with --format json"),
     ("update.no_packages_found", "No package.json files found"),
     ("update.dependency_not_found", "Specified dependency not found"),
     ("update.checking_outdated", "Checking outdated dependencies..."),
@@ -178,6 +272,10 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("fix.completed", "Fix completed, executed {} fix operations"),
     ("fix.results_details", "Fix Results Details"),
     ("fix.result_detail", "  {} {} → {}"),
+    ("fix.manifest.none_fixable", "None of the {} manifest issues can be fixed automatically"),
+    ("fix.manifest.fix_line", "  [{}] {}"),
+    ("fix.manifest.applied", "Fixed {}: {}"),
+    ("fix.manifest.manual_remaining", "{} manifest issues need manual changes, run 'monox check --manifest' to list them"),
     // Config related
     ("analyze.config_loaded", "Loaded config file: {}"),
     ("analyze.config_error", "Failed to load config file: {}"),
@@ -195,6 +293,9 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ),
     ("output.analysis_duration", "Analysis duration: {}ms"),
     ("output.circular_dependencies", "Circular Dependencies"),
+    ("output.break_edges", "Suggested dependencies to remove: {}"),
+    ("output.not_build_order", "not ordering builds"),
+    ("output.ignored_circular_dependencies", "Cycles Not Affecting Build Order"),
     (
         "output.no_circular_dependencies",
         "No circular dependencies found",
@@ -257,7 +358,7 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("executor.summary_title", "Execution Summary"),
     ("executor.summary_stages", "Stages completed: {}/{}"),
     ("executor.summary_tasks", "Tasks successful: {}/{}"),
-    ("executor.summary_duration", "Total duration: {:.2}s"),
+    ("executor.summary_duration", "Total duration: {}"),
     ("executor.summary_success", "All tasks executed successfully!"),
     ("executor.summary_failed", "Execution failed, {} tasks failed"),
     // Run command related
@@ -267,6 +368,7 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("run.package_not_found", "Package not found: {}"),
     ("run.script_not_found", "Script '{}' not found in package {}"),
     ("run.no_executable_packages", "No packages contain script '{}'"),
+    ("run.circular_blocked", "Cannot schedule the build because of circular dependencies: {}. Break them with [workspace.ignore_edges] or pass --allow-cycles"),
     ("run.found_executable_packages", "Found {} packages containing script '{}'"),
     ("run.empty_script", "Script '{}' in package {} is empty"),
     ("run.no_valid_tasks", "No valid tasks to execute"),
@@ -327,4 +429,201 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("runner.processing_packages", "Processing packages:"),
     ("runner.more_packages", "  ... {} more packages"),
     ("runner.stage_complete", "All stages completed {}/{}"),
+    // History and stats related
+    ("history.save_failed", "Failed to save execution history: {}"),
+    ("history.read_failed", "Failed to read execution history: {}"),
+    ("history.write_failed", "Failed to write execution history: {}"),
+    ("stats.no_history", "No execution history found ({}), run some tasks first"),
+    ("stats.start", "Execution statistics for the last {} runs"),
+    ("stats.slowest_packages", "Slowest Packages"),
+    ("stats.slowest_detail", "{}. {} avg {}, max {} ({} samples)"),
+    ("stats.flaky_tasks", "Flaky Tasks"),
+    ("stats.no_flaky_tasks", "No flaky tasks found"),
+    ("stats.flaky_detail", "{} failed {}/{} runs, flipped {} times"),
+    ("stats.trend", "Recent Runs"),
+    ("stats.trend_detail", "{} {} {} took {}, {} tasks, {} failed"),
+    ("stats.percentiles", "Task Duration Percentiles"),
+    ("stats.percentile_detail", "{} p50 {}, p95 {} ({} samples)"),
+    ("stats.usage_tip", "Tip: Use --last to change the window, --command to filter by script, --format json for JSON output"),
+
+    // Profile related
+    ("profile.saved", "Profile written to {} (open with chrome://tracing or ui.perfetto.dev)"),
+    ("profile.save_failed", "Failed to write profile: {}"),
+    ("profile.write_failed", "Failed to write profile file: {}"),
+
+    // Run report related
+    ("report.saved", "Report written to {}"),
+    ("report.save_failed", "Failed to write report: {}"),
+    ("report.write_failed", "Failed to write report file: {}"),
+    ("report.invalid_target", "Invalid report target '{}', expected FORMAT=PATH (e.g. json=report.json)"),
+    ("report.unsupported_format", "Unsupported report format '{}', supported: json, junit"),
+
+    // Dry-run plan related
+    ("plan.title", "Execution plan for '{}': {} task(s) in {} stage(s)"),
+    ("plan.stage", "Stage {}"),
+    ("plan.selected_all", "selected by --all"),
+    ("plan.selected_requested", "requested"),
+    ("plan.selected_dependency", "dependency of {}"),
+    ("plan.skipped", "skipped: {}"),
+    ("plan.skip_script_not_found", "script not found"),
+    ("plan.skip_upstream_failed", "upstream stage failed"),
+    ("plan.skip_cancelled", "cancelled after another task failed"),
+    ("plan.cwd", "cwd: {}"),
+    ("plan.env", "env: {}"),
+    ("plan.depends_on", "depends on: {}"),
+    ("plan.post_command", "After each concurrent stage: {} {}"),
+    ("plan.dry_run_tip", "Dry run: nothing was executed"),
+
+    // Environment variable related
+    ("env.file_read_failed", "Failed to read env file {}: {}"),
+
+    // Lockfile related
+    ("lockfile.read_failed", "Failed to read lockfile {}"),
+    ("lockfile.parse_failed", "Failed to parse lockfile {}"),
+    ("lockfile.npm_unsupported_version", "Unsupported package-lock.json lockfileVersion {}, only v2 and v3 are supported (run npm install with npm 7+ to upgrade)"),
+
+    // Registry client related
+    ("registry.offline_miss", "{} is not in the registry cache, run once without --offline to cache it"),
+    ("registry.request_failed", "Registry request failed: {}"),
+    ("registry.auth_failed", "Registry rejected the credentials for {} (HTTP {}), check the auth token in .npmrc"),
+    ("registry.unexpected_status", "Registry returned HTTP {} for {}"),
+    ("registry.invalid_response", "Invalid registry response from {}"),
+
+    // 更新策略与交互选择
+    ("update.invalid_version", "Invalid version: {}"),
+    ("update.nothing_selected", "No updates selected, nothing to do"),
+    ("update.held_pinned", "{} in {} is pinned at {}, {} is available"),
+    ("update.held_not_allowed", "{} in {}: {} is outside the allowed range {}"),
+    ("update.select_title", "Select updates to apply:"),
+    ("update.select_item", "{}  {} {} → {} ({})"),
+    ("update.update_with_level", "  {} {} → {} ({})"),
+    ("picker.requires_tty", "Interactive selection requires a terminal"),
+    ("picker.prompt", "Enter numbers or ranges (e.g. 1,3-5), a for all, empty to cancel:"),
+    ("picker.invalid_selection", "Invalid selection: {}"),
+
+    // 更新方案发布信息
+    ("update.release.breaking", "Possible breaking changes"),
+    ("update.release.deprecated", "Target version is deprecated: {}"),
+    ("update.release.deprecated_version", "{} (deprecated)"),
+    ("update.release.published", "      Published {}"),
+    ("update.release.skipped", "      Skipped versions ({}): {}"),
+    ("update.release.more", "+{} more"),
+    ("update.release.changelog_version", "        CHANGELOG {}:"),
+    ("update.release.repository", "      Release notes: {}"),
+
+    // 安装与验证
+    ("install.backup_failed", "Failed to back up {}"),
+    ("install.restore_failed", "Failed to restore {}"),
+    ("install.running", "Running {} install..."),
+    ("install.spawn_failed", "Failed to start {}"),
+    ("install.failed", "{} install failed with exit code {}"),
+    ("install.completed", "Install completed"),
+    ("install.verifying", "Verifying with {} in: {}"),
+    ("install.verify_no_targets", "No affected package defines a {} script, skipping verification"),
+    ("install.verify_failed", "Verification task {} failed"),
+    ("install.verified", "Verification task {} passed"),
+    ("install.restoring", "Restoring package.json and lockfile from backup..."),
+    ("install.restored", "Original files restored, run {} install to resync node_modules"),
+
+    // JSON 文件编辑
+    ("json_edit.read_failed", "Failed to read {}"),
+    ("json_edit.parse_failed", "Failed to parse {}"),
+    ("json_edit.write_failed", "Failed to write {}, no files were changed"),
+    ("json_edit.unexpected_token", "Unexpected token at byte {}"),
+
+    // 版本发布
+    ("version.json_requires_yes", "JSON output cannot ask for confirmation, pass --yes to apply the plan or --dry-run to only print it"),
+    ("version.invalid_bump", "Invalid version bump: {} (use patch, minor, major, prerelease or a version number)"),
+    ("version.no_packages_selected", "Select packages with --package or use --all"),
+    ("version.package_not_found", "No workspace package matches {}"),
+    ("version.invalid_version", "Package {} has an invalid version: {}"),
+    ("version.not_greater", "New version of {} ({}) must be greater than the current version {}"),
+    ("version.tag_exists", "Git tag {} already exists"),
+    ("version.plan_details", "Version Plan"),
+    ("version.reason.selected", "selected"),
+    ("version.reason.group", "fixed with {}"),
+    ("version.reason.dependency", "depends on {}"),
+    ("version.ranges_header", "Dependent range updates:"),
+    ("version.range_line", "  {} → {} ({}): {} → {}"),
+    ("version.will_commit", "A release commit will be created"),
+    ("version.will_tag", "Tags: {}"),
+    ("version.total", "Total {} version bumps, {} range updates"),
+    ("version.dry_run_complete", "Dry run complete, no files were changed"),
+    ("version.confirm_prompt", "Apply these version changes? (y/N)"),
+    ("version.cancelled", "Version bump cancelled"),
+    ("version.completed", "Bumped {} packages, updated {} package.json files"),
+    ("version.committed", "Created release commit"),
+    ("version.tagged", "Created tags: {}"),
+    ("version.no_changes", "No change files found in .monox/changes (run monox change add or pass a bump)"),
+    ("version.changes_header", "Change files:"),
+    ("version.changes_consumed", "Consumed {} change files, updated {} CHANGELOG.md files"),
+
+    // 变更文件
+    ("change.select_packages", "Select the changed packages:"),
+    ("change.bump_prompt", "Bump type (patch, minor, major) [patch]:"),
+    ("change.summary_prompt", "Summary:"),
+    ("change.invalid_bump", "Invalid bump type: {} (use patch, minor or major)"),
+    ("change.summary_required", "A change summary is required"),
+    ("change.cancelled", "No packages selected, nothing recorded"),
+    ("change.added", "Created {} ({}: {})"),
+    ("change.none", "No pending changes"),
+    ("change.total", "Total {} pending changes"),
+    ("change.read_failed", "Failed to read change file: {}"),
+    ("change.parse_failed", "Invalid change file: {}"),
+    ("change.write_failed", "Failed to write change file: {}"),
+    ("change.remove_failed", "Failed to remove change file: {}"),
+    ("change.unknown_package", "Unknown package {} in change file {}"),
+    ("changelog.read_failed", "Failed to read changelog: {}"),
+
+    // Git
+    ("git.not_repository", "Workspace is not inside a git repository"),
+    ("git.spawn_failed", "Failed to start git"),
+    ("git.command_failed", "git {} failed: {}"),
+
+    // 发布
+    ("publish.json_requires_yes", "JSON output cannot ask for confirmation, pass --yes to publish or --dry-run to only print the plan"),
+    ("publish.circular", "Cannot determine publish order: {} circular dependencies found"),
+    ("publish.unknown_workspace_dependency", "{} depends on {} with the workspace: protocol, but it is not a workspace package"),
+    ("publish.plan_details", "Publish Plan"),
+    ("publish.stage", "[stage {}]"),
+    ("publish.status.pending", "to publish"),
+    ("publish.status.published", "already published"),
+    ("publish.status.private", "private"),
+    ("publish.rewrite_line", "      {} ({}): {} → {}"),
+    ("publish.registry", "Registry: {}"),
+    ("publish.total", "{} of {} packages to publish"),
+    ("publish.nothing_to_publish", "All public package versions are already published"),
+    ("publish.dry_run_complete", "Dry run complete, nothing was published"),
+    ("publish.confirm_prompt", "Publish these packages? (y/N)"),
+    ("publish.cancelled", "Publish cancelled"),
+    ("publish.publishing", "Publishing {}@{}..."),
+    ("publish.published", "Published: {}"),
+    ("publish.failed", "Failed to publish {}"),
+    ("publish.skipped", "Not published because of the failure: {}"),
+    ("publish.spawn_failed", "Failed to start {}"),
+    ("publish.pack_failed", "Failed to pack {}: {}"),
+    ("publish.publish_failed", "Failed to publish {}: {}"),
+
+    // 依赖图
+    ("graph.invalid_format", "Unsupported graph format: {} (expected dot, mermaid, graphml or cytoscape-json)"),
+    ("graph.no_packages", "No packages found in the workspace"),
+    ("graph.requires_tty", "The interactive graph explorer requires a terminal"),
+    ("graph.too_small", "Terminal is too small, please enlarge the window (q to quit)"),
+    ("graph.title", "MonoX Dependency Graph"),
+    ("graph.on", "on"),
+    ("graph.off", "off"),
+    ("graph.toggle_dev", "[d] dev: {}"),
+    ("graph.toggle_peer", "[p] peer: {}"),
+    ("graph.search", "Search: {}"),
+    ("graph.packages", "Packages ({})"),
+    ("graph.dependencies", "Dependencies ({})"),
+    ("graph.dependents", "Dependents ({})"),
+    ("graph.none", "(none)"),
+    ("graph.stage", "Build stage {} of {}"),
+    ("graph.no_stage", "No build stage (in a cycle)"),
+    ("graph.cycle", "↻ Cycle: {}"),
+    ("graph.no_cycle", "Not part of any cycle"),
+    ("graph.no_match", "No package matches the search"),
+    ("graph.help", "↑↓ move  ←→/Tab switch column  Enter open  / search  d dev  p peer  q quit"),
+    ("graph.search_help", "Type to filter  ↑↓ move  Enter done  Esc clear"),
 ];
@@ -33,10 +33,9 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("analyze.completed", "分析完成，耗时 {}ms，共 {} 个阶段"),
     ("analyze.circular_found", "检测到 {} 个循环依赖"),
     ("analyze.circular_detail", "循环依赖 {}: {}"),
-    (
-        "analyze.circular_warning",
-        "警告: 检测到循环依赖，剩余包: {}",
-    ),
+    ("analyze.unschedulable", "无法安排构建顺序，以下包无法确定构建顺序: {}。请检查它们是否依赖自身或存在循环依赖"),
+    ("analyze.invalid_monox_tags", "忽略 {} 中的 monox 字段: 应为对象，且 tags 为字符串数组"),
+    ("analyze.unknown_ignore_edge", "[workspace.ignore_edges] 中的 {} -> {} 不是已有的工作区依赖"),
     // 单包分析相关
     ("analyze.single_package_start", "开始分析单个包: {}"),
     ("analyze.single_package_found", "找到目标包 '{}': {}"),
@@ -52,11 +51,15 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("check.circular.start", "开始检查循环依赖..."),
     ("check.circular.none_found", "未发现循环依赖"),
     ("check.circular.found", "发现 {} 个循环依赖"),
+    ("check.circular.ignored", "{} 个循环依赖只经过不决定构建顺序的依赖类型: {}"),
     ("check.circular.details", "循环依赖详情"),
-    ("check.circular.cycle_header", "循环 {}:"),
-    ("check.circular.cycle_detail", "  {} {} 依赖 {}"),
+    ("check.circular.cycle_header", "循环 {}（{}）:"),
+    ("check.circular.cycle_detail", "      {} {} 依赖 {}（{}）"),
     ("check.circular.cycle_simple", "  {}"),
-    ("check.circular.suggestion", "建议: 重构代码以消除循环依赖，这可能导致构建问题"),
+    ("check.circular.truncated", "  ……只列出前 {} 个环"),
+    ("check.circular.break_header", "  断开以下 {} 个依赖即可消除全部循环:"),
+    ("check.circular.break_edge", "    - {} → {}（{}）"),
+    ("check.circular.suggestion", "建议: 移除或调整上面列出的依赖（优先选择开发依赖和对等依赖），或在 [workspace] build_dependency_types 中排除它们的类型"),
     ("check.versions.start", "开始检查版本冲突..."),
     ("check.versions.not_implemented", "版本冲突检查功能尚未实现"),
     ("check.versions.none_found", "未发现版本冲突"),
@@ -64,10 +67,12 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("check.versions.details", "版本冲突详情"),
     ("check.versions.processing_package", "处理包文件: {}"),
     ("check.versions.conflicts_found", "发现 {} 个版本冲突"),
-    ("check.versions.conflict_header", "冲突 {}: {}"),
+    ("check.versions.conflict_header", "冲突 {}: {} ({})"),
+    ("check.versions.kind_incompatible", "范围不兼容"),
+    ("check.versions.kind_compatible", "范围兼容，写法不同"),
     ("check.versions.usage_detail", "  {} 使用 {} (解析为 {}, {} 类型)"),
     ("check.versions.version_group", "  版本 {}: {}"),
-    ("check.versions.recommended", "  推荐版本: {}"),
+    ("check.versions.recommended", "  推荐版本: {} (满足 {}/{} 处使用)"),
     ("check.versions.suggestion", "建议: 运行 monox fix 自动修复版本冲突，或手动统一依赖版本"),
     ("check.outdated.start", "开始检查过期依赖..."),
     ("check.outdated.checking", "正在检查过期依赖..."),
@@ -102,8 +107,97 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
         ("check.outdated.dep_simple_multiple", "  {} {} → {}"),
         ("check.outdated.dep_detail_simple", "  {} {} → {} ({})"),
         ("check.outdated.found_prefix", "发现过期:"),
+        ("check.outdated.lookup_failed", "{} 个依赖查询失败，无法判断是否过期:"),
+    ("check.outdated.lookup_failed_item", "  {}: {}"),
+    ("check.outdated.lookup_timeout", "请求超时"),
+        ("check.outdated.below_level", "已隐藏 {} 处低于 {} 级别的过期引用"),
+        ("check.outdated.col_dependency", "依赖"),
+        ("check.outdated.col_range", "范围"),
+        ("check.outdated.col_current", "当前"),
+        ("check.outdated.col_wanted", "范围内最新"),
+        ("check.outdated.col_latest", "最新"),
+        ("check.outdated.col_level", "级别"),
+        ("check.outdated.col_used_by", "使用方"),
+        ("check.outdated.col_type", "类型"),
+        ("check.outdated.level.in_range", "范围内"),
+        ("check.outdated.level.patch", "补丁"),
+        ("check.outdated.level.minor", "次版本"),
+        ("check.outdated.level.major", "主版本"),
+        ("check.outdated.level.prerelease", "预发布"),
+    ("check.outdated.offline", "离线模式: 仅使用本地缓存的 registry 元数据，未缓存的依赖会报告为查询失败"),
+    ("check.duplicates.start", "开始检查重复安装的依赖..."),
+    ("check.duplicates.no_lockfile", "工作区根目录未找到锁文件（pnpm-lock.yaml、package-lock.json 或 yarn.lock），跳过重复依赖检查"),
+    ("check.duplicates.none_found", "{} 中未发现重复安装的依赖（共安装 {} 个包）"),
+    ("check.duplicates.found", "发现 {} 个包安装了多个版本（{}）"),
+    ("check.duplicates.details", "重复依赖详情（{}）"),
+    ("check.duplicates.package_header", "{}. {}: {} 个版本，额外占用 {}"),
+    ("check.duplicates.version_line", "  {} ({}) ← {}"),
+    ("check.duplicates.more", "等另外 {} 个"),
+    ("check.duplicates.unknown_size", "体积未知"),
+    ("check.duplicates.total_extra", "预估额外安装体积: {}（打包体积影响的上限）"),
+    ("check.duplicates.suggestion_npm", "建议: 运行 npm dedupe，或统一引入旧版本的依赖范围"),
+    ("check.duplicates.suggestion_pnpm", "建议: 运行 pnpm dedupe，或统一引入旧版本的依赖范围"),
+    ("check.duplicates.suggestion_yarn", "建议: 运行 yarn dedupe（yarn 2+），或统一引入旧版本的依赖范围"),
+    ("check.protocol.start", "开始检查工作区内部依赖协议..."),
+    ("check.protocol.none_found", "所有内部依赖的写法均符合要求，且匹配本地版本"),
+    ("check.protocol.found", "发现 {} 个写法或版本有问题的内部依赖"),
+    ("check.protocol.details", "内部依赖详情"),
+    ("check.protocol.package_header", "包 {}:"),
+    ("check.protocol.issue_line", "  {} {} → {} ({})"),
+    ("check.protocol.dep_type", "    类型: {}"),
+    ("check.protocol.kind.missing_protocol", "未使用 workspace: 协议，本地版本 {}"),
+    ("check.protocol.kind.unsupported_protocol", "当前包管理器不支持 workspace: 协议，本地版本 {}"),
+    ("check.protocol.kind.version_mismatch", "版本范围不包含本地版本 {}"),
+    ("check.protocol.suggestion", "建议: 运行 monox fix --workspace-protocol 自动改写，写法由 [workspace] internal_protocol 配置"),
+    ("check.boundaries.start", "开始检查架构边界..."),
+    ("check.boundaries.no_rules", "未配置边界规则，请在 monox.toml 中添加 [[boundaries.rules]]"),
+    ("check.boundaries.unknown_tags", "边界规则中使用了没有任何包拥有的标签: {}"),
+    ("check.boundaries.none_found", "{} 个内部依赖均符合 {} 条边界规则"),
+    ("check.boundaries.found", "发现 {} 处违反边界规则的依赖"),
+    ("check.boundaries.details", "边界违规详情"),
+    ("check.boundaries.package_header", "包 {} [{}]:"),
+    ("check.boundaries.violation_line", "  {} {} ({}) 违反规则 {}: {}"),
+    ("check.boundaries.kind.denied", "依赖了匹配 {} 的包"),
+    ("check.boundaries.kind.not_allowed", "只能依赖匹配 {} 的包"),
+    ("check.boundaries.dependency_tags", "    被依赖包的标签: {}"),
+    ("check.boundaries.rule_number", "#{}"),
+    ("check.boundaries.empty_rule", "边界规则 {} 既没有 allow 也没有 deny"),
+    ("check.boundaries.invalid_selector", "选择器 \"{}\" 无效（边界规则 {}）: {}"),
+    ("check.boundaries.empty_pattern", "模式为空"),
+    ("check.boundaries.suggestion", "建议: 移除这些依赖，或调整 monox.toml 中 [boundaries] 下的包标签和规则"),
+    ("check.deps_usage.start", "开始扫描源码导入，检查依赖使用情况..."),
+    ("check.deps_usage.none_found", "已扫描 {} 个文件（{} 个包），所有导入均已声明，所有依赖均被使用"),
+    ("check.deps_usage.found", "发现 {} 个未声明的依赖和 {} 个未使用的依赖"),
+    ("check.deps_usage.details", "依赖使用详情"),
+    ("check.deps_usage.package_header", "包 {}:"),
+    ("check.deps_usage.undeclared_line", "  {} {} 已导入但未声明: {}"),
+    ("check.deps_usage.more_locations", "（另有 {} 处）"),
+    ("check.deps_usage.unused_line", "  {} {} ({}) 已声明但从未导入"),
+    ("check.deps_usage.suggestion", "建议: 在 package.json 中声明导入的包并移除未使用的依赖。只在工具中使用的依赖可加入 [deps_usage] ignore_unused，导入别名可加入 ignore_undeclared"),
+    ("check.manifest.start", "正在检查 package.json 规范..."),
+    ("check.manifest.none_found", "已检查 {} 个包，所有 package.json 都符合规范"),
+    ("check.manifest.found", "package.json 中发现 {} 个错误和 {} 个警告"),
+    ("check.manifest.unknown_rules", "[manifest.rules] 中配置了不存在的规则: {}"),
+    ("check.manifest.invalid_script_pattern", "[manifest] script_pattern '{}' 无效: {}"),
+    ("check.manifest.details", "package.json 规范问题详情"),
+    ("check.manifest.package_header", "包 {} ({}):"),
+    ("check.manifest.issue_line", "  {} [{}] {}{}"),
+    ("check.manifest.fixable_mark", "（可自动修复）"),
+    ("check.manifest.fix_line", "      {} {}"),
+    ("check.manifest.fixable", "{} 个问题可以通过 'monox fix --manifest' 自动修复"),
+    ("check.manifest.suggestion", "建议: 修改提示的字段，或在 [manifest.rules] 中调整规则的严重级别 (off, warn, error)"),
+    ("check.manifest.rule.missing_field", "缺少必需字段 '{}'"),
+    ("check.manifest.rule.duplicate_name", "包名与 {} 重复"),
+    ("check.manifest.rule.folder_name", "包名与目录 '{}' 不一致"),
+    ("check.manifest.rule.engines_mismatch", "engines.node 为 '{}'，工作区使用 '{}'"),
+    ("check.manifest.rule.engines_missing", "未声明 engines.node，工作区使用 '{}'"),
+    ("check.manifest.rule.private_dependency", "依赖了私有包 {}（{}）"),
+    ("check.manifest.rule.missing_files", "可发布的包缺少 'files' 字段"),
+    ("check.manifest.rule.missing_exports", "可发布的包缺少 'exports' 字段"),
+    ("check.manifest.rule.script_name", "脚本 '{}' 不符合 '{}'"),
     // Update 相关
     ("update.missing_package_or_all", "必须指定依赖名或使用 --all 参数"),
+    ("update.interactive_json", "--interactive 不能与 --format json 同时使用"),
     ("update.no_packages_found", "未找到任何 package.json 文件"),
     ("update.dependency_not_found", "未找到指定的依赖"),
     ("update.checking_outdated", "正在检查过期依赖..."),
@@ -160,6 +254,10 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("fix.completed", "修复完成，共执行 {} 个修复操作"),
     ("fix.results_details", "修复结果详情"),
     ("fix.result_detail", "  {} {} → {}"),
+    ("fix.manifest.none_fixable", "{} 个 package.json 规范问题都无法自动修复"),
+    ("fix.manifest.fix_line", "  [{}] {}"),
+    ("fix.manifest.applied", "已修复 {}: {}"),
+    ("fix.manifest.manual_remaining", "{} 个 package.json 规范问题需要手动修改，运行 'monox check --manifest' 查看"),
     // 配置相关
     ("analyze.config_loaded", "已加载配置文件: {}"),
     ("analyze.config_error", "配置文件加载失败: {}"),
@@ -171,6 +269,9 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("output.packages_with_deps", "有工作区依赖的包: {}"),
     ("output.analysis_duration", "分析耗时: {}ms"),
     ("output.circular_dependencies", "循环依赖"),
+    ("output.break_edges", "建议断开的依赖: {}"),
+    ("output.not_build_order", "不决定构建顺序"),
+    ("output.ignored_circular_dependencies", "不影响构建顺序的循环依赖"),
     ("output.no_circular_dependencies", "未发现循环依赖"),
     ("output.build_stages", "构建阶段"),
     ("output.stage_info", "阶段 {} ({} 个包):"),
@@ -227,7 +328,7 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("executor.summary_title", "执行汇总"),
     ("executor.summary_stages", "阶段完成: {}/{}"),
     ("executor.summary_tasks", "任务成功: {}/{}"),
-    ("executor.summary_duration", "总耗时: {:.2}s"),
+    ("executor.summary_duration", "总耗时: {}"),
     ("executor.summary_success", "所有任务执行成功！"),
     ("executor.summary_failed", "执行失败，失败任务数: {}"),
     // Run 命令相关
@@ -237,6 +338,7 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("run.package_not_found", "未找到包: {}"),
     ("run.script_not_found", "包 {} 中未找到脚本: {}"),
     ("run.no_executable_packages", "没有包含脚本 '{}' 的包"),
+    ("run.circular_blocked", "存在循环依赖，无法安排构建顺序: {}。可在 [workspace.ignore_edges] 中断开依赖，或使用 --allow-cycles"),
     ("run.found_executable_packages", "找到 {} 个包含脚本 '{}' 的包"),
     ("run.empty_script", "包 {} 中的脚本 '{}' 为空"),
     ("run.no_valid_tasks", "没有有效的任务可执行"),
@@ -297,4 +399,201 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("runner.processing_packages", "正在处理包:"),
     ("runner.more_packages", "  ... 还有 {} 个包"),
     ("runner.stage_complete", "所有阶段完成 {}/{}"),
+    // 执行历史和统计相关
+    ("history.save_failed", "保存执行历史失败: {}"),
+    ("history.read_failed", "读取执行历史失败: {}"),
+    ("history.write_failed", "写入执行历史失败: {}"),
+    ("stats.no_history", "未找到执行历史 ({})，请先执行任务"),
+    ("stats.start", "最近 {} 次运行的执行统计"),
+    ("stats.slowest_packages", "最慢的包"),
+    ("stats.slowest_detail", "{}. {} 平均 {}，最长 {}（{} 个样本）"),
+    ("stats.flaky_tasks", "不稳定任务"),
+    ("stats.no_flaky_tasks", "未发现不稳定任务"),
+    ("stats.flaky_detail", "{} 在 {}/{} 次运行中失败，切换 {} 次"),
+    ("stats.trend", "最近运行"),
+    ("stats.trend_detail", "{} {} {} 耗时 {}，{} 个任务，{} 个失败"),
+    ("stats.percentiles", "任务耗时分位数"),
+    ("stats.percentile_detail", "{} p50 {}，p95 {}（{} 个样本）"),
+    ("stats.usage_tip", "提示: 使用 --last 调整统计窗口，--command 按脚本过滤，--format json 输出 JSON 格式"),
+
+    // 性能剖析相关
+    ("profile.saved", "性能剖析已写入 {}（可用 chrome://tracing 或 ui.perfetto.dev 打开）"),
+    ("profile.save_failed", "写入性能剖析失败: {}"),
+    ("profile.write_failed", "无法写入性能剖析文件: {}"),
+
+    // 运行报告相关
+    ("report.saved", "运行报告已写入 {}"),
+    ("report.save_failed", "写入运行报告失败: {}"),
+    ("report.write_failed", "无法写入运行报告文件: {}"),
+    ("report.invalid_target", "无效的报告输出目标 '{}'，格式应为 FORMAT=PATH（如: json=report.json）"),
+    ("report.unsupported_format", "不支持的报告格式 '{}'，支持: json, junit"),
+
+    // 试运行执行计划相关
+    ("plan.title", "'{}' 的执行计划: {} 个任务，{} 个阶段"),
+    ("plan.stage", "阶段 {}"),
+    ("plan.selected_all", "通过 --all 选中"),
+    ("plan.selected_requested", "直接指定"),
+    ("plan.selected_dependency", "{} 的依赖"),
+    ("plan.skipped", "跳过: {}"),
+    ("plan.skip_script_not_found", "脚本不存在"),
+    ("plan.skip_upstream_failed", "前序阶段执行失败"),
+    ("plan.skip_cancelled", "其他任务失败后被取消"),
+    ("plan.cwd", "工作目录: {}"),
+    ("plan.env", "环境变量: {}"),
+    ("plan.depends_on", "依赖: {}"),
+    ("plan.post_command", "每个并发阶段结束后执行: {} {}"),
+    ("plan.dry_run_tip", "试运行: 未执行任何命令"),
+
+    // 环境变量相关
+    ("env.file_read_failed", "无法读取 env 文件 {}: {}"),
+
+    // 锁文件相关
+    ("lockfile.read_failed", "读取锁文件失败: {}"),
+    ("lockfile.parse_failed", "解析锁文件失败: {}"),
+    ("lockfile.npm_unsupported_version", "不支持的 package-lock.json lockfileVersion {}，仅支持 v2 和 v3（可使用 npm 7+ 重新执行 npm install 升级）"),
+
+    // Registry 客户端相关
+    ("registry.offline_miss", "registry 缓存中没有 {}，请先在非离线模式下运行一次以缓存"),
+    ("registry.request_failed", "registry 请求失败: {}"),
+    ("registry.auth_failed", "registry 拒绝了 {} 的认证信息（HTTP {}），请检查 .npmrc 中的 auth token"),
+    ("registry.unexpected_status", "registry 返回 HTTP {}: {}"),
+    ("registry.invalid_response", "registry 响应无效: {}"),
+
+    // 更新策略与交互选择
+    ("update.invalid_version", "无效的版本: {}"),
+    ("update.nothing_selected", "未选择任何更新"),
+    ("update.held_pinned", "{}（{}）已固定为 {}，可用版本 {}"),
+    ("update.held_not_allowed", "{}（{}）: {} 不在允许的范围 {} 内"),
+    ("update.select_title", "选择要执行的更新:"),
+    ("update.select_item", "{}  {} {} → {} ({})"),
+    ("update.update_with_level", "  {} {} → {} ({})"),
+    ("picker.requires_tty", "交互式选择需要在终端中运行"),
+    ("picker.prompt", "输入编号或范围（如 1,3-5），a 表示全选，直接回车取消:"),
+    ("picker.invalid_selection", "无效的选择: {}"),
+
+    // 更新方案发布信息
+    ("update.release.breaking", "可能包含破坏性变更"),
+    ("update.release.deprecated", "目标版本已弃用: {}"),
+    ("update.release.deprecated_version", "{}（已弃用）"),
+    ("update.release.published", "      发布于 {}"),
+    ("update.release.skipped", "      跳过的版本（{} 个）: {}"),
+    ("update.release.more", "另有 {} 个"),
+    ("update.release.changelog_version", "        CHANGELOG {}:"),
+    ("update.release.repository", "      发布说明: {}"),
+
+    // 安装与验证
+    ("install.backup_failed", "备份 {} 失败"),
+    ("install.restore_failed", "恢复 {} 失败"),
+    ("install.running", "正在运行 {} install..."),
+    ("install.spawn_failed", "无法启动 {}"),
+    ("install.failed", "{} install 失败，退出码 {}"),
+    ("install.completed", "依赖安装完成"),
+    ("install.verifying", "正在运行验证任务 {}: {}"),
+    ("install.verify_no_targets", "受影响的包中没有 {} 脚本，跳过验证"),
+    ("install.verify_failed", "验证任务 {} 失败"),
+    ("install.verified", "验证任务 {} 通过"),
+    ("install.restoring", "正在从备份恢复 package.json 和锁文件..."),
+    ("install.restored", "已恢复原文件，请运行 {} install 重新同步 node_modules"),
+
+    // JSON 文件编辑
+    ("json_edit.read_failed", "读取 {} 失败"),
+    ("json_edit.parse_failed", "解析 {} 失败"),
+    ("json_edit.write_failed", "写入 {} 失败，所有文件均未修改"),
+    ("json_edit.unexpected_token", "第 {} 字节处存在无法识别的内容"),
+
+    // 版本发布
+    ("version.json_requires_yes", "JSON 输出无法确认操作，请使用 --yes 应用发布方案，或使用 --dry-run 只输出方案"),
+    ("version.invalid_bump", "无效的版本升级方式: {}（可用 patch、minor、major、prerelease 或版本号）"),
+    ("version.no_packages_selected", "请使用 --package 选择要升级的包，或使用 --all"),
+    ("version.package_not_found", "没有匹配 {} 的工作区包"),
+    ("version.invalid_version", "包 {} 的版本号无效: {}"),
+    ("version.not_greater", "{} 的新版本 ({}) 必须大于当前版本 {}"),
+    ("version.tag_exists", "git 标签 {} 已存在"),
+    ("version.plan_details", "版本发布方案"),
+    ("version.reason.selected", "直接选择"),
+    ("version.reason.group", "与 {} 同属固定组"),
+    ("version.reason.dependency", "依赖 {}"),
+    ("version.ranges_header", "依赖方版本范围更新:"),
+    ("version.range_line", "  {} → {} ({}): {} → {}"),
+    ("version.will_commit", "将创建发布提交"),
+    ("version.will_tag", "标签: {}"),
+    ("version.total", "共 {} 个版本升级，{} 个版本范围更新"),
+    ("version.dry_run_complete", "预演完成，未修改任何文件"),
+    ("version.confirm_prompt", "确认执行版本变更吗？(y/N)"),
+    ("version.cancelled", "版本升级已取消"),
+    ("version.completed", "已升级 {} 个包，更新了 {} 个 package.json"),
+    ("version.committed", "已创建发布提交"),
+    ("version.tagged", "已创建标签: {}"),
+    ("version.no_changes", ".monox/changes 中没有变更文件（请运行 monox change add 或指定升级方式）"),
+    ("version.changes_header", "变更文件:"),
+    ("version.changes_consumed", "已消费 {} 个变更文件，更新了 {} 个 CHANGELOG.md"),
+
+    // 变更文件
+    ("change.select_packages", "选择发生变更的包:"),
+    ("change.bump_prompt", "升级类型 (patch, minor, major) [patch]:"),
+    ("change.summary_prompt", "变更摘要:"),
+    ("change.invalid_bump", "无效的升级类型: {}（可用 patch、minor 或 major）"),
+    ("change.summary_required", "必须填写变更摘要"),
+    ("change.cancelled", "未选择任何包，未记录变更"),
+    ("change.added", "已创建 {}（{}: {}）"),
+    ("change.none", "没有待发布的变更"),
+    ("change.total", "共 {} 个待发布的变更"),
+    ("change.read_failed", "读取变更文件失败: {}"),
+    ("change.parse_failed", "变更文件格式无效: {}"),
+    ("change.write_failed", "写入变更文件失败: {}"),
+    ("change.remove_failed", "删除变更文件失败: {}"),
+    ("change.unknown_package", "包 {} 不存在（变更文件 {}）"),
+    ("changelog.read_failed", "读取 CHANGELOG 失败: {}"),
+
+    // Git
+    ("git.not_repository", "工作区不在 git 仓库中"),
+    ("git.spawn_failed", "无法启动 git"),
+    ("git.command_failed", "git {} 执行失败: {}"),
+
+    // 发布
+    ("publish.json_requires_yes", "JSON 输出无法确认操作，请使用 --yes 发布，或使用 --dry-run 只输出方案"),
+    ("publish.circular", "无法确定发布顺序: 存在 {} 个循环依赖"),
+    ("publish.unknown_workspace_dependency", "{} 使用 workspace: 协议依赖 {}，但它不是工作区包"),
+    ("publish.plan_details", "发布方案"),
+    ("publish.stage", "[阶段 {}]"),
+    ("publish.status.pending", "待发布"),
+    ("publish.status.published", "已发布"),
+    ("publish.status.private", "私有"),
+    ("publish.rewrite_line", "      {} ({}): {} → {}"),
+    ("publish.registry", "Registry: {}"),
+    ("publish.total", "共 {} 个包待发布（总计 {} 个包）"),
+    ("publish.nothing_to_publish", "所有公开包的版本均已发布"),
+    ("publish.dry_run_complete", "预演完成，未发布任何包"),
+    ("publish.confirm_prompt", "确认发布这些包吗？(y/N)"),
+    ("publish.cancelled", "发布已取消"),
+    ("publish.publishing", "正在发布 {}@{}..."),
+    ("publish.published", "已发布: {}"),
+    ("publish.failed", "发布 {} 失败"),
+    ("publish.skipped", "因失败而未发布: {}"),
+    ("publish.spawn_failed", "无法启动 {}"),
+    ("publish.pack_failed", "打包 {} 失败: {}"),
+    ("publish.publish_failed", "发布 {} 失败: {}"),
+
+    // 依赖图
+    ("graph.invalid_format", "不支持的依赖图格式: {}（可选 dot、mermaid、graphml、cytoscape-json）"),
+    ("graph.no_packages", "工作区中没有找到包"),
+    ("graph.requires_tty", "交互式依赖图浏览器需要在终端中运行"),
+    ("graph.too_small", "终端窗口太小，请调大窗口（按 q 退出）"),
+    ("graph.title", "MonoX 依赖图"),
+    ("graph.on", "显示"),
+    ("graph.off", "隐藏"),
+    ("graph.toggle_dev", "[d] 开发依赖: {}"),
+    ("graph.toggle_peer", "[p] 对等依赖: {}"),
+    ("graph.search", "搜索: {}"),
+    ("graph.packages", "包 ({})"),
+    ("graph.dependencies", "依赖 ({})"),
+    ("graph.dependents", "被依赖 ({})"),
+    ("graph.none", "（无）"),
+    ("graph.stage", "构建阶段 {} / {}"),
+    ("graph.no_stage", "无构建阶段（处于循环依赖中）"),
+    ("graph.cycle", "↻ 循环依赖: {}"),
+    ("graph.no_cycle", "不在循环依赖中"),
+    ("graph.no_match", "没有匹配搜索的包"),
+    ("graph.help", "↑↓ 移动  ←→/Tab 切换栏  Enter 打开  / 搜索  d 开发依赖  p 对等依赖  q 退出"),
+    ("graph.search_help", "输入以过滤  ↑↓ 移动  Enter 完成  Esc 清除"),
 ];
@@ -21,6 +21,10 @@ mod models;
 mod ui;
 mod utils;
 
+#[cfg(test)]
+#[path = "../tests/support/mock_registry.rs"]
+mod mock_registry;
+
 use anyhow::Result;
 use models::config::Config;
 
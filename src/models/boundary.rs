@@ -0,0 +1,69 @@
+// ============================================================================
+// MonoX - 架构边界数据模型
+// ============================================================================
+//
+// 文件: src/models/boundary.rs
+// 职责: 架构边界检查结果的数据结构定义
+// 边界:
+//   - ✅ 违规依赖数据结构定义
+//   - ✅ 检查报告数据结构定义
+//   - ❌ 不应包含规则解析和匹配逻辑
+//   - ❌ 不应包含 UI 显示逻辑
+//
+// ============================================================================
+
+use serde::Serialize;
+
+use crate::models::package::DependencyType;
+
+/// 违规类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BoundaryViolationKind {
+    /// 依赖了 deny 匹配的包
+    Denied,
+    /// 依赖的包不匹配 allow 中的任何选择器
+    NotAllowed,
+}
+
+/// 违反架构边界规则的依赖（一条依赖违反一条规则）
+#[derive(Debug, Clone, Serialize)]
+pub struct BoundaryViolation {
+    /// 依赖方
+    pub package: String,
+    /// 依赖方的标签
+    pub package_tags: Vec<String>,
+    /// 被依赖的包
+    pub dependency: String,
+    /// 被依赖包的标签
+    pub dependency_tags: Vec<String>,
+    /// 依赖类型
+    pub types: Vec<DependencyType>,
+    /// 规则名称
+    pub rule: String,
+    /// 违规类型
+    pub kind: BoundaryViolationKind,
+    /// 匹配到的 deny 选择器，或 allow 中的全部选择器
+    pub selectors: Vec<String>,
+}
+
+impl BoundaryViolation {
+    /// 依赖类型标签（如 "prod, dev"）
+    pub fn type_label(&self) -> String {
+        self.types.iter().map(|t| t.as_str()).collect::<Vec<_>>().join(", ")
+    }
+}
+
+/// 架构边界检查报告
+#[derive(Debug, Clone, Serialize)]
+pub struct BoundaryReport {
+    /// 规则数量
+    pub rules: usize,
+    /// 检查的工作区内部依赖数量
+    pub dependencies_checked: usize,
+    /// 规则中引用了但没有任何包使用的标签
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub unknown_tags: Vec<String>,
+    /// 违规依赖
+    pub violations: Vec<BoundaryViolation>,
+}
@@ -18,9 +18,13 @@
 // ============================================================================
 
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 use std::sync::{Arc, RwLock};
 
+use crate::models::manifest::RuleSeverity;
+use crate::models::package::DependencyType;
+
 /// 全局配置管理器
 static GLOBAL_CONFIG: std::sync::OnceLock<Arc<RwLock<Config>>> = std::sync::OnceLock::new();
 
@@ -42,6 +46,27 @@ pub struct Config {
     /// 国际化配置
     #[serde(default)]
     pub i18n: I18nConfig,
+    /// 执行历史配置
+    #[serde(default)]
+    pub history: HistoryConfig,
+    /// 依赖源配置
+    #[serde(default)]
+    pub registry: RegistryConfig,
+    /// 依赖更新策略
+    #[serde(default)]
+    pub update: UpdateConfig,
+    /// 版本发布配置
+    #[serde(default)]
+    pub version: VersionConfig,
+    /// 架构边界规则
+    #[serde(default)]
+    pub boundaries: BoundariesConfig,
+    /// 依赖使用情况检查配置
+    #[serde(default)]
+    pub deps_usage: DepsUsageConfig,
+    /// package.json 规范检查配置
+    #[serde(default)]
+    pub manifest: ManifestConfig,
 }
 
 /// 工作空间配置
@@ -56,6 +81,30 @@ pub struct WorkspaceConfig {
     /// 排除扫描的目录或文件模式
     #[serde(default)]
     pub ignore: Vec<String>,
+    /// 所有任务共用的环境变量
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+    /// 按顺序加载的 env 文件（先工作区根目录，再包目录，后者覆盖前者）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub env_files: Vec<String>,
+    /// 允许透传的父进程环境变量（支持 * 通配，为空表示全部透传）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub env_passthrough: Vec<String>,
+    /// 禁止透传的父进程环境变量（支持 * 通配，优先于允许列表）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub env_deny: Vec<String>,
+    /// 额外的敏感变量名模式，其值在日志中脱敏（支持 * 通配）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub env_secrets: Vec<String>,
+    /// 内部依赖的写法 (workspace: 写为 workspace:^，version: 写为本地包的精确版本，为空时按包管理器自动选择)
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub internal_protocol: String,
+    /// 决定构建顺序的依赖类型 (prod, dev, peer, optional)，为空表示全部
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub build_dependency_types: Vec<DependencyType>,
+    /// 不参与构建排序的依赖边（依赖方 -> 被依赖的包列表），用于打破循环依赖
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub ignore_edges: BTreeMap<String, Vec<String>>,
 }
 
 /// 任务配置
@@ -77,6 +126,9 @@ pub struct TaskConfig {
     /// 执行后的命令
     #[serde(default)]
     pub post_command: Option<String>,
+    /// 任务级环境变量（覆盖工作区级配置）
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
 }
 
 /// 执行配置
@@ -118,6 +170,133 @@ pub struct I18nConfig {
     pub language: String,
 }
 
+/// 执行历史配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryConfig {
+    /// 是否记录执行历史（未配置时默认记录）
+    #[serde(default = "Config::default_history_enabled")]
+    pub enabled: bool,
+    /// 最多保留的运行记录数
+    #[serde(default = "Config::default_history_max_runs")]
+    pub max_runs: usize,
+}
+
+/// 依赖源配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegistryConfig {
+    /// 默认 registry 地址（为空时依次读取环境变量、.npmrc，最后使用 npm 官方源）
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub url: String,
+    /// 是否离线模式（仅使用本地缓存的包元数据）
+    #[serde(default)]
+    pub offline: bool,
+    /// 检查更新时使用的发布标签（为空时使用 latest）
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub dist_tag: String,
+    /// 按依赖覆盖发布标签（依赖名 -> 标签）
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub dist_tags: BTreeMap<String, String>,
+}
+
+/// 依赖更新策略配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateConfig {
+    /// 默认更新级别 (patch, minor, major)
+    #[serde(
+        default = "Config::default_update_target",
+        deserialize_with = "deserialize_update_target"
+    )]
+    pub target: String,
+    /// 固定版本的依赖（支持通配符，不会被更新）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pinned: Vec<String>,
+    /// 忽略的依赖（支持通配符，--all 时跳过）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ignore: Vec<String>,
+    /// 允许的版本范围（依赖名 -> 版本范围）
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub allowed: BTreeMap<String, String>,
+}
+
+/// 版本发布配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VersionConfig {
+    /// 版本模式 (independent: 各包独立版本, fixed: 所有包共用一个版本)
+    #[serde(default)]
+    pub mode: String,
+    /// 固定组（支持通配符，组内的包始终使用相同版本）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fixed: Vec<Vec<String>>,
+    /// 默认预发布标识 (如 alpha, beta, rc)
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub preid: String,
+    /// 发布提交信息
+    #[serde(default)]
+    pub commit_message: String,
+    /// 标签格式（{name} 为包名，{version} 为版本，为空时按版本模式选择）
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub tag_format: String,
+}
+
+/// 架构边界配置
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BoundariesConfig {
+    /// 包标签（包名，支持通配符 -> 标签），与 package.json 中的 monox.tags 合并
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub tags: BTreeMap<String, Vec<String>>,
+    /// 依赖规则
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<BoundaryRule>,
+}
+
+/// 架构边界规则（选择器: 标签、path:<路径>、name:<包名>，均支持通配符，! 前缀表示取反）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoundaryRule {
+    /// 规则名称（为空时使用序号）
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub name: String,
+    /// 规则适用的依赖方（任一选择器匹配即可，为空表示所有包）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub from: Vec<String>,
+    /// 规则适用的被依赖包（任一选择器匹配即可，为空表示所有包）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub to: Vec<String>,
+    /// 规则适用的依赖类型（为空表示所有类型）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub types: Vec<DependencyType>,
+    /// 只允许依赖匹配这些选择器的包
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allow: Vec<String>,
+    /// 禁止依赖匹配这些选择器的包
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub deny: Vec<String>,
+}
+
+/// 依赖使用情况检查配置
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DepsUsageConfig {
+    /// 不报告为未使用的依赖（支持通配符，如只在构建工具中使用的依赖）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ignore_unused: Vec<String>,
+    /// 不报告为未声明的导入（支持通配符，如路径别名和虚拟模块）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ignore_undeclared: Vec<String>,
+}
+
+/// package.json 规范检查配置
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ManifestConfig {
+    /// 可发布的包必须包含的字段（为空时使用默认字段）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub required_fields: Vec<String>,
+    /// 脚本名必须匹配的正则表达式（为空时使用默认规则）
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub script_pattern: String,
+    /// 规则严重级别（规则标识 -> off、warn、error）
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub rules: BTreeMap<String, RuleSeverity>,
+}
+
 /// CLI 运行时参数（用于覆盖配置文件）
 #[derive(Debug, Clone, Default)]
 pub struct RuntimeArgs {
@@ -238,6 +417,51 @@ pub trait ConfigDefaults {
     fn default_language() -> String {
         "en_us".to_string()
     }
+
+    /// 获取默认是否记录执行历史
+    fn default_history_enabled() -> bool {
+        true
+    }
+
+    /// 获取默认最多保留的运行记录数
+    fn default_history_max_runs() -> usize {
+        200
+    }
+
+    /// 获取默认是否离线模式
+    fn default_registry_offline() -> bool {
+        false
+    }
+
+    /// 获取默认更新级别
+    fn default_update_target() -> String {
+        "major".to_string()
+    }
+
+    /// 获取默认版本模式
+    fn default_version_mode() -> String {
+        "independent".to_string()
+    }
+
+    /// 获取默认发布提交信息
+    fn default_commit_message() -> String {
+        "chore(release): publish".to_string()
+    }
+
+    /// 获取默认标签格式
+    fn default_tag_format() -> String {
+        "{name}@{version}".to_string()
+    }
+
+    /// 获取可发布的包默认必须包含的字段
+    fn default_manifest_required_fields() -> Vec<String> {
+        ["name", "version", "license", "repository"].iter().map(|s| s.to_string()).collect()
+    }
+
+    /// 获取默认脚本名规则（小写单词，用 : 或 - 连接）
+    fn default_script_pattern() -> String {
+        "^[a-z][a-z0-9]*([:-][a-z0-9]+)*$".to_string()
+    }
 }
 
 impl ConfigDefaults for Config {}
@@ -326,6 +550,7 @@ impl Config {
                 desc: Some("构建所有包".to_string()),
                 command: "npm run build".to_string(),
                 post_command: None,
+                env: BTreeMap::new(),
             },
             TaskConfig {
                 name: "test".to_string(),
@@ -334,6 +559,7 @@ impl Config {
                 desc: Some("运行测试".to_string()),
                 command: "npm run test".to_string(),
                 post_command: None,
+                env: BTreeMap::new(),
             },
             TaskConfig {
                 name: "lint".to_string(),
@@ -342,6 +568,7 @@ impl Config {
                 desc: Some("代码检查".to_string()),
                 command: "npm run lint".to_string(),
                 post_command: None,
+                env: BTreeMap::new(),
             },
         ];
 
@@ -553,6 +780,142 @@ impl Config {
         Ok(config.workspace.package_manager.clone())
     }
 
+    /// 获取执行历史配置（带默认值）
+    pub fn get_history_config() -> HistoryConfig {
+        Self::get_history_config_from_config().unwrap_or_default()
+    }
+
+    /// 从配置获取执行历史配置（可能失败）
+    fn get_history_config_from_config() -> anyhow::Result<HistoryConfig> {
+        let global_config =
+            GLOBAL_CONFIG.get().ok_or_else(|| anyhow::anyhow!("Global config not initialized"))?;
+
+        let config = global_config
+            .read()
+            .map_err(|_| anyhow::anyhow!("Failed to acquire config read lock"))?;
+
+        Ok(config.history.clone())
+    }
+
+    /// 获取依赖源配置（带默认值）
+    pub fn get_registry_config() -> RegistryConfig {
+        Self::get_registry_config_from_config().unwrap_or_default()
+    }
+
+    /// 从配置获取依赖源配置（可能失败）
+    fn get_registry_config_from_config() -> anyhow::Result<RegistryConfig> {
+        let global_config =
+            GLOBAL_CONFIG.get().ok_or_else(|| anyhow::anyhow!("Global config not initialized"))?;
+
+        let config = global_config
+            .read()
+            .map_err(|_| anyhow::anyhow!("Failed to acquire config read lock"))?;
+
+        Ok(config.registry.clone())
+    }
+
+    /// 获取依赖更新策略（带默认值）
+    pub fn get_update_config() -> UpdateConfig {
+        Self::get_update_config_from_config().unwrap_or_default()
+    }
+
+    /// 从配置获取依赖更新策略（可能失败）
+    fn get_update_config_from_config() -> anyhow::Result<UpdateConfig> {
+        let global_config =
+            GLOBAL_CONFIG.get().ok_or_else(|| anyhow::anyhow!("Global config not initialized"))?;
+
+        let config = global_config
+            .read()
+            .map_err(|_| anyhow::anyhow!("Failed to acquire config read lock"))?;
+
+        Ok(config.update.clone())
+    }
+
+    /// 获取版本发布配置（带默认值）
+    pub fn get_version_config() -> VersionConfig {
+        Self::get_version_config_from_config().unwrap_or_default()
+    }
+
+    /// 从配置获取版本发布配置（可能失败）
+    fn get_version_config_from_config() -> anyhow::Result<VersionConfig> {
+        let global_config =
+            GLOBAL_CONFIG.get().ok_or_else(|| anyhow::anyhow!("Global config not initialized"))?;
+
+        let config = global_config
+            .read()
+            .map_err(|_| anyhow::anyhow!("Failed to acquire config read lock"))?;
+
+        Ok(config.version.clone())
+    }
+
+    /// 获取架构边界配置（带默认值）
+    pub fn get_boundaries_config() -> BoundariesConfig {
+        Self::get_boundaries_config_from_config().unwrap_or_default()
+    }
+
+    /// 从配置获取架构边界配置（可能失败）
+    fn get_boundaries_config_from_config() -> anyhow::Result<BoundariesConfig> {
+        let global_config =
+            GLOBAL_CONFIG.get().ok_or_else(|| anyhow::anyhow!("Global config not initialized"))?;
+
+        let config = global_config
+            .read()
+            .map_err(|_| anyhow::anyhow!("Failed to acquire config read lock"))?;
+
+        Ok(config.boundaries.clone())
+    }
+
+    /// 获取依赖使用情况检查配置（带默认值）
+    pub fn get_deps_usage_config() -> DepsUsageConfig {
+        Self::get_deps_usage_config_from_config().unwrap_or_default()
+    }
+
+    /// 从配置获取依赖使用情况检查配置（可能失败）
+    fn get_deps_usage_config_from_config() -> anyhow::Result<DepsUsageConfig> {
+        let global_config =
+            GLOBAL_CONFIG.get().ok_or_else(|| anyhow::anyhow!("Global config not initialized"))?;
+
+        let config = global_config
+            .read()
+            .map_err(|_| anyhow::anyhow!("Failed to acquire config read lock"))?;
+
+        Ok(config.deps_usage.clone())
+    }
+
+    /// 获取 package.json 规范检查配置（带默认值）
+    pub fn get_manifest_config() -> ManifestConfig {
+        Self::get_manifest_config_from_config().unwrap_or_default()
+    }
+
+    /// 从配置获取 package.json 规范检查配置（可能失败）
+    fn get_manifest_config_from_config() -> anyhow::Result<ManifestConfig> {
+        let global_config =
+            GLOBAL_CONFIG.get().ok_or_else(|| anyhow::anyhow!("Global config not initialized"))?;
+
+        let config = global_config
+            .read()
+            .map_err(|_| anyhow::anyhow!("Failed to acquire config read lock"))?;
+
+        Ok(config.manifest.clone())
+    }
+
+    /// 获取工作区配置（带默认值）
+    pub fn get_workspace_config() -> WorkspaceConfig {
+        Self::get_workspace_config_from_config().unwrap_or_default()
+    }
+
+    /// 从配置获取工作区配置（可能失败）
+    fn get_workspace_config_from_config() -> anyhow::Result<WorkspaceConfig> {
+        let global_config =
+            GLOBAL_CONFIG.get().ok_or_else(|| anyhow::anyhow!("Global config not initialized"))?;
+
+        let config = global_config
+            .read()
+            .map_err(|_| anyhow::anyhow!("Failed to acquire config read lock"))?;
+
+        Ok(config.workspace.clone())
+    }
+
     /// 获取任务配置
     pub fn get_task_config(task_name: &str) -> anyhow::Result<TaskConfig> {
         let global_config =
@@ -578,6 +941,14 @@ impl Default for Config {
                 root: ".".to_string(),
                 package_manager: Self::default_package_manager(),
                 ignore: Self::default_ignore_patterns(),
+                env: BTreeMap::new(),
+                env_files: Vec::new(),
+                env_passthrough: Vec::new(),
+                env_deny: Vec::new(),
+                env_secrets: Vec::new(),
+                internal_protocol: String::new(),
+                build_dependency_types: Vec::new(),
+                ignore_edges: BTreeMap::new(),
             },
             tasks: Vec::new(),
             execution: ExecutionConfig {
@@ -592,6 +963,39 @@ impl Default for Config {
                 colored: Self::default_colored(),
             },
             i18n: I18nConfig { language: Self::default_language() },
+            history: HistoryConfig {
+                enabled: Self::default_history_enabled(),
+                max_runs: Self::default_history_max_runs(),
+            },
+            registry: RegistryConfig {
+                url: String::new(),
+                offline: Self::default_registry_offline(),
+                dist_tag: String::new(),
+                dist_tags: BTreeMap::new(),
+            },
+            update: UpdateConfig {
+                target: Self::default_update_target(),
+                pinned: Vec::new(),
+                ignore: Vec::new(),
+                allowed: BTreeMap::new(),
+            },
+            version: VersionConfig {
+                mode: Self::default_version_mode(),
+                fixed: Vec::new(),
+                preid: String::new(),
+                commit_message: Self::default_commit_message(),
+                tag_format: String::new(),
+            },
+            boundaries: BoundariesConfig { tags: BTreeMap::new(), rules: Vec::new() },
+            deps_usage: DepsUsageConfig {
+                ignore_unused: Vec::new(),
+                ignore_undeclared: Vec::new(),
+            },
+            manifest: ManifestConfig {
+                required_fields: Vec::new(),
+                script_pattern: String::new(),
+                rules: BTreeMap::new(),
+            },
         }
     }
 }
@@ -602,6 +1006,14 @@ impl Default for WorkspaceConfig {
             root: ".".to_string(),
             package_manager: Config::default_package_manager(),
             ignore: Config::default_ignore_patterns(),
+            env: BTreeMap::new(),
+            env_files: Vec::new(),
+            env_passthrough: Vec::new(),
+            env_deny: Vec::new(),
+            env_secrets: Vec::new(),
+            internal_protocol: String::new(),
+            build_dependency_types: Vec::new(),
+            ignore_edges: BTreeMap::new(),
         }
     }
 }
@@ -632,3 +1044,133 @@ impl Default for I18nConfig {
         Self { language: Config::default_language() }
     }
 }
+
+impl Default for HistoryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: Config::default_history_enabled(),
+            max_runs: Config::default_history_max_runs(),
+        }
+    }
+}
+
+impl Default for RegistryConfig {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            offline: Config::default_registry_offline(),
+            dist_tag: String::new(),
+            dist_tags: BTreeMap::new(),
+        }
+    }
+}
+
+impl RegistryConfig {
+    /// 获取指定依赖使用的发布标签
+    pub fn dist_tag_for(&self, name: &str) -> &str {
+        match self.dist_tags.get(name) {
+            Some(tag) if !tag.is_empty() => tag,
+            _ if !self.dist_tag.is_empty() => &self.dist_tag,
+            _ => "latest",
+        }
+    }
+}
+
+impl Default for UpdateConfig {
+    fn default() -> Self {
+        Self {
+            target: Config::default_update_target(),
+            pinned: Vec::new(),
+            ignore: Vec::new(),
+            allowed: BTreeMap::new(),
+        }
+    }
+}
+
+impl UpdateConfig {
+    /// 判断依赖是否固定版本
+    pub fn is_pinned(&self, name: &str) -> bool {
+        matches_any(&self.pinned, name)
+    }
+
+    /// 判断依赖是否被忽略
+    pub fn is_ignored(&self, name: &str) -> bool {
+        matches_any(&self.ignore, name)
+    }
+}
+
+impl Default for VersionConfig {
+    fn default() -> Self {
+        Self {
+            mode: Config::default_version_mode(),
+            fixed: Vec::new(),
+            preid: String::new(),
+            commit_message: Config::default_commit_message(),
+            tag_format: String::new(),
+        }
+    }
+}
+
+impl VersionConfig {
+    /// 是否为固定模式（所有包共用一个版本）
+    pub fn is_fixed_mode(&self) -> bool {
+        self.mode == "fixed"
+    }
+
+    /// 发布提交信息（未配置时使用默认值）
+    pub fn commit_message(&self) -> String {
+        match self.commit_message.as_str() {
+            "" => Config::default_commit_message(),
+            message => message.to_string(),
+        }
+    }
+
+    /// 包的标签名（固定模式未配置格式时使用 v{version}）
+    pub fn tag_name(&self, name: &str, version: &str) -> String {
+        let format = match self.tag_format.as_str() {
+            "" if self.is_fixed_mode() => "v{version}".to_string(),
+            "" => Config::default_tag_format(),
+            format => format.to_string(),
+        };
+        format.replace("{name}", name).replace("{version}", version)
+    }
+}
+
+impl ManifestConfig {
+    /// 可发布的包必须包含的字段（未配置时使用默认值）
+    pub fn required_fields(&self) -> Vec<String> {
+        if self.required_fields.is_empty() {
+            Config::default_manifest_required_fields()
+        } else {
+            self.required_fields.clone()
+        }
+    }
+
+    /// 脚本名规则（未配置时使用默认值）
+    pub fn script_pattern(&self) -> String {
+        match self.script_pattern.as_str() {
+            "" => Config::default_script_pattern(),
+            pattern => pattern.to_string(),
+        }
+    }
+}
+
+/// 读取默认更新级别，拼写错误的级别在加载配置时报错而不是按 major 处理
+fn deserialize_update_target<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    const TARGETS: &[&str] = &["patch", "minor", "major"];
+    let target = String::deserialize(deserializer)?;
+    if !TARGETS.contains(&target.as_str()) {
+        return Err(serde::de::Error::unknown_variant(&target, TARGETS));
+    }
+    Ok(target)
+}
+
+/// 判断名称是否匹配任一通配符模式
+pub fn matches_any(patterns: &[String], name: &str) -> bool {
+    patterns.iter().any(|pattern| {
+        glob::Pattern::new(pattern).map(|p| p.matches(name)).unwrap_or(pattern == name)
+    })
+}
@@ -0,0 +1,62 @@
+// ============================================================================
+// MonoX - 依赖使用情况数据模型
+// ============================================================================
+//
+// 文件: src/models/deps_usage.rs
+// 职责: 未声明依赖和未使用依赖检查结果的数据结构定义
+// 边界:
+//   - ✅ 问题依赖数据结构定义
+//   - ✅ 检查报告数据结构定义
+//   - ❌ 不应包含源码扫描逻辑
+//   - ❌ 不应包含 UI 显示逻辑
+//
+// ============================================================================
+
+use serde::Serialize;
+
+use crate::models::package::DependencyType;
+
+/// 依赖使用问题类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DependencyUsageKind {
+    /// 代码中导入了但 package.json 中没有声明
+    Undeclared,
+    /// package.json 中声明了但代码和脚本中都没有使用
+    Unused,
+}
+
+/// 依赖使用问题
+#[derive(Debug, Clone, Serialize)]
+pub struct DependencyUsageIssue {
+    /// 工作区包名
+    pub package: String,
+    /// 依赖名
+    pub dependency: String,
+    /// 问题类型
+    pub kind: DependencyUsageKind,
+    /// 声明所在的依赖类型（未声明时为空）
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub types: Vec<DependencyType>,
+    /// 导入该依赖的位置（相对包目录的 "文件:行号"，未使用时为空）
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub locations: Vec<String>,
+}
+
+impl DependencyUsageIssue {
+    /// 依赖类型标签（如 "prod, dev"）
+    pub fn type_label(&self) -> String {
+        self.types.iter().map(|t| t.as_str()).collect::<Vec<_>>().join(", ")
+    }
+}
+
+/// 依赖使用情况检查报告
+#[derive(Debug, Clone, Serialize)]
+pub struct DependencyUsageReport {
+    /// 检查的包数量
+    pub packages_scanned: usize,
+    /// 扫描的源码文件数量
+    pub files_scanned: usize,
+    /// 问题依赖（按包名、问题类型、依赖名排序）
+    pub issues: Vec<DependencyUsageIssue>,
+}
@@ -0,0 +1,243 @@
+// ============================================================================
+// MonoX - 执行历史数据模型
+// ============================================================================
+//
+// 文件: src/models/history.rs
+// 职责: 执行历史记录数据结构定义
+// 边界:
+//   - ✅ 单次运行记录数据结构定义
+//   - ✅ 任务执行记录数据结构定义
+//   - ✅ 阶段执行记录数据结构定义
+//   - ✅ 主机信息数据结构定义
+//   - ✅ 从任务模型转换为历史记录
+//   - ❌ 不应包含历史文件读写逻辑
+//   - ❌ 不应包含统计计算逻辑
+//   - ❌ 不应包含 UI 显示逻辑
+//
+// ============================================================================
+
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::models::task::{SkipReason, Task, TaskStatus};
+
+/// 单次运行记录（一次 run/exec 调用）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunRecord {
+    /// 运行ID
+    pub id: String,
+    /// 执行的脚本命令
+    pub command: String,
+    /// 开始时间（Unix 毫秒）
+    pub started_at: u64,
+    /// 结束时间（Unix 毫秒）
+    pub finished_at: u64,
+    /// 是否全部成功
+    pub success: bool,
+    /// 主机信息
+    pub host: HostInfo,
+    /// 任务执行记录
+    pub tasks: Vec<TaskRecord>,
+    /// 阶段执行记录
+    #[serde(default)]
+    pub stages: Vec<StageRecord>,
+}
+
+/// 阶段执行记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StageRecord {
+    /// 阶段序号（从 1 开始）
+    pub stage: usize,
+    /// 开始时间（Unix 毫秒）
+    pub started_at: u64,
+    /// 结束时间（Unix 毫秒）
+    pub finished_at: u64,
+    /// 阶段内任务数
+    pub tasks: usize,
+}
+
+/// 任务执行记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskRecord {
+    /// 包名
+    pub package: String,
+    /// 执行的脚本命令
+    pub command: String,
+    /// 所在构建阶段（从 1 开始）
+    pub stage: usize,
+    /// 任务状态
+    pub status: TaskStatus,
+    /// 开始时间（Unix 毫秒）
+    pub started_at: Option<u64>,
+    /// 结束时间（Unix 毫秒）
+    pub ended_at: Option<u64>,
+    /// 执行时长（毫秒）
+    pub duration_ms: Option<u64>,
+    /// 退出状态码
+    pub exit_code: Option<i32>,
+    /// 缓存状态
+    #[serde(default)]
+    pub cache_status: CacheStatus,
+    /// 跳过原因
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skip_reason: Option<SkipReason>,
+}
+
+/// 任务缓存状态
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CacheStatus {
+    /// 命中缓存，未实际执行
+    Hit,
+    /// 未命中缓存，实际执行
+    Miss,
+    /// 未启用缓存
+    #[default]
+    Disabled,
+}
+
+/// 主机信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HostInfo {
+    /// 主机名
+    pub hostname: String,
+    /// 操作系统
+    pub os: String,
+    /// CPU 架构
+    pub arch: String,
+    /// CPU 核心数
+    pub cpus: usize,
+    /// MonoX 版本
+    pub monox_version: String,
+}
+
+impl RunRecord {
+    /// 运行总时长（毫秒）
+    pub fn duration_ms(&self) -> u64 {
+        self.finished_at.saturating_sub(self.started_at)
+    }
+
+    /// 失败任务数
+    pub fn failed_count(&self) -> usize {
+        self.tasks.iter().filter(|t| t.status == TaskStatus::Failed).count()
+    }
+}
+
+impl TaskRecord {
+    /// 从已完成的任务创建执行记录
+    pub fn from_task(task: &Task, stage: usize) -> Self {
+        Self {
+            package: task.package_name.clone(),
+            command: task.command.clone(),
+            stage,
+            status: task.status.clone(),
+            started_at: task.started_at.map(to_unix_millis),
+            ended_at: task.completed_at.map(to_unix_millis),
+            duration_ms: task.duration().map(|d| d.as_millis() as u64),
+            exit_code: task.result.as_ref().map(|r| r.exit_code),
+            cache_status: CacheStatus::default(),
+            skip_reason: task.skip_reason.clone(),
+        }
+    }
+
+    /// 任务唯一键（包名:命令）
+    pub fn key(&self) -> String {
+        format!("{}:{}", self.package, self.command)
+    }
+}
+
+impl HostInfo {
+    /// 采集当前主机信息
+    pub fn current() -> Self {
+        let hostname = std::env::var("HOSTNAME")
+            .or_else(|_| std::env::var("COMPUTERNAME"))
+            .ok()
+            .or_else(|| std::fs::read_to_string("/etc/hostname").ok().map(|s| s.trim().to_string()))
+            .filter(|s| !s.is_empty())
+            .unwrap_or_else(|| "unknown".to_string());
+
+        Self {
+            hostname,
+            os: std::env::consts::OS.to_string(),
+            arch: std::env::consts::ARCH.to_string(),
+            cpus: num_cpus::get(),
+            monox_version: env!("CARGO_PKG_VERSION").to_string(),
+        }
+    }
+}
+
+/// 将系统时间转换为 Unix 毫秒
+pub fn to_unix_millis(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or(0)
+}
+
+/// 执行历史统计报告
+#[derive(Debug, Clone, Serialize)]
+pub struct HistoryReport {
+    /// 参与统计的运行次数
+    pub total_runs: usize,
+    /// 最慢的包（按平均耗时降序）
+    pub slowest_packages: Vec<PackageTiming>,
+    /// 不稳定任务（成功/失败交替出现）
+    pub flaky_tasks: Vec<FlakyTask>,
+    /// 最近运行趋势（按时间顺序）
+    pub trend: Vec<RunTrend>,
+    /// 各任务耗时分位数
+    pub task_percentiles: Vec<TaskPercentile>,
+}
+
+/// 包耗时统计
+#[derive(Debug, Clone, Serialize)]
+pub struct PackageTiming {
+    /// 包名
+    pub package: String,
+    /// 样本数
+    pub samples: usize,
+    /// 平均耗时（毫秒）
+    pub mean_ms: u64,
+    /// 最大耗时（毫秒）
+    pub max_ms: u64,
+}
+
+/// 不稳定任务信息
+#[derive(Debug, Clone, Serialize)]
+pub struct FlakyTask {
+    /// 任务键（包名:命令）
+    pub task: String,
+    /// 执行次数（不含跳过）
+    pub runs: usize,
+    /// 失败次数
+    pub failures: usize,
+    /// 成功/失败切换次数
+    pub flips: usize,
+}
+
+/// 单次运行趋势
+#[derive(Debug, Clone, Serialize)]
+pub struct RunTrend {
+    /// 运行ID
+    pub id: String,
+    /// 开始时间（Unix 毫秒）
+    pub started_at: u64,
+    /// 执行的脚本命令
+    pub command: String,
+    /// 总耗时（毫秒）
+    pub duration_ms: u64,
+    /// 任务数
+    pub tasks: usize,
+    /// 失败任务数
+    pub failed: usize,
+}
+
+/// 任务耗时分位数
+#[derive(Debug, Clone, Serialize)]
+pub struct TaskPercentile {
+    /// 任务键（包名:命令）
+    pub task: String,
+    /// 样本数
+    pub samples: usize,
+    /// p50 耗时（毫秒）
+    pub p50_ms: u64,
+    /// p95 耗时（毫秒）
+    pub p95_ms: u64,
+}
@@ -0,0 +1,106 @@
+// ============================================================================
+// MonoX - 锁文件数据模型
+// ============================================================================
+//
+// 文件: src/models/lockfile.rs
+// 职责: 锁文件解析结果和重复依赖报告的数据结构定义
+// 边界:
+//   - ✅ 锁文件类型定义
+//   - ✅ 已解析依赖图数据结构定义
+//   - ✅ 重复依赖报告数据结构定义
+//   - ❌ 不应包含锁文件解析逻辑
+//   - ❌ 不应包含文件操作逻辑
+//   - ❌ 不应包含 UI 显示逻辑
+//
+// ============================================================================
+
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
+
+/// 锁文件类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LockfileKind {
+    /// pnpm-lock.yaml
+    Pnpm,
+    /// package-lock.json (v2/v3)
+    Npm,
+    /// yarn.lock (yarn 1.x)
+    YarnClassic,
+    /// yarn.lock (yarn 2+)
+    YarnBerry,
+}
+
+impl fmt::Display for LockfileKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LockfileKind::Pnpm => write!(f, "pnpm-lock.yaml"),
+            LockfileKind::Npm => write!(f, "package-lock.json"),
+            LockfileKind::YarnClassic => write!(f, "yarn.lock (classic)"),
+            LockfileKind::YarnBerry => write!(f, "yarn.lock (berry)"),
+        }
+    }
+}
+
+/// 已解析的锁文件
+#[derive(Debug, Clone)]
+pub struct Lockfile {
+    /// 锁文件类型
+    pub kind: LockfileKind,
+    /// 锁文件路径
+    pub path: PathBuf,
+    /// 已安装的包（键为包 ID）
+    pub packages: BTreeMap<String, LockedPackage>,
+    /// 工作区包（导入方）直接依赖的包 ID
+    pub importers: BTreeMap<String, Vec<String>>,
+}
+
+/// 锁文件中的已安装包
+#[derive(Debug, Clone)]
+pub struct LockedPackage {
+    /// 包名
+    pub name: String,
+    /// 解析后的版本
+    pub version: String,
+    /// 依赖的包 ID
+    pub dependencies: Vec<String>,
+}
+
+/// 重复依赖检查报告
+#[derive(Debug, Clone, Serialize)]
+pub struct DuplicateReport {
+    /// 锁文件类型
+    pub lockfile: LockfileKind,
+    /// 锁文件路径
+    pub path: PathBuf,
+    /// 已安装的包总数
+    pub total_packages: usize,
+    /// 重复安装的包
+    pub duplicates: Vec<DuplicatePackage>,
+    /// 重复版本的预估额外体积（字节，未安装时为空）
+    pub extra_bytes: Option<u64>,
+}
+
+/// 安装了多个版本的包
+#[derive(Debug, Clone, Serialize)]
+pub struct DuplicatePackage {
+    /// 包名
+    pub name: String,
+    /// 各个已安装版本（按版本升序）
+    pub versions: Vec<DuplicateVersion>,
+    /// 除最高版本外其他版本的预估体积（字节）
+    pub extra_bytes: Option<u64>,
+}
+
+/// 重复包的单个版本
+#[derive(Debug, Clone, Serialize)]
+pub struct DuplicateVersion {
+    /// 版本号
+    pub version: String,
+    /// 引入该版本的包（工作区包名或 名称@版本）
+    pub pulled_by: Vec<String>,
+    /// 单份安装体积（字节，未安装时为空）
+    pub size_bytes: Option<u64>,
+}
@@ -0,0 +1,85 @@
+// ============================================================================
+// MonoX - package.json 规范检查数据模型
+// ============================================================================
+//
+// 文件: src/models/manifest.rs
+// 职责: package.json 规范检查的严重级别、问题和自动修复数据结构定义
+// 边界:
+//   - ✅ 规则严重级别定义（配置和输出共用）
+//   - ✅ 检查问题和自动修复数据结构定义
+//   - ✅ 检查报告数据结构定义
+//   - ❌ 不应包含规则实现
+//   - ❌ 不应包含文件读写逻辑
+//   - ❌ 不应包含 UI 显示逻辑
+//
+// ============================================================================
+
+use serde::{Deserialize, Serialize};
+
+/// 规则严重级别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleSeverity {
+    /// 不检查
+    Off,
+    /// 只提示，不影响退出码
+    Warn,
+    /// 检查失败
+    Error,
+}
+
+/// 可以安全自动应用的修改
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum ManifestFix {
+    /// 把键路径上的字符串从 from 改为 to
+    Replace {
+        /// 键路径（如 ["engines", "node"]）
+        key: Vec<String>,
+        /// 当前值
+        from: String,
+        /// 新值
+        to: String,
+    },
+    /// 新增顶层字段
+    Insert {
+        /// 字段名
+        key: String,
+        /// 字段值
+        value: serde_json::Value,
+    },
+}
+
+/// package.json 规范问题
+#[derive(Debug, Clone, Serialize)]
+pub struct ManifestIssue {
+    /// 规则标识
+    pub rule: String,
+    /// 严重级别
+    pub severity: RuleSeverity,
+    /// 包名（没有包名时为目录名）
+    pub package: String,
+    /// 包的相对路径
+    pub folder: String,
+    /// 问题描述
+    pub message: String,
+    /// 自动修复（无法安全修复时为空）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix: Option<ManifestFix>,
+}
+
+/// package.json 规范检查报告
+#[derive(Debug, Clone, Serialize)]
+pub struct ManifestReport {
+    /// 检查的包数量
+    pub packages_checked: usize,
+    /// 错误数量
+    pub errors: usize,
+    /// 警告数量
+    pub warnings: usize,
+    /// [manifest.rules] 中不存在的规则
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub unknown_rules: Vec<String>,
+    /// 问题（按包的相对路径和规则排序）
+    pub issues: Vec<ManifestIssue>,
+}
@@ -15,10 +15,18 @@
 //
 // ============================================================================
 
+pub mod boundary;
 pub mod config;
+pub mod deps_usage;
+pub mod history;
+pub mod lockfile;
+pub mod manifest;
 pub mod package;
+pub mod plan;
+pub mod registry;
+pub mod report;
 pub mod task;
 
 // 重新导出常用类型
 pub use package::DependencyAnalysisResult;
-pub use task::{Task, TaskConfig, TaskResult, TaskStatus};
+pub use task::{SkipReason, Task, TaskConfig, TaskResult, TaskStatus};
@@ -21,6 +21,32 @@ use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::PathBuf;
 
+/// 依赖类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DependencyType {
+    /// dependencies
+    Prod,
+    /// devDependencies
+    Dev,
+    /// peerDependencies
+    Peer,
+    /// optionalDependencies
+    Optional,
+}
+
+impl DependencyType {
+    /// 类型名称
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DependencyType::Prod => "prod",
+            DependencyType::Dev => "dev",
+            DependencyType::Peer => "peer",
+            DependencyType::Optional => "optional",
+        }
+    }
+}
+
 /// 工作区包信息
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorkspacePackage {
@@ -32,12 +58,21 @@ pub struct WorkspacePackage {
     pub absolute_path: PathBuf,
     /// 版本
     pub version: String,
-    /// 所有依赖（包括 dependencies, devDependencies, peerDependencies）
+    /// 所有依赖（包括 dependencies, devDependencies, peerDependencies, optionalDependencies）
     pub dependencies: HashMap<String, String>,
+    /// 每个依赖声明所在的依赖类型（同一依赖可能同时出现在多个字段中）
+    #[serde(default)]
+    pub dependency_types: HashMap<String, Vec<DependencyType>>,
     /// 仅工作区内的依赖
     pub workspace_dependencies: HashSet<String>,
+    /// 决定构建顺序的工作区依赖（依赖类型在 build_dependency_types 中且不在 ignore_edges 中）
+    #[serde(default)]
+    pub build_dependencies: HashSet<String>,
     /// 构建脚本
     pub scripts: HashMap<String, String>,
+    /// 包标签（来自 package.json 的 monox.tags 和 [boundaries.tags]）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
 }
 
 /// package.json 文件结构（用于解析）
@@ -51,8 +86,13 @@ pub struct PackageJson {
     pub dev_dependencies: HashMap<String, String>,
     #[serde(default, rename = "peerDependencies")]
     pub peer_dependencies: HashMap<String, String>,
+    #[serde(default, rename = "optionalDependencies")]
+    pub optional_dependencies: HashMap<String, String>,
     #[serde(default)]
     pub scripts: HashMap<String, String>,
+    /// monox 字段（包级配置），按原始值读取，格式错误时不影响其它字段的解析
+    #[serde(default)]
+    pub monox: serde_json::Value,
 }
 
 /// 依赖分析结果
@@ -64,10 +104,49 @@ pub struct DependencyAnalysisResult {
     pub stages: Vec<Vec<WorkspacePackage>>,
     /// 循环依赖（如果存在）
     pub circular_dependencies: Vec<Vec<String>>,
+    /// 只经过不决定构建顺序的依赖类型的循环依赖（不影响构建）
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub ignored_circular_dependencies: Vec<Vec<String>>,
+    /// 每个循环依赖中的基本环和建议断开的依赖
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub cycle_details: Vec<CircularDependency>,
     /// 分析统计信息
     pub statistics: AnalysisStatistics,
 }
 
+/// 循环依赖中的一条边（依赖方 -> 被依赖的包）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CycleEdge {
+    /// 依赖方
+    pub from: String,
+    /// 被依赖的包
+    pub to: String,
+    /// 依赖类型
+    pub types: Vec<DependencyType>,
+}
+
+/// 基本环（每个包依赖下一个包，最后一个包依赖第一个包）
+#[derive(Debug, Clone, Serialize)]
+pub struct ElementaryCycle {
+    /// 环上的包（从包名最小的包开始）
+    pub packages: Vec<String>,
+    /// 环上的边
+    pub edges: Vec<CycleEdge>,
+}
+
+/// 一组互相依赖的包（强连通分量）
+#[derive(Debug, Clone, Serialize)]
+pub struct CircularDependency {
+    /// 涉及的包（按包名排序）
+    pub packages: Vec<String>,
+    /// 其中的基本环（按长度排序）
+    pub cycles: Vec<ElementaryCycle>,
+    /// 基本环过多时只列出一部分
+    pub truncated: bool,
+    /// 断开后不再有循环的最少依赖（优先开发依赖和对等依赖）
+    pub break_edges: Vec<CycleEdge>,
+}
+
 /// 分析统计信息
 #[derive(Debug, Clone, Serialize)]
 pub struct AnalysisStatistics {
@@ -99,8 +178,11 @@ impl WorkspacePackage {
             absolute_path,
             version,
             dependencies,
+            dependency_types: HashMap::new(),
             workspace_dependencies: HashSet::new(),
+            build_dependencies: HashSet::new(),
             scripts,
+            tags: Vec::new(),
         }
     }
 
@@ -118,6 +200,35 @@ impl WorkspacePackage {
     pub fn add_workspace_dependency(&mut self, dep_name: String) {
         self.workspace_dependencies.insert(dep_name);
     }
+
+    /// 依赖声明所在的依赖类型
+    pub fn dependency_types_of(&self, dep_name: &str) -> &[DependencyType] {
+        self.dependency_types.get(dep_name).map(Vec::as_slice).unwrap_or_default()
+    }
+}
+
+impl CycleEdge {
+    /// 依赖类型标签（如 "dev, peer"）
+    pub fn type_label(&self) -> String {
+        self.types.iter().map(|t| t.as_str()).collect::<Vec<_>>().join(", ")
+    }
+
+    /// 是否只是开发依赖或对等依赖（断开时影响最小）
+    pub fn is_soft(&self) -> bool {
+        !self.types.is_empty()
+            && self.types.iter().all(|t| matches!(t, DependencyType::Dev | DependencyType::Peer))
+    }
+}
+
+impl ElementaryCycle {
+    /// 环的路径（如 "a ─prod→ b ─dev→ a"）
+    pub fn path(&self) -> String {
+        let mut path = self.packages.first().cloned().unwrap_or_default();
+        for edge in &self.edges {
+            path.push_str(&format!(" ─{}→ {}", edge.type_label(), edge.to));
+        }
+        path
+    }
 }
 
 impl PackageJson {
@@ -129,10 +240,41 @@ impl PackageJson {
         all_deps.extend(self.dependencies.clone());
         all_deps.extend(self.dev_dependencies.clone());
         all_deps.extend(self.peer_dependencies.clone());
+        all_deps.extend(self.optional_dependencies.clone());
 
         all_deps
     }
 
+    /// 获取每个依赖声明所在的依赖类型
+    pub fn get_dependency_types(&self) -> HashMap<String, Vec<DependencyType>> {
+        let mut types: HashMap<String, Vec<DependencyType>> = HashMap::new();
+        let fields = [
+            (&self.dependencies, DependencyType::Prod),
+            (&self.dev_dependencies, DependencyType::Dev),
+            (&self.peer_dependencies, DependencyType::Peer),
+            (&self.optional_dependencies, DependencyType::Optional),
+        ];
+        for (deps, dep_type) in fields {
+            for dep_name in deps.keys() {
+                types.entry(dep_name.clone()).or_default().push(dep_type);
+            }
+        }
+        types
+    }
+
+    /// 获取 monox.tags 中的包标签，monox 或 tags 不是预期的格式时返回 None
+    pub fn get_monox_tags(&self) -> Option<Vec<String>> {
+        let tags = match &self.monox {
+            serde_json::Value::Null => return Some(Vec::new()),
+            serde_json::Value::Object(monox) => match monox.get("tags") {
+                None => return Some(Vec::new()),
+                Some(tags) => tags.as_array()?,
+            },
+            _ => return None,
+        };
+        tags.iter().map(|tag| tag.as_str().map(str::to_string)).collect()
+    }
+
     /// 获取包名，如果没有则使用目录名
     pub fn get_name(&self, fallback_name: &str) -> String {
         self.name.clone().unwrap_or_else(|| fallback_name.to_string())
@@ -163,3 +305,22 @@ impl Default for AnalysisStatistics {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monox_tags(manifest: serde_json::Value) -> Option<Vec<String>> {
+        serde_json::from_value::<PackageJson>(manifest).unwrap().get_monox_tags()
+    }
+
+    #[test]
+    fn reads_monox_tags_leniently() {
+        let tags = monox_tags(serde_json::json!({ "monox": { "tags": ["ui", "web"] } }));
+        assert_eq!(tags, Some(vec!["ui".to_string(), "web".to_string()]));
+        assert_eq!(monox_tags(serde_json::json!({ "name": "a" })), Some(Vec::new()));
+        assert_eq!(monox_tags(serde_json::json!({ "monox": { "tags": "ui" } })), None);
+        assert_eq!(monox_tags(serde_json::json!({ "monox": { "tags": [1] } })), None);
+        assert_eq!(monox_tags(serde_json::json!({ "monox": true })), None);
+    }
+}
@@ -0,0 +1,73 @@
+// ============================================================================
+// MonoX - 执行计划数据模型
+// ============================================================================
+//
+// 文件: src/models/plan.rs
+// 职责: 试运行（--dry-run）执行计划的数据结构定义
+// 边界:
+//   - ✅ 执行计划数据结构定义
+//   - ✅ 计划任务数据结构定义
+//   - ✅ 包选中原因定义
+//   - ❌ 不应包含计划生成逻辑
+//   - ❌ 不应包含任务执行逻辑
+//   - ❌ 不应包含 UI 显示逻辑
+//
+// ============================================================================
+
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use crate::models::task::SkipReason;
+
+/// 执行计划（试运行结果，不实际执行任何命令）
+#[derive(Debug, Clone, Serialize)]
+pub struct ExecutionPlan {
+    /// 执行的脚本命令
+    pub command: String,
+    /// 每个并发阶段结束后执行的命令
+    pub post_command: Option<String>,
+    /// 包管理器
+    pub package_manager: String,
+    /// 阶段数
+    pub total_stages: usize,
+    /// 计划任务（按阶段顺序）
+    pub tasks: Vec<PlannedTask>,
+}
+
+/// 计划任务
+#[derive(Debug, Clone, Serialize)]
+pub struct PlannedTask {
+    /// 包名
+    pub package: String,
+    /// 包版本
+    pub version: String,
+    /// 所在构建阶段（从 1 开始）
+    pub stage: usize,
+    /// 解析后的完整命令行
+    pub command_line: String,
+    /// 工作目录
+    pub working_directory: String,
+    /// 覆盖的环境变量
+    pub env: BTreeMap<String, String>,
+    /// 计划内依赖的工作区包
+    pub depends_on: Vec<String>,
+    /// 选中原因
+    pub selection: SelectionReason,
+    /// 跳过原因（为空表示会执行）
+    pub skip_reason: Option<SkipReason>,
+}
+
+/// 包被选中执行的原因
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "reason", rename_all = "snake_case")]
+pub enum SelectionReason {
+    /// 通过 --all 选中
+    All,
+    /// 直接指定
+    Requested,
+    /// 作为指定包的依赖被选中
+    Dependency {
+        /// 依赖该包的指定包
+        required_by: Vec<String>,
+    },
+}
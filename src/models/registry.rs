@@ -0,0 +1,106 @@
+// ============================================================================
+// MonoX - Registry 数据模型
+// ============================================================================
+//
+// 文件: src/models/registry.rs
+// 职责: npm registry 包元数据（packument）和本地缓存条目的数据结构定义
+// 边界:
+//   - ✅ 精简版和完整版 packument 数据结构定义
+//   - ✅ 缓存条目数据结构定义
+//   - ❌ 不应包含网络请求逻辑
+//   - ❌ 不应包含缓存读写逻辑
+//   - ❌ 不应包含 UI 显示逻辑
+//
+// ============================================================================
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// 包元数据（精简格式 application/vnd.npm.install-v1+json，完整格式额外包含发布时间和仓库地址）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Packument {
+    /// 包名
+    pub name: String,
+    /// 发布标签（latest、next 等）
+    #[serde(rename = "dist-tags", default)]
+    pub dist_tags: BTreeMap<String, String>,
+    /// 已发布的版本
+    #[serde(default)]
+    pub versions: BTreeMap<String, PackumentVersion>,
+    /// 最后修改时间
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modified: Option<String>,
+    /// 各版本发布时间（仅完整格式）
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub time: BTreeMap<String, String>,
+    /// 源码仓库（仅完整格式）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<Repository>,
+}
+
+impl Packument {
+    /// latest 标签指向的版本
+    pub fn latest(&self) -> Option<&str> {
+        self.dist_tags.get("latest").map(|v| v.as_str())
+    }
+
+    /// 仓库的网页地址（git+https://github.com/a/b.git -> https://github.com/a/b）
+    pub fn repository_url(&self) -> Option<String> {
+        let url = match self.repository.as_ref()? {
+            Repository::Url(url) => url.as_str(),
+            Repository::Detailed { url } => url.as_deref()?,
+        };
+        let url = url.trim().trim_start_matches("git+").trim_end_matches(".git");
+
+        // 简写形式: github:user/repo 或 user/repo
+        if let Some(path) = url.strip_prefix("github:") {
+            return Some(format!("https://github.com/{}", path));
+        }
+        if !url.contains(':') && url.split('/').count() == 2 {
+            return Some(format!("https://github.com/{}", url));
+        }
+
+        let url = url.replacen("git://", "https://", 1).replacen("ssh://git@", "https://", 1);
+        let url = match url.strip_prefix("git@") {
+            Some(rest) => format!("https://{}", rest.replacen(':', "/", 1)),
+            None => url,
+        };
+        url.starts_with("http").then_some(url)
+    }
+}
+
+/// 源码仓库信息（字符串或 { type, url } 对象）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Repository {
+    /// 仓库地址字符串
+    Url(String),
+    /// 仓库对象
+    Detailed {
+        /// 仓库地址
+        #[serde(default)]
+        url: Option<String>,
+    },
+}
+
+/// 单个已发布版本
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackumentVersion {
+    /// 版本号
+    pub version: String,
+    /// 弃用说明
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<String>,
+}
+
+/// 本地缓存的包元数据
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedPackument {
+    /// 响应的 ETag（用于条件请求）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
+    /// 缓存时间（Unix 毫秒）
+    pub fetched_at: u64,
+    /// 原始响应内容
+    pub body: serde_json::Value,
+}
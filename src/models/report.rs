@@ -0,0 +1,116 @@
+// ============================================================================
+// MonoX - 运行报告数据模型
+// ============================================================================
+//
+// 文件: src/models/report.rs
+// 职责: 机器可读运行报告的数据结构定义
+// 边界:
+//   - ✅ 报告格式和输出目标定义
+//   - ✅ 运行报告、任务报告数据结构定义
+//   - ✅ 从任务模型转换为任务报告（含输出截断）
+//   - ❌ 不应包含报告文件写入逻辑
+//   - ❌ 不应包含任务执行逻辑
+//   - ❌ 不应包含 UI 显示逻辑
+//
+// ============================================================================
+
+use serde::Serialize;
+use std::path::PathBuf;
+
+use crate::models::history::TaskRecord;
+use crate::models::task::{Task, TaskStatus};
+
+/// 单个输出流最多保留的字节数（保留末尾部分）
+pub const MAX_OUTPUT_BYTES: usize = 16 * 1024;
+
+/// 运行报告格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// JSON 格式
+    Json,
+    /// JUnit XML 格式
+    Junit,
+}
+
+/// 运行报告输出目标
+#[derive(Debug, Clone)]
+pub struct ReportTarget {
+    /// 报告格式
+    pub format: ReportFormat,
+    /// 输出路径
+    pub path: PathBuf,
+}
+
+/// 运行报告（一次 run/exec 调用）
+#[derive(Debug, Clone, Serialize)]
+pub struct RunReport {
+    /// 执行的脚本命令
+    pub command: String,
+    /// 开始时间（Unix 毫秒）
+    pub started_at: u64,
+    /// 结束时间（Unix 毫秒）
+    pub finished_at: u64,
+    /// 总时长（毫秒）
+    pub duration_ms: u64,
+    /// 是否全部成功
+    pub success: bool,
+    /// 任务总数
+    pub total: usize,
+    /// 成功任务数
+    pub successful: usize,
+    /// 失败任务数
+    pub failed: usize,
+    /// 跳过任务数
+    pub skipped: usize,
+    /// 任务报告
+    pub tasks: Vec<TaskReport>,
+}
+
+/// 任务报告
+#[derive(Debug, Clone, Serialize)]
+pub struct TaskReport {
+    /// 任务执行记录
+    #[serde(flatten)]
+    pub record: TaskRecord,
+    /// 标准输出（超出部分截断）
+    pub stdout: String,
+    /// 标准错误输出（超出部分截断）
+    pub stderr: String,
+}
+
+impl TaskReport {
+    /// 从已完成的任务创建任务报告
+    pub fn from_task(task: &Task, stage: usize) -> Self {
+        let record = TaskRecord::from_task(task, stage);
+        let (stdout, stderr) = task
+            .result
+            .as_ref()
+            .map(|r| (truncate_output(&r.stdout), truncate_output(&r.stderr)))
+            .unwrap_or_default();
+
+        Self { record, stdout, stderr }
+    }
+
+    /// 判断任务是否失败
+    pub fn is_failed(&self) -> bool {
+        self.record.status == TaskStatus::Failed
+    }
+
+    /// 判断任务是否跳过
+    pub fn is_skipped(&self) -> bool {
+        self.record.status == TaskStatus::Skipped
+    }
+}
+
+/// 截断输出，只保留末尾 MAX_OUTPUT_BYTES 字节（错误信息通常在末尾）
+fn truncate_output(output: &str) -> String {
+    if output.len() <= MAX_OUTPUT_BYTES {
+        return output.to_string();
+    }
+
+    let mut start = output.len() - MAX_OUTPUT_BYTES;
+    while !output.is_char_boundary(start) {
+        start += 1;
+    }
+    format!("...[truncated {} bytes]\n{}", start, &output[start..])
+}
@@ -7,6 +7,7 @@
 // 边界:
 //   - ✅ 任务信息数据结构定义
 //   - ✅ 任务状态枚举定义
+//   - ✅ 任务跳过原因定义
 //   - ✅ 执行结果数据结构定义
 //   - ✅ 任务配置数据结构定义
 //   - ❌ 不应包含任务执行逻辑
@@ -51,6 +52,18 @@ impl fmt::Display for TaskStatus {
     }
 }
 
+/// 任务跳过原因
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SkipReason {
+    /// 包中不存在该脚本
+    ScriptNotFound,
+    /// 前序阶段执行失败，未执行
+    UpstreamFailed,
+    /// 同一阶段的其他任务失败后被取消（fail-fast）
+    Cancelled,
+}
+
 /// 任务信息
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Task {
@@ -76,6 +89,9 @@ pub struct Task {
     pub started_at: Option<SystemTime>,
     /// 完成时间
     pub completed_at: Option<SystemTime>,
+    /// 跳过原因
+    #[serde(default)]
+    pub skip_reason: Option<SkipReason>,
     /// 执行结果
     pub result: Option<TaskResult>,
 }
@@ -146,6 +162,7 @@ impl Task {
             created_at: SystemTime::now(),
             started_at: None,
             completed_at: None,
+            skip_reason: None,
             result: None,
         }
     }
@@ -170,7 +187,7 @@ impl Task {
                 self.started_at = Some(SystemTime::now());
             }
             false => {
-                self.skip();
+                self.skip(SkipReason::ScriptNotFound);
             }
         }
     }
@@ -192,8 +209,9 @@ impl Task {
     }
 
     /// 跳过执行
-    pub fn skip(&mut self) {
+    pub fn skip(&mut self, reason: SkipReason) {
         self.status = TaskStatus::Skipped;
+        self.skip_reason = Some(reason);
         self.completed_at = Some(SystemTime::now());
     }
 
@@ -0,0 +1,640 @@
+// ============================================================================
+// MonoX - 交互式依赖图浏览器
+// ============================================================================
+//
+// 文件: src/ui/graph_explorer.rs
+// 职责: 在终端全屏界面中浏览包的依赖和被依赖关系
+// 边界:
+//   - ✅ 包列表、依赖列表、被依赖列表三栏显示和方向键导航
+//   - ✅ 按包名搜索、切换开发依赖和对等依赖的显示
+//   - ✅ 高亮当前包的构建阶段和所在的循环依赖
+//   - ❌ 不应包含依赖分析算法
+//   - ❌ 不应包含 CLI 参数处理
+//
+// ============================================================================
+
+use anyhow::Result;
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::style::{Attribute, Color, Print, ResetColor, SetAttribute, SetForegroundColor};
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+use std::collections::{BTreeSet, HashMap};
+use std::io::{self, Write};
+
+use crate::models::package::{DependencyAnalysisResult, DependencyType};
+use crate::{t, tf};
+
+/// 界面最小宽度
+const MIN_WIDTH: u16 = 60;
+
+/// 界面最小高度
+const MIN_HEIGHT: u16 = 12;
+
+/// 列表区域之外占用的行数（标题、分隔线、栏标题、底部信息）
+const CHROME_ROWS: u16 = 7;
+
+/// 浏览器中的包
+struct ExplorerNode {
+    /// 包名
+    name: String,
+    /// 版本
+    version: String,
+    /// 相对路径
+    path: String,
+    /// 构建阶段（从 1 开始，存在循环依赖时为 None）
+    stage: Option<usize>,
+    /// 所在循环依赖的下标
+    cycle: Option<usize>,
+}
+
+/// 浏览器中的边
+struct ExplorerEdge {
+    /// 另一端的包下标
+    target: usize,
+    /// 依赖类型
+    types: Vec<DependencyType>,
+}
+
+/// 当前焦点所在的栏
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pane {
+    /// 包列表
+    Packages,
+    /// 依赖
+    Dependencies,
+    /// 被依赖
+    Dependents,
+}
+
+impl Pane {
+    /// 右侧的栏
+    fn next(self) -> Self {
+        match self {
+            Pane::Packages => Pane::Dependencies,
+            Pane::Dependencies => Pane::Dependents,
+            Pane::Dependents => Pane::Packages,
+        }
+    }
+
+    /// 左侧的栏
+    fn previous(self) -> Self {
+        match self {
+            Pane::Packages => Pane::Dependents,
+            Pane::Dependencies => Pane::Packages,
+            Pane::Dependents => Pane::Dependencies,
+        }
+    }
+}
+
+/// 列表项的显示样式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Style {
+    /// 普通
+    Normal,
+    /// 标题
+    Header,
+    /// 次要信息
+    Dim,
+    /// 循环依赖
+    Cycle,
+}
+
+/// 列表中的一行
+struct ListItem {
+    /// 阶段标记（如 "S2"，循环依赖中的包为 "↻"）
+    tag: String,
+    /// 文本
+    text: String,
+    /// 样式
+    style: Style,
+}
+
+/// 交互式依赖图浏览器
+struct GraphExplorer {
+    /// 所有包（按包名排序）
+    nodes: Vec<ExplorerNode>,
+    /// 每个包的依赖
+    dependencies: Vec<Vec<ExplorerEdge>>,
+    /// 每个包的被依赖
+    dependents: Vec<Vec<ExplorerEdge>>,
+    /// 循环依赖（包名列表）
+    cycles: Vec<Vec<String>>,
+    /// 构建阶段总数
+    total_stages: usize,
+    /// 搜索关键字
+    query: String,
+    /// 是否正在输入搜索关键字
+    searching: bool,
+    /// 是否显示开发依赖
+    show_dev: bool,
+    /// 是否显示对等依赖
+    show_peer: bool,
+    /// 焦点所在的栏
+    focus: Pane,
+    /// 匹配搜索的包下标
+    filtered: Vec<usize>,
+    /// 包列表光标
+    package_cursor: usize,
+    /// 依赖列表光标
+    dependency_cursor: usize,
+    /// 被依赖列表光标
+    dependent_cursor: usize,
+}
+
+/// 进入全屏界面，退出时恢复终端
+struct TerminalGuard;
+
+impl TerminalGuard {
+    /// 开启原始模式并切换到备用屏幕
+    fn enter() -> Result<Self> {
+        terminal::enable_raw_mode()?;
+        let guard = Self;
+        execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+        Ok(guard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), ResetColor, Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// 打开交互式依赖图浏览器，按 q 退出
+pub fn explore(result: &DependencyAnalysisResult) -> Result<()> {
+    if !atty::is(atty::Stream::Stdin) || !atty::is(atty::Stream::Stdout) {
+        anyhow::bail!(t!("graph.requires_tty"));
+    }
+
+    let mut explorer = GraphExplorer::from_analysis(result);
+    let _guard = TerminalGuard::enter()?;
+    let mut stdout = io::stdout();
+
+    loop {
+        explorer.draw(&mut stdout)?;
+        // 其他事件（如窗口大小变化）只需重新绘制
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press && !explorer.handle_key(key) {
+                return Ok(());
+            }
+        }
+    }
+}
+
+impl GraphExplorer {
+    /// 由全工作区分析结果构建浏览器状态
+    fn from_analysis(result: &DependencyAnalysisResult) -> Self {
+        let stages: HashMap<&str, usize> = result
+            .stages
+            .iter()
+            .enumerate()
+            .flat_map(|(index, stage)| stage.iter().map(move |p| (p.name.as_str(), index + 1)))
+            .collect();
+        let cycles: HashMap<&str, usize> = result
+            .circular_dependencies
+            .iter()
+            .enumerate()
+            .flat_map(|(index, cycle)| cycle.iter().map(move |name| (name.as_str(), index)))
+            .collect();
+
+        let mut packages: Vec<_> = result.packages.iter().collect();
+        packages.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let nodes: Vec<ExplorerNode> = packages
+            .iter()
+            .map(|package| ExplorerNode {
+                name: package.name.clone(),
+                version: package.version.clone(),
+                path: package.folder.to_string_lossy().replace('\\', "/"),
+                stage: stages.get(package.name.as_str()).copied(),
+                cycle: cycles.get(package.name.as_str()).copied(),
+            })
+            .collect();
+
+        let index: HashMap<&str, usize> =
+            nodes.iter().enumerate().map(|(i, node)| (node.name.as_str(), i)).collect();
+        let mut dependencies: Vec<Vec<ExplorerEdge>> = nodes.iter().map(|_| Vec::new()).collect();
+        let mut dependents: Vec<Vec<ExplorerEdge>> = nodes.iter().map(|_| Vec::new()).collect();
+        for (from, package) in packages.iter().enumerate() {
+            let deps: BTreeSet<&String> = package.workspace_dependencies.iter().collect();
+            for dep in deps {
+                let Some(&to) = index.get(dep.as_str()) else {
+                    continue;
+                };
+                let types = package.dependency_types_of(dep).to_vec();
+                dependents[to].push(ExplorerEdge { target: from, types: types.clone() });
+                dependencies[from].push(ExplorerEdge { target: to, types });
+            }
+        }
+        for edges in dependencies.iter_mut().chain(dependents.iter_mut()) {
+            edges.sort_by(|a, b| nodes[a.target].name.cmp(&nodes[b.target].name));
+        }
+
+        let filtered = (0..nodes.len()).collect();
+        Self {
+            nodes,
+            dependencies,
+            dependents,
+            cycles: result.circular_dependencies.clone(),
+            total_stages: result.stages.len(),
+            query: String::new(),
+            searching: false,
+            show_dev: true,
+            show_peer: true,
+            focus: Pane::Packages,
+            filtered,
+            package_cursor: 0,
+            dependency_cursor: 0,
+            dependent_cursor: 0,
+        }
+    }
+
+    /// 当前选中的包
+    fn selected(&self) -> Option<usize> {
+        self.filtered.get(self.package_cursor).copied()
+    }
+
+    /// 按开关过滤后的边（生产依赖和可选依赖始终显示）
+    fn visible<'a>(&self, edges: &'a [ExplorerEdge]) -> Vec<&'a ExplorerEdge> {
+        edges
+            .iter()
+            .filter(|edge| {
+                edge.types.is_empty()
+                    || edge.types.iter().any(|kind| match kind {
+                        DependencyType::Dev => self.show_dev,
+                        DependencyType::Peer => self.show_peer,
+                        DependencyType::Prod | DependencyType::Optional => true,
+                    })
+            })
+            .collect()
+    }
+
+    /// 当前包可见的依赖
+    fn visible_dependencies(&self) -> Vec<&ExplorerEdge> {
+        match self.selected() {
+            Some(selected) => self.visible(&self.dependencies[selected]),
+            None => Vec::new(),
+        }
+    }
+
+    /// 当前包可见的被依赖
+    fn visible_dependents(&self) -> Vec<&ExplorerEdge> {
+        match self.selected() {
+            Some(selected) => self.visible(&self.dependents[selected]),
+            None => Vec::new(),
+        }
+    }
+
+    /// 处理按键，返回 false 表示退出
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            return false;
+        }
+
+        if self.searching {
+            match key.code {
+                KeyCode::Enter => self.searching = false,
+                KeyCode::Esc => {
+                    self.searching = false;
+                    self.set_query(String::new());
+                }
+                KeyCode::Backspace => {
+                    let mut query = self.query.clone();
+                    query.pop();
+                    self.set_query(query);
+                }
+                KeyCode::Char(c) => {
+                    let query = format!("{}{}", self.query, c);
+                    self.set_query(query);
+                }
+                KeyCode::Up | KeyCode::Down => self.move_cursor(key.code == KeyCode::Up),
+                _ => {}
+            }
+            return true;
+        }
+
+        match key.code {
+            KeyCode::Char('q') => return false,
+            KeyCode::Esc if self.query.is_empty() => return false,
+            KeyCode::Esc => self.set_query(String::new()),
+            KeyCode::Char('/') => {
+                self.searching = true;
+                self.focus = Pane::Packages;
+            }
+            KeyCode::Char('d') => {
+                self.show_dev = !self.show_dev;
+                self.reset_edge_cursors();
+            }
+            KeyCode::Char('p') => {
+                self.show_peer = !self.show_peer;
+                self.reset_edge_cursors();
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.move_cursor(true),
+            KeyCode::Down | KeyCode::Char('j') => self.move_cursor(false),
+            KeyCode::Left | KeyCode::Char('h') | KeyCode::BackTab => {
+                self.focus = self.focus.previous()
+            }
+            KeyCode::Right | KeyCode::Char('l') | KeyCode::Tab => self.focus = self.focus.next(),
+            KeyCode::Enter => self.jump(),
+            _ => {}
+        }
+        true
+    }
+
+    /// 更新搜索关键字并重新过滤包列表
+    fn set_query(&mut self, query: String) {
+        let current = self.selected();
+        self.query = query;
+        let needle = self.query.to_lowercase();
+        self.filtered = (0..self.nodes.len())
+            .filter(|&i| self.nodes[i].name.to_lowercase().contains(&needle))
+            .collect();
+        self.package_cursor =
+            current.and_then(|c| self.filtered.iter().position(|&i| i == c)).unwrap_or(0);
+        self.reset_edge_cursors();
+    }
+
+    /// 在焦点所在的栏中移动光标
+    fn move_cursor(&mut self, up: bool) {
+        let (cursor, len) = match self.focus {
+            Pane::Packages => (&mut self.package_cursor, self.filtered.len()),
+            Pane::Dependencies => {
+                let len = self.visible_dependencies().len();
+                (&mut self.dependency_cursor, len)
+            }
+            Pane::Dependents => {
+                let len = self.visible_dependents().len();
+                (&mut self.dependent_cursor, len)
+            }
+        };
+        if up {
+            *cursor = cursor.saturating_sub(1);
+        } else if *cursor + 1 < len {
+            *cursor += 1;
+        }
+        if self.focus == Pane::Packages {
+            self.reset_edge_cursors();
+        }
+    }
+
+    /// 跳转到依赖或被依赖栏中选中的包
+    fn jump(&mut self) {
+        let target = match self.focus {
+            Pane::Packages => None,
+            Pane::Dependencies => {
+                self.visible_dependencies().get(self.dependency_cursor).map(|edge| edge.target)
+            }
+            Pane::Dependents => {
+                self.visible_dependents().get(self.dependent_cursor).map(|edge| edge.target)
+            }
+        };
+        let Some(target) = target else {
+            return;
+        };
+
+        if !self.filtered.contains(&target) {
+            self.set_query(String::new());
+        }
+        self.package_cursor = self.filtered.iter().position(|&i| i == target).unwrap_or(0);
+        self.reset_edge_cursors();
+    }
+
+    /// 选中的包变化后重置右侧两栏的光标
+    fn reset_edge_cursors(&mut self) {
+        self.dependency_cursor = 0;
+        self.dependent_cursor = 0;
+    }
+
+    /// 包的阶段标记
+    fn tag(&self, index: usize) -> String {
+        let node = &self.nodes[index];
+        match (node.cycle, node.stage) {
+            (Some(_), _) => "↻".to_string(),
+            (None, Some(stage)) => format!("S{}", stage),
+            (None, None) => String::new(),
+        }
+    }
+
+    /// 边的列表项（非生产依赖时附带依赖类型）
+    fn edge_item(&self, edge: &ExplorerEdge, selected: usize) -> ListItem {
+        let node = &self.nodes[edge.target];
+        let mut text = node.name.clone();
+        if edge.types.iter().any(|kind| *kind != DependencyType::Prod) {
+            let types: Vec<&str> = edge.types.iter().map(|kind| kind.as_str()).collect();
+            text = format!("{} ({})", text, types.join(", "));
+        }
+        let in_cycle = node.cycle.is_some() && node.cycle == self.nodes[selected].cycle;
+        ListItem {
+            tag: self.tag(edge.target),
+            text,
+            style: if in_cycle { Style::Cycle } else { Style::Normal },
+        }
+    }
+
+    /// 绘制整个界面
+    fn draw(&self, out: &mut impl Write) -> Result<()> {
+        let (width, height) = terminal::size()?;
+        queue!(out, ResetColor, Clear(ClearType::All))?;
+
+        if width < MIN_WIDTH || height < MIN_HEIGHT {
+            draw_text(out, 0, 0, width, &t!("graph.too_small"), Style::Normal)?;
+            out.flush()?;
+            return Ok(());
+        }
+
+        // 标题栏
+        let on_off = |on: bool| if on { t!("graph.on") } else { t!("graph.off") };
+        let mut title = format!(
+            "{}  {}  {}",
+            t!("graph.title"),
+            tf!("graph.toggle_dev", on_off(self.show_dev)),
+            tf!("graph.toggle_peer", on_off(self.show_peer))
+        );
+        if self.searching || !self.query.is_empty() {
+            let cursor = if self.searching { "_" } else { "" };
+            title = format!("{}  {}{}", title, tf!("graph.search", self.query), cursor);
+        }
+        draw_text(out, 0, 0, width, &title, Style::Header)?;
+        draw_text(out, 0, 1, width, &"─".repeat(width as usize), Style::Dim)?;
+
+        // 三栏列表
+        let column = width / 3;
+        let list_height = height - CHROME_ROWS;
+        let packages: Vec<ListItem> = self
+            .filtered
+            .iter()
+            .map(|&i| ListItem {
+                tag: self.tag(i),
+                text: self.nodes[i].name.clone(),
+                style: if self.nodes[i].cycle.is_some() { Style::Cycle } else { Style::Normal },
+            })
+            .collect();
+        let (dependencies, dependents) = match self.selected() {
+            Some(selected) => (
+                self.visible_dependencies().iter().map(|e| self.edge_item(e, selected)).collect(),
+                self.visible_dependents().iter().map(|e| self.edge_item(e, selected)).collect(),
+            ),
+            None => (Vec::new(), Vec::new()),
+        };
+
+        let panes = [
+            (
+                Pane::Packages,
+                0,
+                column,
+                tf!("graph.packages", packages.len()),
+                &packages,
+                self.package_cursor,
+            ),
+            (
+                Pane::Dependencies,
+                column,
+                column,
+                tf!("graph.dependencies", dependencies.len()),
+                &dependencies,
+                self.dependency_cursor,
+            ),
+            (
+                Pane::Dependents,
+                column * 2,
+                width - column * 2,
+                tf!("graph.dependents", dependents.len()),
+                &dependents,
+                self.dependent_cursor,
+            ),
+        ];
+        for (pane, x, pane_width, header, items, cursor) in panes {
+            let style = if pane == self.focus { Style::Header } else { Style::Dim };
+            draw_text(out, x, 2, pane_width - 1, &header, style)?;
+            draw_list(out, x, 3, pane_width - 1, list_height, items, cursor, pane == self.focus)?;
+        }
+
+        // 当前包的详情
+        let footer = height - 4;
+        draw_text(out, 0, footer, width, &"─".repeat(width as usize), Style::Dim)?;
+        if let Some(selected) = self.selected() {
+            let node = &self.nodes[selected];
+            let stage = match node.stage {
+                Some(stage) => tf!("graph.stage", stage, self.total_stages),
+                None => t!("graph.no_stage"),
+            };
+            let detail = format!("{}@{}  {}  {}", node.name, node.version, stage, node.path);
+            draw_text(out, 0, footer + 1, width, &detail, Style::Header)?;
+
+            match node.cycle {
+                Some(cycle) => {
+                    let members = self.cycles[cycle].join(" → ");
+                    draw_text(
+                        out,
+                        0,
+                        footer + 2,
+                        width,
+                        &tf!("graph.cycle", members),
+                        Style::Cycle,
+                    )?
+                }
+                None => draw_text(out, 0, footer + 2, width, &t!("graph.no_cycle"), Style::Dim)?,
+            }
+        } else {
+            draw_text(out, 0, footer + 1, width, &t!("graph.no_match"), Style::Dim)?;
+        }
+
+        let help = if self.searching { t!("graph.search_help") } else { t!("graph.help") };
+        draw_text(out, 0, footer + 3, width, &help, Style::Dim)?;
+        out.flush()?;
+        Ok(())
+    }
+}
+
+/// 绘制带滚动的列表，光标所在行反显（仅焦点栏）
+#[allow(clippy::too_many_arguments)]
+fn draw_list(
+    out: &mut impl Write,
+    x: u16,
+    y: u16,
+    width: u16,
+    height: u16,
+    items: &[ListItem],
+    cursor: usize,
+    focused: bool,
+) -> Result<()> {
+    if items.is_empty() {
+        return draw_text(out, x, y, width, &t!("graph.none"), Style::Dim);
+    }
+
+    let height = height as usize;
+    let offset = if cursor >= height { cursor + 1 - height } else { 0 };
+    for (row, (index, item)) in items.iter().enumerate().skip(offset).take(height).enumerate() {
+        let text = format!("{:<3}{}", item.tag, item.text);
+        let row = y + row as u16;
+        if index == cursor && focused {
+            queue!(out, SetAttribute(Attribute::Reverse))?;
+            draw_text(out, x, row, width, &pad(&text, width as usize), item.style)?;
+            queue!(out, SetAttribute(Attribute::Reset))?;
+        } else {
+            let text = if index == cursor { format!("{} ◂", text) } else { text };
+            draw_text(out, x, row, width, &text, item.style)?;
+        }
+    }
+    Ok(())
+}
+
+/// 在指定位置绘制一行文本（超出宽度时截断）
+fn draw_text(
+    out: &mut impl Write,
+    x: u16,
+    y: u16,
+    width: u16,
+    text: &str,
+    style: Style,
+) -> Result<()> {
+    let color = match style {
+        Style::Normal => None,
+        Style::Header => Some(Color::Cyan),
+        Style::Dim => Some(Color::DarkGrey),
+        Style::Cycle => Some(Color::Red),
+    };
+    queue!(out, MoveTo(x, y))?;
+    if let Some(color) = color {
+        queue!(out, SetForegroundColor(color))?;
+    }
+    queue!(out, Print(truncate(text, width as usize)), ResetColor)?;
+    Ok(())
+}
+
+/// 按显示宽度截断文本
+fn truncate(text: &str, width: usize) -> String {
+    let mut used = 0;
+    let mut result = String::new();
+    for c in text.chars() {
+        let w = char_width(c);
+        if used + w > width {
+            break;
+        }
+        used += w;
+        result.push(c);
+    }
+    result
+}
+
+/// 用空格补齐到指定显示宽度
+fn pad(text: &str, width: usize) -> String {
+    let used: usize = text.chars().map(char_width).sum();
+    format!("{}{}", text, " ".repeat(width.saturating_sub(used)))
+}
+
+/// 字符的显示宽度（中日韩文字和全角符号占两列）
+fn char_width(c: char) -> usize {
+    match c as u32 {
+        0x1100..=0x115F
+        | 0x2E80..=0xA4CF
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFE30..=0xFE4F
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6 => 2,
+        _ => 1,
+    }
+}
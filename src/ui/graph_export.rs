@@ -0,0 +1,432 @@
+// ============================================================================
+// MonoX - 依赖图导出
+// ============================================================================
+//
+// 文件: src/ui/graph_export.rs
+// 职责: 将依赖分析结果导出为 DOT、Mermaid、GraphML 和 Cytoscape JSON
+// 边界:
+//   - ✅ 按包范围裁剪节点（目标包及其依赖链）
+//   - ✅ 节点版本和路径元数据、边的依赖类型标注
+//   - ✅ 循环依赖高亮和构建阶段分组
+//   - ❌ 不应包含依赖分析算法
+//   - ❌ 不应包含 CLI 参数处理
+//
+// ============================================================================
+
+use anyhow::Result;
+use serde_json::json;
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+
+use crate::models::package::{DependencyAnalysisResult, DependencyType};
+use crate::tf;
+
+/// 依赖图导出格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphFormat {
+    /// Graphviz DOT
+    Dot,
+    /// Mermaid flowchart
+    Mermaid,
+    /// GraphML
+    GraphMl,
+    /// Cytoscape.js elements JSON
+    CytoscapeJson,
+}
+
+impl GraphFormat {
+    /// 解析导出格式，不是图格式时返回 None
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "dot" => Some(GraphFormat::Dot),
+            "mermaid" => Some(GraphFormat::Mermaid),
+            "graphml" => Some(GraphFormat::GraphMl),
+            "cytoscape-json" => Some(GraphFormat::CytoscapeJson),
+            _ => None,
+        }
+    }
+}
+
+/// 图节点（工作区包）
+#[derive(Debug, Clone)]
+struct GraphNode {
+    /// 包名
+    name: String,
+    /// 版本
+    version: String,
+    /// 相对路径
+    path: String,
+    /// 构建阶段（从 1 开始，存在循环依赖时为 None）
+    stage: Option<usize>,
+    /// 是否在循环依赖中
+    in_cycle: bool,
+}
+
+/// 图的边（依赖方 -> 被依赖的包）
+#[derive(Debug, Clone)]
+struct GraphEdge {
+    /// 依赖方下标
+    from: usize,
+    /// 被依赖的包下标
+    to: usize,
+    /// 依赖类型
+    types: Vec<DependencyType>,
+    /// 两端是否在同一个循环依赖中
+    in_cycle: bool,
+}
+
+impl GraphEdge {
+    /// 依赖类型标签（如 "prod, peer"）
+    fn label(&self) -> String {
+        self.types.iter().map(|t| t.as_str()).collect::<Vec<_>>().join(", ")
+    }
+
+    /// 是否只是开发依赖
+    fn is_dev_only(&self) -> bool {
+        !self.types.is_empty() && self.types.iter().all(|t| *t == DependencyType::Dev)
+    }
+}
+
+/// 用于导出的依赖图
+pub struct DependencyGraphView {
+    /// 节点（按构建阶段和包名排序）
+    nodes: Vec<GraphNode>,
+    /// 边
+    edges: Vec<GraphEdge>,
+}
+
+impl DependencyGraphView {
+    /// 由全工作区分析结果构建依赖图，scope 不为空时只保留这些包及其依赖链
+    pub fn from_analysis(result: &DependencyAnalysisResult, scope: &[String]) -> Result<Self> {
+        let packages: HashMap<&str, _> =
+            result.packages.iter().map(|p| (p.name.as_str(), p)).collect();
+
+        // 计算范围：目标包及其传递依赖
+        let included: BTreeSet<&str> = if scope.is_empty() {
+            packages.keys().copied().collect()
+        } else {
+            let mut included = BTreeSet::new();
+            let mut queue = VecDeque::new();
+            for name in scope {
+                let Some((&name, _)) = packages.get_key_value(name.as_str()) else {
+                    anyhow::bail!(tf!("error.package_not_found", name));
+                };
+                queue.push_back(name);
+            }
+            while let Some(name) = queue.pop_front() {
+                if included.insert(name) {
+                    for dep in &packages[name].workspace_dependencies {
+                        if let Some((&dep, _)) = packages.get_key_value(dep.as_str()) {
+                            queue.push_back(dep);
+                        }
+                    }
+                }
+            }
+            included
+        };
+
+        let stages: HashMap<&str, usize> = result
+            .stages
+            .iter()
+            .enumerate()
+            .flat_map(|(index, stage)| stage.iter().map(move |p| (p.name.as_str(), index + 1)))
+            .collect();
+        let cycles: HashMap<&str, usize> = result
+            .circular_dependencies
+            .iter()
+            .enumerate()
+            .flat_map(|(index, cycle)| cycle.iter().map(move |name| (name.as_str(), index)))
+            .collect();
+
+        let mut nodes: Vec<GraphNode> = included
+            .iter()
+            .map(|&name| {
+                let package = packages[name];
+                GraphNode {
+                    name: name.to_string(),
+                    version: package.version.clone(),
+                    path: package.folder.to_string_lossy().replace('\\', "/"),
+                    stage: stages.get(name).copied(),
+                    in_cycle: cycles.contains_key(name),
+                }
+            })
+            .collect();
+        nodes.sort_by(|a, b| {
+            a.stage
+                .unwrap_or(usize::MAX)
+                .cmp(&b.stage.unwrap_or(usize::MAX))
+                .then(a.name.cmp(&b.name))
+        });
+
+        let index: HashMap<&str, usize> =
+            nodes.iter().enumerate().map(|(i, node)| (node.name.as_str(), i)).collect();
+        let mut edges = Vec::new();
+        for (from, node) in nodes.iter().enumerate() {
+            let package = packages[node.name.as_str()];
+            let deps: BTreeSet<&String> = package.workspace_dependencies.iter().collect();
+            for dep in deps {
+                let Some(&to) = index.get(dep.as_str()) else {
+                    continue;
+                };
+                let in_cycle = matches!(
+                    (cycles.get(node.name.as_str()), cycles.get(dep.as_str())),
+                    (Some(a), Some(b)) if a == b
+                );
+                edges.push(GraphEdge {
+                    from,
+                    to,
+                    types: package.dependency_types_of(dep).to_vec(),
+                    in_cycle,
+                });
+            }
+        }
+
+        Ok(Self { nodes, edges })
+    }
+
+    /// 按格式渲染
+    pub fn render(&self, format: GraphFormat) -> String {
+        match format {
+            GraphFormat::Dot => self.render_dot(),
+            GraphFormat::Mermaid => self.render_mermaid(),
+            GraphFormat::GraphMl => self.render_graphml(),
+            GraphFormat::CytoscapeJson => self.render_cytoscape(),
+        }
+    }
+
+    /// 按构建阶段分组的节点下标（None 为未分配阶段的节点）
+    fn stage_groups(&self) -> BTreeMap<Option<usize>, Vec<usize>> {
+        let mut groups: BTreeMap<Option<usize>, Vec<usize>> = BTreeMap::new();
+        for (i, node) in self.nodes.iter().enumerate() {
+            groups.entry(node.stage).or_default().push(i);
+        }
+        groups
+    }
+
+    /// Graphviz DOT
+    fn render_dot(&self) -> String {
+        let mut out = String::from("digraph dependencies {\n");
+        out.push_str("  rankdir=LR;\n");
+        out.push_str("  node [shape=box, style=rounded, fontname=\"Helvetica\"];\n");
+        out.push_str("  edge [fontname=\"Helvetica\", fontsize=10];\n");
+
+        for (stage, members) in self.stage_groups() {
+            let indent = if stage.is_some() { "    " } else { "  " };
+            if let Some(stage) = stage {
+                out.push_str(&format!("\n  subgraph cluster_stage_{} {{\n", stage));
+                out.push_str(&format!("    label=\"Stage {}\";\n", stage));
+                out.push_str("    style=dashed;\n");
+            } else {
+                out.push('\n');
+            }
+            for &i in &members {
+                let node = &self.nodes[i];
+                let mut attrs = vec![
+                    format!("label=\"{}\\n{}\"", dot_escape(&node.name), dot_escape(&node.version)),
+                    format!("tooltip=\"{}\"", dot_escape(&node.path)),
+                ];
+                if node.in_cycle {
+                    attrs.push("color=red".to_string());
+                    attrs.push("fontcolor=red".to_string());
+                }
+                out.push_str(&format!("{}n{} [{}];\n", indent, i, attrs.join(", ")));
+            }
+            if stage.is_some() {
+                out.push_str("  }\n");
+            }
+        }
+
+        out.push('\n');
+        for edge in &self.edges {
+            let mut attrs = vec![format!("label=\"{}\"", dot_escape(&edge.label()))];
+            if edge.is_dev_only() {
+                attrs.push("style=dashed".to_string());
+            }
+            if edge.in_cycle {
+                attrs.push("color=red".to_string());
+                attrs.push("penwidth=2".to_string());
+            }
+            out.push_str(&format!("  n{} -> n{} [{}];\n", edge.from, edge.to, attrs.join(", ")));
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// Mermaid flowchart
+    fn render_mermaid(&self) -> String {
+        let mut out = String::from("flowchart LR\n");
+
+        for (stage, members) in self.stage_groups() {
+            let indent = if stage.is_some() { "    " } else { "  " };
+            if let Some(stage) = stage {
+                out.push_str(&format!("  subgraph stage_{}[\"Stage {}\"]\n", stage, stage));
+            }
+            for &i in &members {
+                let node = &self.nodes[i];
+                out.push_str(&format!(
+                    "{}n{}[\"{}<br/>{}\"]\n",
+                    indent,
+                    i,
+                    mermaid_escape(&node.name),
+                    mermaid_escape(&node.version)
+                ));
+            }
+            if stage.is_some() {
+                out.push_str("  end\n");
+            }
+        }
+
+        for edge in &self.edges {
+            let arrow = if edge.is_dev_only() { "-.->" } else { "-->" };
+            out.push_str(&format!(
+                "  n{} {}|{}| n{}\n",
+                edge.from,
+                arrow,
+                mermaid_escape(&edge.label()),
+                edge.to
+            ));
+        }
+
+        // 循环依赖高亮
+        let cycle_nodes: Vec<String> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| node.in_cycle)
+            .map(|(i, _)| format!("n{}", i))
+            .collect();
+        if !cycle_nodes.is_empty() {
+            out.push_str("  classDef cycle fill:#fdd,stroke:#c00,color:#900\n");
+            out.push_str(&format!("  class {} cycle\n", cycle_nodes.join(",")));
+        }
+        let cycle_edges: Vec<String> = self
+            .edges
+            .iter()
+            .enumerate()
+            .filter(|(_, edge)| edge.in_cycle)
+            .map(|(i, _)| i.to_string())
+            .collect();
+        if !cycle_edges.is_empty() {
+            out.push_str(&format!(
+                "  linkStyle {} stroke:#c00,stroke-width:2px\n",
+                cycle_edges.join(",")
+            ));
+        }
+        out
+    }
+
+    /// GraphML
+    fn render_graphml(&self) -> String {
+        let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        out.push_str("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n");
+        out.push_str("  <key id=\"name\" for=\"node\" attr.name=\"name\" attr.type=\"string\"/>\n");
+        out.push_str(
+            "  <key id=\"version\" for=\"node\" attr.name=\"version\" attr.type=\"string\"/>\n",
+        );
+        out.push_str("  <key id=\"path\" for=\"node\" attr.name=\"path\" attr.type=\"string\"/>\n");
+        out.push_str("  <key id=\"stage\" for=\"node\" attr.name=\"stage\" attr.type=\"int\"/>\n");
+        out.push_str(
+            "  <key id=\"node_cycle\" for=\"node\" attr.name=\"in_cycle\" attr.type=\"boolean\"/>\n",
+        );
+        out.push_str(
+            "  <key id=\"types\" for=\"edge\" attr.name=\"types\" attr.type=\"string\"/>\n",
+        );
+        out.push_str(
+            "  <key id=\"edge_cycle\" for=\"edge\" attr.name=\"in_cycle\" attr.type=\"boolean\"/>\n",
+        );
+        out.push_str("  <graph id=\"dependencies\" edgedefault=\"directed\">\n");
+
+        for (i, node) in self.nodes.iter().enumerate() {
+            out.push_str(&format!("    <node id=\"n{}\">\n", i));
+            out.push_str(&format!("      <data key=\"name\">{}</data>\n", xml_escape(&node.name)));
+            out.push_str(&format!(
+                "      <data key=\"version\">{}</data>\n",
+                xml_escape(&node.version)
+            ));
+            out.push_str(&format!("      <data key=\"path\">{}</data>\n", xml_escape(&node.path)));
+            if let Some(stage) = node.stage {
+                out.push_str(&format!("      <data key=\"stage\">{}</data>\n", stage));
+            }
+            out.push_str(&format!("      <data key=\"node_cycle\">{}</data>\n", node.in_cycle));
+            out.push_str("    </node>\n");
+        }
+        for (i, edge) in self.edges.iter().enumerate() {
+            out.push_str(&format!(
+                "    <edge id=\"e{}\" source=\"n{}\" target=\"n{}\">\n",
+                i, edge.from, edge.to
+            ));
+            out.push_str(&format!(
+                "      <data key=\"types\">{}</data>\n",
+                xml_escape(&edge.label())
+            ));
+            out.push_str(&format!("      <data key=\"edge_cycle\">{}</data>\n", edge.in_cycle));
+            out.push_str("    </edge>\n");
+        }
+
+        out.push_str("  </graph>\n</graphml>\n");
+        out
+    }
+
+    /// Cytoscape.js elements JSON（构建阶段作为复合父节点）
+    fn render_cytoscape(&self) -> String {
+        let mut nodes: Vec<serde_json::Value> = self
+            .stage_groups()
+            .keys()
+            .flatten()
+            .map(|stage| {
+                json!({ "data": { "id": format!("stage-{}", stage), "label": format!("Stage {}", stage) } })
+            })
+            .collect();
+        nodes.extend(self.nodes.iter().map(|node| {
+            let mut data = json!({
+                "id": node.name,
+                "label": node.name,
+                "version": node.version,
+                "path": node.path,
+                "in_cycle": node.in_cycle,
+            });
+            if let Some(stage) = node.stage {
+                data["stage"] = json!(stage);
+                data["parent"] = json!(format!("stage-{}", stage));
+            }
+            json!({ "data": data, "classes": if node.in_cycle { "cycle" } else { "" } })
+        }));
+
+        let edges: Vec<serde_json::Value> = self
+            .edges
+            .iter()
+            .map(|edge| {
+                let source = &self.nodes[edge.from].name;
+                let target = &self.nodes[edge.to].name;
+                json!({
+                    "data": {
+                        "id": format!("{}->{}", source, target),
+                        "source": source,
+                        "target": target,
+                        "types": edge.types,
+                        "label": edge.label(),
+                        "in_cycle": edge.in_cycle,
+                    },
+                    "classes": if edge.in_cycle { "cycle" } else { "" },
+                })
+            })
+            .collect();
+
+        let elements = json!({ "elements": { "nodes": nodes, "edges": edges } });
+        serde_json::to_string_pretty(&elements).unwrap_or_default() + "\n"
+    }
+}
+
+/// DOT 字符串转义
+fn dot_escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Mermaid 标签转义
+fn mermaid_escape(value: &str) -> String {
+    value.replace('"', "#quot;").replace('|', "#124;")
+}
+
+/// XML 文本转义
+fn xml_escape(value: &str) -> String {
+    value.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
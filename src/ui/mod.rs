@@ -15,6 +15,9 @@
 //
 // ============================================================================
 
+pub mod graph_explorer;
+pub mod graph_export;
+pub mod picker;
 pub mod runner;
 pub mod spinner;
 pub mod summary;
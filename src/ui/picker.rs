@@ -0,0 +1,78 @@
+// ============================================================================
+// MonoX - 多选列表组件
+// ============================================================================
+//
+// 文件: src/ui/picker.rs
+// 职责: 在终端中展示编号列表并读取用户的多选输入
+// 边界:
+//   - ✅ 编号列表显示
+//   - ✅ 选择输入解析（编号、范围、全选）
+//   - ✅ 无效输入重新提示
+//   - ❌ 不应包含业务逻辑
+//   - ❌ 不应包含文件操作
+//
+// ============================================================================
+
+use anyhow::Result;
+use std::io::{self, Write};
+
+use crate::utils::colors::Colors;
+use crate::utils::logger::Logger;
+use crate::{t, tf};
+
+/// 显示编号列表并返回用户选择的索引（输入为空时返回空列表）
+pub fn multi_select(title: &str, items: &[String]) -> Result<Vec<usize>> {
+    if !atty::is(atty::Stream::Stdin) {
+        anyhow::bail!(t!("picker.requires_tty"));
+    }
+
+    Logger::info(title);
+    let width = items.len().to_string().len();
+    for (index, item) in items.iter().enumerate() {
+        Logger::info(format!("  {} {}", Colors::info(&format!("[{:>width$}]", index + 1)), item));
+    }
+
+    loop {
+        print!("{} ", t!("picker.prompt"));
+        io::stdout().flush()?;
+
+        let mut input = String::new();
+        if io::stdin().read_line(&mut input)? == 0 {
+            return Ok(Vec::new());
+        }
+
+        match parse_selection(input.trim(), items.len()) {
+            Some(selected) => return Ok(selected),
+            None => Logger::warn(tf!("picker.invalid_selection", input.trim())),
+        }
+    }
+}
+
+/// 解析选择输入：逗号或空格分隔的编号和范围（如 1,3-5），a/all 表示全选
+fn parse_selection(input: &str, count: usize) -> Option<Vec<usize>> {
+    if input.is_empty() {
+        return Some(Vec::new());
+    }
+    if input.eq_ignore_ascii_case("a") || input.eq_ignore_ascii_case("all") {
+        return Some((0..count).collect());
+    }
+
+    let mut selected = Vec::new();
+    for token in input.split([',', ' ']).filter(|token| !token.is_empty()) {
+        let (start, end) = match token.split_once('-') {
+            Some((start, end)) => (start.trim().parse().ok()?, end.trim().parse().ok()?),
+            None => {
+                let number: usize = token.parse().ok()?;
+                (number, number)
+            }
+        };
+        if start == 0 || start > end || end > count {
+            return None;
+        }
+        selected.extend(start - 1..end);
+    }
+
+    selected.sort_unstable();
+    selected.dedup();
+    Some(selected)
+}
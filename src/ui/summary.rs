@@ -9,6 +9,7 @@
 //   - ✅ 统计信息格式化输出
 //   - ✅ 国际化文本支持
 //   - ✅ 检查结果表格显示
+//   - ✅ 试运行执行计划显示
 //   - ❌ 不应包含具体业务逻辑
 //   - ❌ 不应包含任务执行逻辑
 //   - ❌ 不应包含文件操作
@@ -17,13 +18,22 @@
 // ============================================================================
 
 use anyhow::Result;
-use std::collections::{BTreeMap, HashMap};
+use std::collections::BTreeMap;
 use std::io::{self, Write};
 
+use crate::models::boundary::{BoundaryViolation, BoundaryViolationKind};
 use crate::models::config::Config;
+use crate::models::deps_usage::{DependencyUsageIssue, DependencyUsageKind};
+use crate::models::history::HistoryReport;
+use crate::models::lockfile::DuplicateReport;
+use crate::models::manifest::{ManifestFix, ManifestIssue, RuleSeverity};
+use crate::models::package::{CircularDependency, ElementaryCycle};
+use crate::models::plan::{ExecutionPlan, SelectionReason};
+use crate::models::task::SkipReason;
 use crate::utils::colors::Colors;
 use crate::utils::constants::icons;
 use crate::utils::logger::Logger;
+use crate::utils::performance::{format_bytes, format_duration_ms, format_timestamp};
 use crate::utils::styles::TextStyles;
 use crate::{t, tf};
 
@@ -36,10 +46,18 @@ use crate::{t, tf};
 pub struct OutdatedDependency {
     /// 包名
     pub name: String,
+    /// 声明的版本规范
+    pub spec: String,
     /// 当前版本
     pub current: String,
+    /// 规范范围内可用的最高版本
+    pub wanted: Option<String>,
     /// 最新版本
     pub latest: String,
+    /// 目标发布标签
+    pub dist_tag: String,
+    /// 更新级别 (in_range, patch, minor, major, prerelease)
+    pub level: String,
     /// 所属包
     pub package: String,
     /// 依赖类型 (dependencies, devDependencies, etc.)
@@ -53,8 +71,31 @@ pub struct VersionConflict {
     pub name: String,
     /// 冲突的版本使用情况
     pub conflicts: Vec<ConflictUsage>,
+    /// 冲突类型 (incompatible, compatible)
+    pub kind: String,
     /// 推荐的统一版本
     pub recommended_version: String,
+    /// 推荐版本满足的使用数
+    pub satisfied_usages: usize,
+}
+
+/// 工作区内部依赖写法问题
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ProtocolIssue {
+    /// 声明依赖的包名
+    pub package: String,
+    /// 依赖的工作区包名
+    pub dependency: String,
+    /// 依赖类型
+    pub dep_type: String,
+    /// 声明的版本规范
+    pub spec: String,
+    /// 工作区包的本地版本
+    pub local_version: String,
+    /// 问题类型 (missing_protocol, unsupported_protocol, version_mismatch)
+    pub kind: String,
+    /// 推荐的写法
+    pub suggested: String,
 }
 
 /// 版本冲突使用情况
@@ -64,7 +105,7 @@ pub struct ConflictUsage {
     pub package: String,
     /// 版本规范
     pub version_spec: String,
-    /// 解析后的版本
+    /// 满足版本规范的最低版本
     pub resolved_version: String,
     /// 依赖类型
     pub dep_type: String,
@@ -98,7 +139,7 @@ pub fn render_execution_summary(
         summary_lines.push(format!(
             "{} {}",
             icons::TIME,
-            tf!("executor.summary_duration", duration as f64 / 1000.0)
+            tf!("executor.summary_duration", format_duration_ms(duration))
         ));
     }
 
@@ -116,20 +157,30 @@ pub fn render_execution_summary(
 
 /// 打印循环依赖表格
 pub fn print_circular_dependencies_table(
-    circular_dependencies: &[Vec<String>],
+    circular_dependencies: &[CircularDependency],
     detail: bool,
 ) -> Result<()> {
     Logger::info("");
     Logger::info(t!("check.circular.details"));
     Logger::info("───────────────────────────────────────");
 
-    for (index, cycle) in circular_dependencies.iter().enumerate() {
-        Logger::info(tf!("check.circular.cycle_header", index + 1));
+    for (index, circular) in circular_dependencies.iter().enumerate() {
+        Logger::info(tf!("check.circular.cycle_header", index + 1, circular.packages.join(", ")));
 
-        if detail {
-            print_detailed_cycle(cycle);
-        } else {
-            print_simple_cycle(cycle);
+        for cycle in &circular.cycles {
+            if detail {
+                print_detailed_cycle(cycle);
+            } else {
+                Logger::info(tf!("check.circular.cycle_simple", cycle.path()));
+            }
+        }
+        if circular.truncated {
+            Logger::info(tf!("check.circular.truncated", circular.cycles.len()));
+        }
+
+        Logger::info(tf!("check.circular.break_header", circular.break_edges.len()));
+        for edge in &circular.break_edges {
+            Logger::info(tf!("check.circular.break_edge", edge.from, edge.to, edge.type_label()));
         }
         Logger::info("");
     }
@@ -138,20 +189,20 @@ pub fn print_circular_dependencies_table(
     Ok(())
 }
 
-/// 打印详细循环路径
-fn print_detailed_cycle(cycle: &[String]) {
-    for (i, package) in cycle.iter().enumerate() {
-        let next_package = &cycle[(i + 1) % cycle.len()];
-        Logger::info(tf!("check.circular.cycle_detail", icons::ARROW, package, next_package));
+/// 打印详细循环路径（每条依赖一行）
+fn print_detailed_cycle(cycle: &ElementaryCycle) {
+    Logger::info(tf!("check.circular.cycle_simple", cycle.path()));
+    for edge in &cycle.edges {
+        Logger::info(tf!(
+            "check.circular.cycle_detail",
+            icons::ARROW,
+            edge.from,
+            edge.to,
+            edge.type_label()
+        ));
     }
 }
 
-/// 打印简单循环路径
-fn print_simple_cycle(cycle: &[String]) {
-    let cycle_str = cycle.join(" → ");
-    Logger::info(tf!("check.circular.cycle_simple", cycle_str));
-}
-
 /// 打印过期依赖表格
 pub fn print_outdated_dependencies_table(
     outdated_deps: &[OutdatedDependency],
@@ -171,62 +222,132 @@ pub fn print_outdated_dependencies_table(
     Ok(())
 }
 
-/// 打印详细的过期依赖信息
+/// 打印详细的过期依赖信息（按包分组）
 fn print_detailed_outdated_deps(outdated_deps: &[OutdatedDependency]) {
     let mut packages: BTreeMap<String, Vec<&OutdatedDependency>> = BTreeMap::new();
     for dep in outdated_deps {
         packages.entry(dep.package.clone()).or_default().push(dep);
     }
 
-    for (package_name, deps) in packages {
+    for (package_name, mut deps) in packages {
         Logger::info(tf!("check.outdated.package_header", package_name));
+        deps.sort_by(|a, b| a.name.cmp(&b.name));
 
-        let mut unique_deps: BTreeMap<String, &OutdatedDependency> = BTreeMap::new();
-        for dep in deps {
-            unique_deps.insert(dep.name.clone(), dep);
-        }
-
-        for (_, dep) in unique_deps {
-            Logger::info(tf!(
-                "check.outdated.dep_detail_simple",
-                Colors::info(&dep.name),
-                dep.current,
-                dep.latest,
-                dep.dep_type
-            ));
-        }
+        let rows: Vec<(&OutdatedDependency, String)> =
+            deps.into_iter().map(|dep| (dep, dep.dep_type.clone())).collect();
+        print_outdated_rows(&rows, t!("check.outdated.col_type"));
         Logger::info("");
     }
 }
 
-/// 打印简单的过期依赖信息
+/// 打印简单的过期依赖信息（相同依赖和规范合并为一行）
 fn print_simple_outdated_deps(outdated_deps: &[OutdatedDependency]) {
-    let mut unique_deps: BTreeMap<String, (&OutdatedDependency, Vec<String>)> = BTreeMap::new();
+    let mut unique_deps: BTreeMap<(String, String), (&OutdatedDependency, Vec<String>)> =
+        BTreeMap::new();
 
     for dep in outdated_deps {
-        unique_deps
-            .entry(dep.name.clone())
-            .and_modify(|(_, packages)| {
-                if !packages.contains(&dep.package) {
-                    packages.push(dep.package.clone());
-                }
-            })
-            .or_insert((dep, vec![dep.package.clone()]));
+        let (_, packages) = unique_deps
+            .entry((dep.name.clone(), dep.spec.clone()))
+            .or_insert_with(|| (dep, Vec::new()));
+        if !packages.contains(&dep.package) {
+            packages.push(dep.package.clone());
+        }
     }
 
-    for (_, (dep, packages)) in unique_deps {
-        Logger::info(tf!(
-            "check.outdated.dep_simple_single",
-            Colors::info(&format!("[{}]", dep.name)),
-            dep.current,
-            dep.latest
-        ));
+    let rows: Vec<(&OutdatedDependency, String)> =
+        unique_deps.into_values().map(|(dep, packages)| (dep, packages.join(", "))).collect();
+    print_outdated_rows(&rows, t!("check.outdated.col_used_by"));
+    Logger::info("");
+}
 
-        for package in &packages {
-            Logger::info(format!("    {}", package));
+/// 按列对齐打印过期依赖（最后一列由调用方提供）
+fn print_outdated_rows(rows: &[(&OutdatedDependency, String)], last_header: String) {
+    let header = [
+        t!("check.outdated.col_dependency"),
+        t!("check.outdated.col_range"),
+        t!("check.outdated.col_current"),
+        t!("check.outdated.col_wanted"),
+        t!("check.outdated.col_latest"),
+        t!("check.outdated.col_level"),
+        last_header,
+    ];
+    let cells: Vec<[String; 7]> = rows
+        .iter()
+        .map(|(dep, last)| {
+            let latest = if dep.dist_tag == "latest" {
+                dep.latest.clone()
+            } else {
+                format!("{} ({})", dep.latest, dep.dist_tag)
+            };
+            let level = t!(&format!("check.outdated.level.{}", dep.level));
+            [
+                dep.name.clone(),
+                dep.spec.clone(),
+                dep.current.clone(),
+                dep.wanted.clone().unwrap_or_else(|| "-".to_string()),
+                latest,
+                level,
+                last.clone(),
+            ]
+        })
+        .collect();
+
+    let mut widths = header.clone().map(|cell| display_width(&cell));
+    for row in &cells {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(display_width(cell));
         }
     }
-    Logger::info("");
+
+    let header_line: Vec<String> =
+        header.iter().zip(widths).map(|(cell, width)| pad_cell(cell, width)).collect();
+    Logger::info(format!("  {}", TextStyles::bold(header_line.join("  ").trim_end())));
+
+    for ((dep, _), row) in rows.iter().zip(&cells) {
+        let line: Vec<String> = row
+            .iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(index, (cell, width))| {
+                let padded = pad_cell(cell, width);
+                match index {
+                    0 => Colors::info(&padded),
+                    4 | 5 => color_by_level(&dep.level, &padded),
+                    _ => padded,
+                }
+            })
+            .collect();
+        Logger::info(format!("  {}", line.join("  ").trim_end()));
+    }
+}
+
+/// 按更新级别着色（major 红色、minor 黄色、patch 绿色）
+fn color_by_level(level: &str, text: &str) -> String {
+    match level {
+        "major" => Colors::red(text),
+        "minor" => Colors::yellow(text),
+        _ => Colors::green(text),
+    }
+}
+
+/// 终端显示宽度（中日韩字符按两列计算）
+fn display_width(text: &str) -> usize {
+    text.chars()
+        .map(|c| match c as u32 {
+            0x1100..=0x115F
+            | 0x2E80..=0xA4CF
+            | 0xAC00..=0xD7A3
+            | 0xF900..=0xFAFF
+            | 0xFF00..=0xFF60
+            | 0xFFE0..=0xFFE6 => 2,
+            _ => 1,
+        })
+        .sum()
+}
+
+/// 按显示宽度右侧补齐空格
+fn pad_cell(text: &str, width: usize) -> String {
+    format!("{}{}", text, " ".repeat(width.saturating_sub(display_width(text))))
 }
 
 /// 打印包管理器建议
@@ -247,10 +368,15 @@ pub fn print_version_conflicts_table(conflicts: &[VersionConflict], detail: bool
     Logger::info("───────────────────────────────────────");
 
     for (index, conflict) in conflicts.iter().enumerate() {
+        let kind = match conflict.kind.as_str() {
+            "compatible" => t!("check.versions.kind_compatible"),
+            _ => Colors::red(&t!("check.versions.kind_incompatible")),
+        };
         Logger::info(tf!(
             "check.versions.conflict_header",
             index + 1,
-            Colors::info(&conflict.name)
+            Colors::info(&conflict.name),
+            kind
         ));
 
         if detail {
@@ -261,7 +387,9 @@ pub fn print_version_conflicts_table(conflicts: &[VersionConflict], detail: bool
 
         Logger::info(tf!(
             "check.versions.recommended",
-            Colors::info(&conflict.recommended_version)
+            Colors::info(&conflict.recommended_version),
+            conflict.satisfied_usages,
+            conflict.conflicts.len()
         ));
         Logger::info("");
     }
@@ -283,9 +411,9 @@ fn print_detailed_conflict(conflict: &VersionConflict) {
     }
 }
 
-/// 打印简单冲突信息
+/// 打印简单冲突信息（按版本规范分组）
 fn print_simple_conflict(conflict: &VersionConflict) {
-    let version_groups = group_by_version(&conflict.conflicts);
+    let version_groups = group_by_spec(&conflict.conflicts);
 
     for (version, usages) in version_groups {
         let packages: Vec<String> = usages.iter().map(|u| u.package.clone()).collect();
@@ -293,13 +421,260 @@ fn print_simple_conflict(conflict: &VersionConflict) {
     }
 }
 
-/// 按版本分组
-fn group_by_version(usages: &[ConflictUsage]) -> HashMap<String, Vec<&ConflictUsage>> {
-    let mut unique_versions: HashMap<String, Vec<&ConflictUsage>> = HashMap::new();
+/// 按版本规范分组
+fn group_by_spec(usages: &[ConflictUsage]) -> BTreeMap<String, Vec<&ConflictUsage>> {
+    let mut unique_specs: BTreeMap<String, Vec<&ConflictUsage>> = BTreeMap::new();
     for usage in usages {
-        unique_versions.entry(usage.resolved_version.clone()).or_default().push(usage);
+        unique_specs.entry(usage.version_spec.clone()).or_default().push(usage);
+    }
+    unique_specs
+}
+
+/// 打印内部依赖写法问题表格（按包分组）
+pub fn print_protocol_issues_table(issues: &[ProtocolIssue], detail: bool) -> Result<()> {
+    Logger::info("");
+    Logger::info(t!("check.protocol.details"));
+    Logger::info("───────────────────────────────────────");
+
+    let mut packages: BTreeMap<&str, Vec<&ProtocolIssue>> = BTreeMap::new();
+    for issue in issues {
+        packages.entry(&issue.package).or_default().push(issue);
+    }
+
+    for (package, package_issues) in packages {
+        Logger::info(tf!("check.protocol.package_header", Colors::info(package)));
+        for issue in package_issues {
+            let reason = tf!(&format!("check.protocol.kind.{}", issue.kind), issue.local_version);
+            Logger::info(tf!(
+                "check.protocol.issue_line",
+                issue.dependency,
+                Colors::red(&issue.spec),
+                Colors::green(&issue.suggested),
+                reason
+            ));
+            if detail {
+                Logger::info(tf!("check.protocol.dep_type", issue.dep_type));
+            }
+        }
+        Logger::info("");
+    }
+
+    Logger::info(t!("check.protocol.suggestion"));
+    Ok(())
+}
+
+/// 打印架构边界违规表格（按依赖方分组）
+pub fn print_boundary_violations_table(
+    violations: &[BoundaryViolation],
+    detail: bool,
+) -> Result<()> {
+    Logger::info("");
+    Logger::info(t!("check.boundaries.details"));
+    Logger::info("───────────────────────────────────────");
+
+    let mut packages: BTreeMap<&str, Vec<&BoundaryViolation>> = BTreeMap::new();
+    for violation in violations {
+        packages.entry(&violation.package).or_default().push(violation);
+    }
+
+    for (package, package_violations) in packages {
+        let tags = format_tags(&package_violations[0].package_tags);
+        Logger::info(tf!("check.boundaries.package_header", Colors::info(package), tags));
+        for violation in package_violations {
+            let selectors = violation.selectors.join(", ");
+            let reason = match violation.kind {
+                BoundaryViolationKind::Denied => tf!("check.boundaries.kind.denied", selectors),
+                BoundaryViolationKind::NotAllowed => {
+                    tf!("check.boundaries.kind.not_allowed", selectors)
+                }
+            };
+            Logger::info(tf!(
+                "check.boundaries.violation_line",
+                icons::ARROW,
+                Colors::red(&violation.dependency),
+                violation.type_label(),
+                violation.rule,
+                reason
+            ));
+            if detail {
+                Logger::info(tf!(
+                    "check.boundaries.dependency_tags",
+                    format_tags(&violation.dependency_tags)
+                ));
+            }
+        }
+        Logger::info("");
+    }
+
+    Logger::info(t!("check.boundaries.suggestion"));
+    Ok(())
+}
+
+/// 简要模式下每个未声明依赖最多显示的导入位置数量
+const MAX_IMPORT_LOCATIONS: usize = 3;
+
+/// 打印依赖使用问题表格（按包分组）
+pub fn print_deps_usage_table(issues: &[DependencyUsageIssue], detail: bool) -> Result<()> {
+    Logger::info("");
+    Logger::info(t!("check.deps_usage.details"));
+    Logger::info("───────────────────────────────────────");
+
+    let mut packages: BTreeMap<&str, Vec<&DependencyUsageIssue>> = BTreeMap::new();
+    for issue in issues {
+        packages.entry(&issue.package).or_default().push(issue);
+    }
+
+    for (package, package_issues) in packages {
+        Logger::info(tf!("check.deps_usage.package_header", Colors::info(package)));
+        for issue in package_issues {
+            match issue.kind {
+                DependencyUsageKind::Undeclared => {
+                    let shown = if detail {
+                        issue.locations.len()
+                    } else {
+                        issue.locations.len().min(MAX_IMPORT_LOCATIONS)
+                    };
+                    let mut locations = issue.locations[..shown].join(", ");
+                    if shown < issue.locations.len() {
+                        locations.push_str(&tf!(
+                            "check.deps_usage.more_locations",
+                            issue.locations.len() - shown
+                        ));
+                    }
+                    Logger::info(tf!(
+                        "check.deps_usage.undeclared_line",
+                        icons::ERROR,
+                        Colors::red(&issue.dependency),
+                        locations
+                    ));
+                }
+                DependencyUsageKind::Unused => {
+                    Logger::info(tf!(
+                        "check.deps_usage.unused_line",
+                        icons::SKIP,
+                        Colors::yellow(&issue.dependency),
+                        issue.type_label()
+                    ));
+                }
+            }
+        }
+        Logger::info("");
+    }
+
+    Logger::info(t!("check.deps_usage.suggestion"));
+    Ok(())
+}
+
+/// 打印 package.json 规范问题表格（按包分组）
+pub fn print_manifest_issues_table(issues: &[ManifestIssue], detail: bool) -> Result<()> {
+    Logger::info("");
+    Logger::info(t!("check.manifest.details"));
+    Logger::info("───────────────────────────────────────");
+
+    let mut packages: BTreeMap<(&str, &str), Vec<&ManifestIssue>> = BTreeMap::new();
+    for issue in issues {
+        packages.entry((&issue.folder, &issue.package)).or_default().push(issue);
+    }
+
+    for ((folder, package), package_issues) in packages {
+        Logger::info(tf!("check.manifest.package_header", Colors::info(package), folder));
+        for issue in package_issues {
+            let (icon, rule) = match issue.severity {
+                RuleSeverity::Error => (icons::ERROR, Colors::red(&issue.rule)),
+                _ => (icons::WARNING, Colors::yellow(&issue.rule)),
+            };
+            let fixable =
+                if issue.fix.is_some() { t!("check.manifest.fixable_mark") } else { String::new() };
+            Logger::info(tf!("check.manifest.issue_line", icon, rule, issue.message, fixable));
+
+            if let (true, Some(fix)) = (detail, &issue.fix) {
+                Logger::info(tf!(
+                    "check.manifest.fix_line",
+                    icons::ARROW,
+                    format_manifest_fix(fix)
+                ));
+            }
+        }
+        Logger::info("");
     }
-    unique_versions
+
+    Logger::info(t!("check.manifest.suggestion"));
+    Ok(())
+}
+
+/// 自动修复的简要描述（如 engines.node: ">=16" → ">=18"）
+pub fn format_manifest_fix(fix: &ManifestFix) -> String {
+    match fix {
+        ManifestFix::Replace { key, from, to } => {
+            format!("{}: {:?} → {:?}", key.join("."), from, to)
+        }
+        ManifestFix::Insert { key, value } => format!("{}: {}", key, value),
+    }
+}
+
+/// 标签列表（没有标签时显示 "-"）
+fn format_tags(tags: &[String]) -> String {
+    if tags.is_empty() {
+        "-".to_string()
+    } else {
+        tags.join(", ")
+    }
+}
+
+/// 简要模式下每个版本最多显示的引入方数量
+const MAX_PULLED_BY: usize = 3;
+
+/// 打印重复依赖表格
+pub fn print_duplicates_table(report: &DuplicateReport, detail: bool) -> Result<()> {
+    Logger::info("");
+    Logger::info(tf!("check.duplicates.details", report.lockfile));
+    Logger::info("───────────────────────────────────────");
+
+    for (index, duplicate) in report.duplicates.iter().enumerate() {
+        Logger::info(tf!(
+            "check.duplicates.package_header",
+            index + 1,
+            Colors::info(&duplicate.name),
+            duplicate.versions.len(),
+            format_optional_bytes(duplicate.extra_bytes)
+        ));
+
+        for version in &duplicate.versions {
+            let mut pulled_by = version.pulled_by.clone();
+            if !detail && pulled_by.len() > MAX_PULLED_BY {
+                let more = pulled_by.len() - MAX_PULLED_BY;
+                pulled_by.truncate(MAX_PULLED_BY);
+                pulled_by.push(tf!("check.duplicates.more", more));
+            }
+            Logger::info(tf!(
+                "check.duplicates.version_line",
+                Colors::yellow(&version.version),
+                format_optional_bytes(version.size_bytes),
+                pulled_by.join(", ")
+            ));
+        }
+        Logger::info("");
+    }
+
+    if let Some(extra_bytes) = report.extra_bytes {
+        Logger::info(tf!(
+            "check.duplicates.total_extra",
+            TextStyles::bold(&format_bytes(extra_bytes))
+        ));
+    }
+
+    let suggestion = match Config::get_package_manager().as_str() {
+        "pnpm" => t!("check.duplicates.suggestion_pnpm"),
+        "yarn" => t!("check.duplicates.suggestion_yarn"),
+        _ => t!("check.duplicates.suggestion_npm"),
+    };
+    Logger::info(suggestion);
+    Ok(())
+}
+
+/// 格式化可能未知的体积
+fn format_optional_bytes(bytes: Option<u64>) -> String {
+    bytes.map(format_bytes).unwrap_or_else(|| t!("check.duplicates.unknown_size"))
 }
 
 /// 实时显示发现的过期包
@@ -308,3 +683,149 @@ pub fn print_outdated_package_realtime(dep_name: &str, current: &str, latest: &s
         Logger::warn(tf!("check.outdated.found_realtime", Colors::info(dep_name), current, latest));
     }
 }
+
+// ============================================================================
+// 执行历史统计显示
+// ============================================================================
+
+/// 打印执行历史统计报告
+pub fn print_history_report(report: &HistoryReport) {
+    // 最慢的包
+    Logger::info("");
+    Logger::info(TextStyles::bold(&t!("stats.slowest_packages")));
+    Logger::info("───────────────────────────────────────");
+    for (index, timing) in report.slowest_packages.iter().enumerate() {
+        Logger::info(tf!(
+            "stats.slowest_detail",
+            index + 1,
+            Colors::info(&timing.package),
+            format_duration_ms(timing.mean_ms),
+            format_duration_ms(timing.max_ms),
+            timing.samples
+        ));
+    }
+
+    // 不稳定任务
+    Logger::info("");
+    Logger::info(TextStyles::bold(&t!("stats.flaky_tasks")));
+    Logger::info("───────────────────────────────────────");
+    if report.flaky_tasks.is_empty() {
+        Logger::info(format!("{} {}", icons::SUCCESS, t!("stats.no_flaky_tasks")));
+    }
+    for flaky in &report.flaky_tasks {
+        Logger::warn(tf!(
+            "stats.flaky_detail",
+            Colors::yellow(&flaky.task),
+            flaky.failures,
+            flaky.runs,
+            flaky.flips
+        ));
+    }
+
+    // 运行趋势
+    Logger::info("");
+    Logger::info(TextStyles::bold(&t!("stats.trend")));
+    Logger::info("───────────────────────────────────────");
+    for run in &report.trend {
+        let status_icon = if run.failed == 0 { icons::SUCCESS } else { icons::ERROR };
+        Logger::info(tf!(
+            "stats.trend_detail",
+            status_icon,
+            format_timestamp(run.started_at),
+            run.command,
+            format_duration_ms(run.duration_ms),
+            run.tasks,
+            run.failed
+        ));
+    }
+
+    // 任务耗时分位数
+    Logger::info("");
+    Logger::info(TextStyles::bold(&t!("stats.percentiles")));
+    Logger::info("───────────────────────────────────────");
+    for task in &report.task_percentiles {
+        Logger::info(tf!(
+            "stats.percentile_detail",
+            Colors::info(&task.task),
+            format_duration_ms(task.p50_ms),
+            format_duration_ms(task.p95_ms),
+            task.samples
+        ));
+    }
+    Logger::info("");
+}
+
+// ============================================================================
+// 试运行执行计划显示
+// ============================================================================
+
+/// 打印执行计划
+pub fn print_execution_plan(plan: &ExecutionPlan) {
+    let runnable = plan.tasks.iter().filter(|task| task.skip_reason.is_none()).count();
+
+    Logger::info("");
+    Logger::info(format!(
+        "{} {}",
+        icons::EXEC,
+        TextStyles::bold(&tf!("plan.title", &plan.command, runnable, plan.total_stages))
+    ));
+    Logger::info("═══════════════════════════════════════");
+
+    for stage in 1..=plan.total_stages {
+        Logger::info("");
+        Logger::info(format!("{} {}", icons::STAGE, tf!("plan.stage", stage)));
+        Logger::info("───────────────────────────────────────");
+
+        for task in plan.tasks.iter().filter(|task| task.stage == stage) {
+            let selection = match &task.selection {
+                SelectionReason::All => t!("plan.selected_all"),
+                SelectionReason::Requested => t!("plan.selected_requested"),
+                SelectionReason::Dependency { required_by } => {
+                    tf!("plan.selected_dependency", required_by.join(", "))
+                }
+            };
+
+            if let Some(reason) = &task.skip_reason {
+                let reason = match reason {
+                    SkipReason::ScriptNotFound => t!("plan.skip_script_not_found"),
+                    SkipReason::UpstreamFailed => t!("plan.skip_upstream_failed"),
+                    SkipReason::Cancelled => t!("plan.skip_cancelled"),
+                };
+                Logger::info(format!(
+                    "  {} {} ({}) - {}",
+                    icons::SKIP,
+                    Colors::yellow(&task.package),
+                    selection,
+                    tf!("plan.skipped", reason)
+                ));
+                continue;
+            }
+
+            Logger::info(format!(
+                "  {} {}@{} ({})",
+                icons::PACKAGE,
+                Colors::info(&task.package),
+                task.version,
+                selection
+            ));
+            Logger::info(format!("    $ {}", TextStyles::bold(&task.command_line)));
+            Logger::info(format!("    {}", tf!("plan.cwd", &task.working_directory)));
+            if !task.env.is_empty() {
+                let env: Vec<String> =
+                    task.env.iter().map(|(key, value)| format!("{}={}", key, value)).collect();
+                Logger::info(format!("    {}", tf!("plan.env", env.join(" "))));
+            }
+            if !task.depends_on.is_empty() {
+                Logger::info(format!("    {}", tf!("plan.depends_on", task.depends_on.join(", "))));
+            }
+        }
+    }
+
+    if let Some(post_command) = &plan.post_command {
+        Logger::info("");
+        Logger::info(tf!("plan.post_command", &plan.package_manager, post_command));
+    }
+
+    Logger::info("");
+    Logger::info(t!("plan.dry_run_tip"));
+}
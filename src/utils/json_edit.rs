@@ -0,0 +1,464 @@
+// ============================================================================
+// MonoX - JSON 文件编辑
+// ============================================================================
+//
+// 文件: src/utils/json_edit.rs
+// 职责: 按键路径修改 JSON 文件中的字符串值，保留原有格式
+// 边界:
+//   - ✅ 键路径定位（只替换目标值的文本，缩进、键顺序、换行符保持不变）
+//   - ✅ 在根对象末尾新增字段（沿用已有字段的缩进和文件的换行符）
+//   - ✅ 多个文件的原子写入（临时文件 + 重命名）和失败回滚，可与其它文本文件一起写入
+//   - ❌ 不应包含依赖版本计算逻辑
+//   - ❌ 不应包含 CLI 输出逻辑
+//
+// ============================================================================
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::fs;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use crate::tf;
+
+/// 已加载到内存中的 JSON 文件
+#[derive(Debug, Clone)]
+pub struct JsonDocument {
+    /// 文件路径
+    path: PathBuf,
+    /// 读取时的原始内容
+    original: String,
+    /// 修改后的内容
+    content: String,
+    /// 文件使用的换行符（\n 或 \r\n）
+    line_ending: &'static str,
+}
+
+impl JsonDocument {
+    /// 读取并校验 JSON 文件
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| tf!("json_edit.read_failed", path.display()))?;
+        serde_json::from_str::<serde_json::Value>(&content)
+            .with_context(|| tf!("json_edit.parse_failed", path.display()))?;
+
+        let line_ending = if content.contains("\r\n") { "\r\n" } else { "\n" };
+        Ok(Self { path: path.to_path_buf(), original: content.clone(), content, line_ending })
+    }
+
+    /// 文件路径
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 解析当前内容
+    pub fn value(&self) -> Result<serde_json::Value> {
+        serde_json::from_str(&self.content)
+            .with_context(|| tf!("json_edit.parse_failed", self.path.display()))
+    }
+
+    /// 内容是否已被修改
+    pub fn is_modified(&self) -> bool {
+        self.content != self.original
+    }
+
+    /// 将键路径上的字符串值从 expected 改为 value，值不存在或不等于 expected 时返回 false
+    pub fn set_string(&mut self, key_path: &[&str], expected: &str, value: &str) -> Result<bool> {
+        let Some(span) = find_value_span(&self.content, key_path)
+            .with_context(|| tf!("json_edit.parse_failed", self.path.display()))?
+        else {
+            return Ok(false);
+        };
+
+        match serde_json::from_str::<String>(&self.content[span.clone()]) {
+            Ok(current) if current == expected => {}
+            _ => return Ok(false),
+        }
+
+        self.content.replace_range(span, &serde_json::to_string(value)?);
+        Ok(true)
+    }
+
+    /// 在根对象末尾新增字段，根不是对象或字段已存在时返回 false
+    pub fn insert(&mut self, key: &str, value: &serde_json::Value) -> Result<bool> {
+        let current = self.value()?;
+        let Some(object) = current.as_object() else {
+            return Ok(false);
+        };
+        if object.contains_key(key) {
+            return Ok(false);
+        }
+
+        let mut scanner = Scanner { content: &self.content, pos: 0 };
+        scanner.skip_whitespace();
+        let open = scanner.pos;
+        scanner.skip_value().with_context(|| tf!("json_edit.parse_failed", self.path.display()))?;
+        let close = scanner.pos - 1;
+        let body = &self.content[open + 1..close];
+        let key = serde_json::to_string(key)?;
+        let eol = self.line_ending;
+
+        if object.is_empty() {
+            let text = format!("{{{}  {}: {}{}}}", eol, key, format_value(value, "  ", eol)?, eol);
+            self.content.replace_range(open..=close, &text);
+            return Ok(true);
+        }
+
+        // 沿用第一个字段所在行的缩进，单行对象时追加在同一行
+        let indent = body.find('"').and_then(|first| {
+            let before = &body[..first];
+            before.rfind('\n').map(|newline| before[newline + 1..].to_string())
+        });
+        let text = match indent {
+            Some(indent) => {
+                format!(",{}{}{}: {}", eol, indent, key, format_value(value, &indent, eol)?)
+            }
+            None => format!(", {}: {}", key, serde_json::to_string(value)?),
+        };
+        let end = open + 1 + body.trim_end().len();
+        self.content.insert_str(end, &text);
+        Ok(true)
+    }
+}
+
+/// 与 JSON 文档一起写入的文本文件（如 CHANGELOG.md）
+#[derive(Debug, Clone)]
+pub struct TextFile {
+    /// 文件路径
+    path: PathBuf,
+    /// 读取时的原始内容（None 表示文件原本不存在）
+    original: Option<String>,
+    /// 修改后的内容
+    content: String,
+}
+
+impl TextFile {
+    /// 读取文本文件（不存在时内容为空）
+    pub fn load(path: &Path) -> Result<Self> {
+        let original = if path.exists() {
+            Some(
+                fs::read_to_string(path)
+                    .with_context(|| tf!("json_edit.read_failed", path.display()))?,
+            )
+        } else {
+            None
+        };
+        let content = original.clone().unwrap_or_default();
+        Ok(Self { path: path.to_path_buf(), original, content })
+    }
+
+    /// 文件路径
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 当前内容
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    /// 替换内容
+    pub fn set_content(&mut self, content: String) {
+        self.content = content;
+    }
+
+    /// 内容是否已被修改（新文件总是视为修改）
+    pub fn is_modified(&self) -> bool {
+        self.original.as_deref() != Some(self.content.as_str())
+    }
+}
+
+/// 按给定缩进和换行符格式化值（嵌套层级使用相同的缩进单位，续行整体再缩进一级）
+fn format_value(value: &serde_json::Value, indent: &str, line_ending: &str) -> Result<String> {
+    let mut buffer = Vec::new();
+    let formatter = serde_json::ser::PrettyFormatter::with_indent(indent.as_bytes());
+    let mut serializer = serde_json::Serializer::with_formatter(&mut buffer, formatter);
+    value.serialize(&mut serializer)?;
+    Ok(String::from_utf8(buffer)?.replace('\n', &format!("{}{}", line_ending, indent)))
+}
+
+/// 原子写回所有修改过的文档：先全部写入临时文件再逐个替换，任一步骤失败时恢复已替换的文件
+pub fn commit(documents: &[JsonDocument]) -> Result<usize> {
+    commit_with(documents, &[])
+}
+
+/// 与 commit 相同，同时写入文本文件；回滚时删除原本不存在的文件
+pub fn commit_with(documents: &[JsonDocument], files: &[TextFile]) -> Result<usize> {
+    // 路径、原始内容、修改后的内容
+    let modified: Vec<(&Path, Option<&str>, &str)> = documents
+        .iter()
+        .filter(|doc| doc.is_modified())
+        .map(|doc| (doc.path.as_path(), Some(doc.original.as_str()), doc.content.as_str()))
+        .chain(
+            files
+                .iter()
+                .filter(|file| file.is_modified())
+                .map(|file| (file.path.as_path(), file.original.as_deref(), file.content.as_str())),
+        )
+        .collect();
+
+    // 写入临时文件
+    let mut staged: Vec<(&Path, Option<&str>, PathBuf)> = Vec::new();
+    for &(path, original, content) in &modified {
+        let temp = temp_path(path);
+        if let Err(error) = fs::write(&temp, content) {
+            let _ = fs::remove_file(&temp);
+            staged.iter().for_each(|(_, _, temp)| {
+                let _ = fs::remove_file(temp);
+            });
+            return Err(error).with_context(|| tf!("json_edit.write_failed", path.display()));
+        }
+        staged.push((path, original, temp));
+    }
+
+    // 替换原文件
+    for (index, (path, _, temp)) in staged.iter().enumerate() {
+        if let Err(error) = fs::rename(temp, path) {
+            staged[index..].iter().for_each(|(_, _, temp)| {
+                let _ = fs::remove_file(temp);
+            });
+            staged[..index].iter().for_each(|(replaced, original, _)| {
+                let _ = match original {
+                    Some(original) => fs::write(replaced, original),
+                    None => fs::remove_file(replaced),
+                };
+            });
+            return Err(error).with_context(|| tf!("json_edit.write_failed", path.display()));
+        }
+    }
+
+    Ok(modified.len())
+}
+
+/// 与目标文件同目录的临时文件路径（保证重命名不跨文件系统）
+fn temp_path(path: &Path) -> PathBuf {
+    let file_name = path.file_name().map(|name| name.to_string_lossy()).unwrap_or_default();
+    path.with_file_name(format!(".{}.monox-tmp", file_name))
+}
+
+/// 定位键路径对应值在文本中的字节范围
+fn find_value_span(content: &str, key_path: &[&str]) -> Result<Option<Range<usize>>> {
+    if key_path.is_empty() {
+        return Ok(None);
+    }
+
+    let mut scanner = Scanner { content, pos: 0 };
+    scanner.skip_whitespace();
+    scanner.find_in_object(key_path)
+}
+
+/// 只做定位的简易 JSON 扫描器（输入已通过 serde_json 校验）
+struct Scanner<'a> {
+    content: &'a str,
+    pos: usize,
+}
+
+impl Scanner<'_> {
+    /// 在当前位置的对象中查找键路径
+    fn find_in_object(&mut self, key_path: &[&str]) -> Result<Option<Range<usize>>> {
+        if self.peek() != Some(b'{') {
+            return Ok(None);
+        }
+        self.pos += 1;
+
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b'}') => return Ok(None),
+                Some(b',') => {
+                    self.pos += 1;
+                    continue;
+                }
+                Some(b'"') => {}
+                _ => anyhow::bail!(tf!("json_edit.unexpected_token", self.pos)),
+            }
+
+            let key = self.parse_string()?;
+            self.skip_whitespace();
+            if self.peek() != Some(b':') {
+                anyhow::bail!(tf!("json_edit.unexpected_token", self.pos));
+            }
+            self.pos += 1;
+            self.skip_whitespace();
+
+            if key == key_path[0] {
+                if key_path.len() == 1 {
+                    let start = self.pos;
+                    self.skip_value()?;
+                    return Ok(Some(start..self.pos));
+                }
+                return self.find_in_object(&key_path[1..]);
+            }
+            self.skip_value()?;
+        }
+    }
+
+    /// 解析当前位置的字符串并返回解码后的内容
+    fn parse_string(&mut self) -> Result<String> {
+        let start = self.pos;
+        self.skip_string()?;
+        Ok(serde_json::from_str(&self.content[start..self.pos])?)
+    }
+
+    /// 跳过当前位置的字符串（包括两侧引号）
+    fn skip_string(&mut self) -> Result<()> {
+        let bytes = self.content.as_bytes();
+        let mut index = self.pos + 1;
+        while index < bytes.len() {
+            match bytes[index] {
+                b'\\' => index += 2,
+                b'"' => {
+                    self.pos = index + 1;
+                    return Ok(());
+                }
+                _ => index += 1,
+            }
+        }
+        anyhow::bail!(tf!("json_edit.unexpected_token", self.pos))
+    }
+
+    /// 跳过当前位置的任意值
+    fn skip_value(&mut self) -> Result<()> {
+        match self.peek() {
+            Some(b'"') => self.skip_string(),
+            Some(b'{') | Some(b'[') => {
+                let mut depth = 0usize;
+                while let Some(byte) = self.peek() {
+                    match byte {
+                        b'"' => {
+                            self.skip_string()?;
+                            continue;
+                        }
+                        b'{' | b'[' => depth += 1,
+                        b'}' | b']' => {
+                            depth -= 1;
+                            if depth == 0 {
+                                self.pos += 1;
+                                return Ok(());
+                            }
+                        }
+                        _ => {}
+                    }
+                    self.pos += 1;
+                }
+                anyhow::bail!(tf!("json_edit.unexpected_token", self.pos))
+            }
+            Some(_) => {
+                // 数字、true、false、null
+                while let Some(byte) = self.peek() {
+                    if matches!(byte, b',' | b'}' | b']') || byte.is_ascii_whitespace() {
+                        break;
+                    }
+                    self.pos += 1;
+                }
+                Ok(())
+            }
+            None => anyhow::bail!(tf!("json_edit.unexpected_token", self.pos)),
+        }
+    }
+
+    /// 跳过空白字符
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|byte| byte.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    /// 当前字节
+    fn peek(&self) -> Option<u8> {
+        self.content.as_bytes().get(self.pos).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rolls_back_documents_and_new_files_when_a_write_fails() {
+        let root = tempfile::tempdir().unwrap();
+        let manifest = root.path().join("package.json");
+        fs::write(&manifest, "{\n  \"version\": \"1.0.0\"\n}\n").unwrap();
+        let mut document = JsonDocument::load(&manifest).unwrap();
+        document.set_string(&["version"], "1.0.0", "1.0.1").unwrap();
+
+        let mut created = TextFile::load(&root.path().join("CHANGELOG.md")).unwrap();
+        created.set_content("# a\n".to_string());
+        // 读取后目标路径变成非空目录，替换时失败
+        let blocked_path = root.path().join("blocked");
+        let mut blocked = TextFile::load(&blocked_path).unwrap();
+        blocked.set_content("text".to_string());
+        fs::create_dir_all(blocked_path.join("inner")).unwrap();
+
+        assert!(commit_with(&[document], &[created, blocked]).is_err());
+        assert_eq!(fs::read_to_string(&manifest).unwrap(), "{\n  \"version\": \"1.0.0\"\n}\n");
+        assert!(!root.path().join("CHANGELOG.md").exists());
+        assert!(!root.path().join(".blocked.monox-tmp").exists());
+    }
+
+    /// 写入临时 package.json 并加载
+    fn load_document(root: &Path, content: &str) -> JsonDocument {
+        let manifest = root.join("package.json");
+        fs::write(&manifest, content).unwrap();
+        JsonDocument::load(&manifest).unwrap()
+    }
+
+    #[test]
+    fn set_string_edits_only_the_given_dependency_type() {
+        let root = tempfile::tempdir().unwrap();
+        let mut document = load_document(
+            root.path(),
+            "{\n  \"dependencies\": { \"react\": \"^17.0.0\" },\n  \"peerDependencies\": { \"react\": \"^17.0.0\" }\n}\n",
+        );
+
+        assert!(document.set_string(&["peerDependencies", "react"], "^17.0.0", "^18.0.0").unwrap());
+        assert_eq!(
+            document.content,
+            "{\n  \"dependencies\": { \"react\": \"^17.0.0\" },\n  \"peerDependencies\": { \"react\": \"^18.0.0\" }\n}\n"
+        );
+    }
+
+    #[test]
+    fn set_string_keeps_formatting_and_key_order() {
+        let root = tempfile::tempdir().unwrap();
+        let original = "{\r\n\t\"version\": \"1.0.0\",\r\n\t\"name\": \"a\",\r\n\t\"dependencies\": {\r\n\t\t\"zod\": \"^3.0.0\",\r\n\t\t\"axios\": \"~1.2.0\"\r\n\t}\r\n}\r\n";
+        let mut document = load_document(root.path(), original);
+
+        assert!(document.set_string(&["dependencies", "axios"], "~1.2.0", "~1.6.0").unwrap());
+        assert_eq!(document.content, original.replace("~1.2.0", "~1.6.0"));
+        assert!(document.is_modified());
+
+        // 没有末尾换行的文件保持原样
+        let mut document = load_document(root.path(), "{\"version\":\"1.0.0\"}");
+        assert!(document.set_string(&["version"], "1.0.0", "1.0.1").unwrap());
+        assert_eq!(document.content, "{\"version\":\"1.0.1\"}");
+    }
+
+    #[test]
+    fn set_string_returns_false_when_value_is_not_expected() {
+        let root = tempfile::tempdir().unwrap();
+        let original = "{\n  \"dependencies\": { \"react\": \"^17.0.0\", \"count\": 1 }\n}\n";
+        let mut document = load_document(root.path(), original);
+
+        assert!(!document.set_string(&["dependencies", "react"], "^16.0.0", "^18.0.0").unwrap());
+        assert!(!document.set_string(&["dependencies", "count"], "1", "2").unwrap());
+        assert!(!document.set_string(&["devDependencies", "react"], "^17.0.0", "^18.0.0").unwrap());
+        assert_eq!(document.content, original);
+        assert!(!document.is_modified());
+    }
+
+    #[test]
+    fn inserts_with_the_document_line_ending() {
+        let root = tempfile::tempdir().unwrap();
+        let manifest = root.path().join("package.json");
+        fs::write(&manifest, "{\r\n  \"name\": \"a\"\r\n}\r\n").unwrap();
+
+        let mut document = JsonDocument::load(&manifest).unwrap();
+        let repository = serde_json::json!({ "type": "git" });
+        assert!(document.insert("repository", &repository).unwrap());
+        assert_eq!(
+            document.content,
+            "{\r\n  \"name\": \"a\",\r\n  \"repository\": {\r\n    \"type\": \"git\"\r\n  }\r\n}\r\n"
+        );
+    }
+}
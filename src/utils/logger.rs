@@ -16,13 +16,37 @@
 //
 // ============================================================================
 
+use std::sync::atomic::{AtomicBool, Ordering};
+
 use super::colors::Colors;
 use super::constants::APP_NAME;
 
+/// 日志是否全部写到标准错误（标准输出保留给 JSON 报告）
+static STDERR_OUTPUT: AtomicBool = AtomicBool::new(false);
+
 /// 简单的日志工具
 pub struct Logger;
 
 impl Logger {
+    /// 设置日志是否全部写到标准错误，JSON 报告输出前后的过程日志不会混入标准输出
+    pub fn set_stderr_output(enabled: bool) {
+        STDERR_OUTPUT.store(enabled, Ordering::Relaxed);
+    }
+
+    /// 日志是否全部写到标准错误
+    pub fn is_stderr_output() -> bool {
+        STDERR_OUTPUT.load(Ordering::Relaxed)
+    }
+
+    /// 输出一行非错误日志
+    fn emit(line: String) {
+        if Self::is_stderr_output() {
+            eprintln!("{}", line);
+        } else {
+            println!("{}", line);
+        }
+    }
+
     /// 获取各种类型日志前缀(带颜色)
     pub fn get_prefix(level: &str) -> String {
         match level {
@@ -36,15 +60,15 @@ impl Logger {
     }
 
     pub fn debug<S: AsRef<str>>(msg: S) {
-        println!("{} {}", Self::get_prefix("DEBUG"), msg.as_ref());
+        Self::emit(format!("{} {}", Self::get_prefix("DEBUG"), msg.as_ref()));
     }
 
     pub fn info<S: AsRef<str>>(msg: S) {
-        println!("{} {}", Self::get_prefix("INFO"), msg.as_ref());
+        Self::emit(format!("{} {}", Self::get_prefix("INFO"), msg.as_ref()));
     }
 
     pub fn warn<S: AsRef<str>>(msg: S) {
-        println!("{} {}", Self::get_prefix("WARN"), msg.as_ref());
+        Self::emit(format!("{} {}", Self::get_prefix("WARN"), msg.as_ref()));
     }
 
     pub fn error<S: AsRef<str>>(msg: S) {
@@ -52,6 +76,6 @@ impl Logger {
     }
 
     pub fn success<S: AsRef<str>>(msg: S) {
-        println!("{} {}", Self::get_prefix("SUCCESS"), msg.as_ref());
+        Self::emit(format!("{} {}", Self::get_prefix("SUCCESS"), msg.as_ref()));
     }
 }
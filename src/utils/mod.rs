@@ -17,6 +17,8 @@
 
 pub mod colors;
 pub mod constants;
+pub mod json_edit;
 pub mod logger;
 pub mod performance;
+pub mod semver_range;
 pub mod styles;
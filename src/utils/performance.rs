@@ -16,4 +16,85 @@
 //
 // ============================================================================
 
-// TODO: 实现性能监控工具
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 计算分位数（最近秩法），输入无需排序
+pub fn percentile(values: &[u64], p: f64) -> Option<u64> {
+    if values.is_empty() {
+        return None;
+    }
+
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    let index = rank.clamp(1, sorted.len()) - 1;
+    Some(sorted[index])
+}
+
+/// 计算平均值
+pub fn mean(values: &[u64]) -> Option<u64> {
+    if values.is_empty() {
+        return None;
+    }
+    Some(values.iter().sum::<u64>() / values.len() as u64)
+}
+
+/// 当前时间（Unix 毫秒）
+pub fn now_millis() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or(0)
+}
+
+/// 格式化时长（毫秒）为易读字符串
+pub fn format_duration_ms(ms: u64) -> String {
+    match ms {
+        0..=999 => format!("{}ms", ms),
+        1_000..=59_999 => format!("{:.2}s", ms as f64 / 1000.0),
+        _ => format!("{}m{:02}s", ms / 60_000, (ms % 60_000) / 1000),
+    }
+}
+
+/// 格式化字节数为易读字符串
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: &[&str] = &["B", "KB", "MB", "GB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+/// 格式化 Unix 毫秒时间戳为 UTC 时间字符串 (YYYY-MM-DD HH:MM:SS)
+pub fn format_timestamp(ms: u64) -> String {
+    let secs = ms / 1000;
+    let days = (secs / 86_400) as i64;
+    let seconds_of_day = secs % 86_400;
+
+    // 公历日期换算（Howard Hinnant civil_from_days 算法）
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        seconds_of_day / 3600,
+        (seconds_of_day % 3600) / 60,
+        seconds_of_day % 60
+    )
+}
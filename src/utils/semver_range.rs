@@ -0,0 +1,232 @@
+// ============================================================================
+// MonoX - npm 版本范围解析
+// ============================================================================
+//
+// 文件: src/utils/semver_range.rs
+// 职责: 将 npm 风格的版本规范解析为 semver 版本要求
+// 边界:
+//   - ✅ npm 范围语法解析（^ ~ 比较符、x 通配、空格交集、|| 并集、连字符范围）
+//   - ✅ npm: 别名解析
+//   - ✅ 版本范围下界计算和匹配判断
+//   - ❌ 不应包含依赖冲突分析逻辑
+//   - ❌ 不应包含文件操作逻辑
+//   - ❌ 不应包含网络请求逻辑
+//
+// ============================================================================
+
+use semver::{Comparator, Op, Version, VersionReq};
+
+/// npm 依赖版本规范（如 "^1.2.3"、">=1.2 <2"、"npm:foo@^1.0.0"）
+#[derive(Debug, Clone)]
+pub struct NpmVersionSpec {
+    /// npm: 别名指向的真实包名
+    pub alias: Option<String>,
+    /// 以 || 分隔的各个候选范围（满足任意一个即可）
+    alternatives: Vec<VersionReq>,
+}
+
+impl NpmVersionSpec {
+    /// 解析版本规范，无法识别的规范（如 dist-tag、URL）返回 None
+    pub fn parse(spec: &str) -> Option<Self> {
+        let spec = spec.trim();
+
+        // npm:<name>@<range>
+        let (alias, range) = match spec.strip_prefix("npm:") {
+            Some(aliased) => {
+                // 作用域包名以 @ 开头，需从第二个字符开始查找分隔符
+                let split_at = aliased.get(1..)?.find('@').map(|i| i + 1);
+                match split_at {
+                    Some(i) => (Some(aliased[..i].to_string()), &aliased[i + 1..]),
+                    None => (Some(aliased.to_string()), ""),
+                }
+            }
+            None => (None, spec),
+        };
+
+        let alternatives =
+            range.split("||").map(parse_alternative).collect::<Option<Vec<VersionReq>>>()?;
+
+        Some(Self { alias, alternatives })
+    }
+
+    /// 判断版本是否满足该规范
+    pub fn matches(&self, version: &Version) -> bool {
+        self.alternatives.iter().any(|req| req.matches(version))
+    }
+
+    /// 各候选范围的下界（满足该范围的最低版本）
+    pub fn lower_bounds(&self) -> Vec<Version> {
+        self.alternatives.iter().map(lower_bound).collect()
+    }
+
+    /// 满足该规范的最低版本
+    pub fn min_version(&self) -> Option<Version> {
+        self.lower_bounds().into_iter().min()
+    }
+}
+
+/// 解析单个候选范围（空格分隔的比较符取交集）
+fn parse_alternative(range: &str) -> Option<VersionReq> {
+    let tokens = merge_operator_tokens(range);
+
+    // 连字符范围: A - B  =>  >=A, <=B
+    if tokens.len() == 3 && tokens[1] == "-" {
+        let from = normalize_version(&tokens[0]);
+        let to = normalize_version(&tokens[2]);
+        return VersionReq::parse(&format!(">={}, <={}", from, to)).ok();
+    }
+
+    let comparators: Vec<String> =
+        tokens.iter().filter_map(|token| normalize_token(token)).collect();
+    if comparators.is_empty() {
+        return Some(VersionReq::STAR);
+    }
+
+    VersionReq::parse(&comparators.join(", ")).ok()
+}
+
+/// 按空白切分，并将单独的运算符与后面的版本号合并（如 ">= 1.2" => ">=1.2"）
+fn merge_operator_tokens(range: &str) -> Vec<String> {
+    let mut tokens: Vec<String> = Vec::new();
+    let mut pending_op: Option<&str> = None;
+
+    for token in range.split_whitespace() {
+        let is_operator = token.chars().all(|c| matches!(c, '<' | '>' | '=' | '^' | '~'));
+        match (pending_op.take(), is_operator) {
+            (None, true) => pending_op = Some(token),
+            (Some(op), _) => tokens.push(format!("{}{}", op, token)),
+            (None, false) => tokens.push(token.to_string()),
+        }
+    }
+
+    tokens
+}
+
+/// 将单个比较符转换为 semver 语法（无运算符的版本在 npm 中表示精确匹配）
+fn normalize_token(token: &str) -> Option<String> {
+    let op_len =
+        token.find(|c: char| !matches!(c, '<' | '>' | '=' | '^' | '~')).unwrap_or(token.len());
+    let (op, version) = token.split_at(op_len);
+    let version = normalize_version(version);
+
+    if version == "*" {
+        return match op {
+            "" | "=" | ">=" | "^" | "~" => None,
+            _ => Some(format!("{}0.0.0", op)),
+        };
+    }
+
+    let op = if op.is_empty() { "=" } else { op };
+    Some(format!("{}{}", op, version))
+}
+
+/// 规范化版本号：去除 v 前缀，去除通配部分（"1.x" => "1"，"x" => "*"）
+fn normalize_version(version: &str) -> String {
+    let version = version.trim_start_matches('v');
+    let parts: Vec<&str> =
+        version.split('.').take_while(|part| !matches!(*part, "x" | "X" | "*" | "")).collect();
+
+    if parts.is_empty() {
+        "*".to_string()
+    } else {
+        parts.join(".")
+    }
+}
+
+/// 计算单个范围的下界
+fn lower_bound(req: &VersionReq) -> Version {
+    req.comparators.iter().filter_map(comparator_lower_bound).max().unwrap_or(Version::new(0, 0, 0))
+}
+
+/// 计算单个比较符的下界（小于类比较符没有下界）
+fn comparator_lower_bound(comparator: &Comparator) -> Option<Version> {
+    let minor = comparator.minor.unwrap_or(0);
+    let patch = comparator.patch.unwrap_or(0);
+
+    let mut version = match comparator.op {
+        Op::Exact | Op::GreaterEq | Op::Tilde | Op::Caret | Op::Wildcard => {
+            Version::new(comparator.major, minor, patch)
+        }
+        Op::Greater => match (comparator.minor, comparator.patch) {
+            (Some(_), Some(_)) => Version::new(comparator.major, minor, patch + 1),
+            (Some(_), None) => Version::new(comparator.major, minor + 1, 0),
+            _ => Version::new(comparator.major + 1, 0, 0),
+        },
+        _ => return None,
+    };
+
+    if comparator.op != Op::Greater {
+        version.pre = comparator.pre.clone();
+    }
+    Some(version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version(value: &str) -> Version {
+        Version::parse(value).unwrap()
+    }
+
+    fn matches(spec: &str, value: &str) -> bool {
+        NpmVersionSpec::parse(spec).unwrap().matches(&version(value))
+    }
+
+    #[test]
+    fn parses_union_ranges() {
+        assert!(matches("^1.2.0 || ^2.0.0", "1.5.0"));
+        assert!(matches("^1.2.0 || ^2.0.0", "2.3.1"));
+        assert!(!matches("^1.2.0 || ^2.0.0", "3.0.0"));
+        assert!(matches(">=1.2 <2 || 3.0.0", "3.0.0"));
+    }
+
+    #[test]
+    fn parses_hyphen_ranges() {
+        assert!(matches("1.2.3 - 2.3.4", "1.2.3"));
+        assert!(matches("1.2.3 - 2.3.4", "2.3.4"));
+        assert!(!matches("1.2.3 - 2.3.4", "2.3.5"));
+        assert!(!matches("1.2.3 - 2.3.4", "1.2.2"));
+    }
+
+    #[test]
+    fn parses_x_ranges_and_spaced_operators() {
+        assert!(matches("1.x", "1.9.0"));
+        assert!(!matches("1.x", "2.0.0"));
+        assert!(matches("1.2.X", "1.2.7"));
+        assert!(!matches("1.2.X", "1.3.0"));
+        assert!(matches("*", "0.0.1"));
+        assert!(matches("", "5.0.0"));
+        assert!(matches(">= 1.2", "1.4.0"));
+        assert!(matches("1.2.3", "1.2.3"));
+        assert!(!matches("1.2.3", "1.2.4"));
+    }
+
+    #[test]
+    fn parses_npm_aliases() {
+        let spec = NpmVersionSpec::parse("npm:@scope/real@^2.1.0").unwrap();
+        assert_eq!(spec.alias.as_deref(), Some("@scope/real"));
+        assert!(spec.matches(&version("2.4.0")));
+
+        let spec = NpmVersionSpec::parse("npm:lodash").unwrap();
+        assert_eq!(spec.alias.as_deref(), Some("lodash"));
+        assert!(spec.matches(&version("4.17.21")));
+
+        assert!(NpmVersionSpec::parse("latest").is_none());
+        assert!(NpmVersionSpec::parse("git+https://github.com/a/b.git").is_none());
+    }
+
+    #[test]
+    fn computes_min_version() {
+        let min = |spec: &str| NpmVersionSpec::parse(spec).unwrap().min_version();
+
+        assert_eq!(min("^1.2.3"), Some(version("1.2.3")));
+        assert_eq!(min("~1.2"), Some(version("1.2.0")));
+        assert_eq!(min(">1.2.3"), Some(version("1.2.4")));
+        assert_eq!(min(">1.2"), Some(version("1.3.0")));
+        assert_eq!(min("<2.0.0"), Some(version("0.0.0")));
+        assert_eq!(min("^2.0.0 || ^1.4.0"), Some(version("1.4.0")));
+        assert_eq!(min("1.0.0 - 2.0.0"), Some(version("1.0.0")));
+        assert_eq!(min("^1.0.0-beta.1"), Some(version("1.0.0-beta.1")));
+    }
+}
@@ -0,0 +1,150 @@
+// ============================================================================
+// MonoX - Publish 集成测试
+// ============================================================================
+//
+// 文件: tests/publish.rs
+// 职责: 通过 --registry 指向本地模拟 registry，端到端验证 publish 命令
+// 边界:
+//   - ✅ 启动本地模拟 registry（类似 verdaccio 的 GET/PUT 接口）
+//   - ✅ 生成临时工作区并运行 monox 可执行文件
+//   - ❌ 不应访问真实的 npm registry
+//
+// ============================================================================
+
+#[path = "support/mock_registry.rs"]
+mod mock_registry;
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+use mock_registry::{packument_body, MockRegistry, MockRequest};
+
+/// 类似 verdaccio 的 registry：已发布的包返回元数据，其余返回 404，PUT 视为发布成功
+fn start_registry(published: &[(&str, &str)], fail_reads: bool) -> MockRegistry {
+    let published: HashMap<String, String> =
+        published.iter().map(|(name, version)| (name.to_string(), version.to_string())).collect();
+
+    MockRegistry::start(move |request| {
+        let name = request.path.trim_start_matches('/').replace("%2f", "/");
+        let (status, body) = match request.method.as_str() {
+            "GET" if fail_reads => (500, "{}".to_string()),
+            "GET" => match published.get(&name) {
+                Some(version) => (200, packument_body(&name, version)),
+                None => (404, r#"{"error":"not found"}"#.to_string()),
+            },
+            "PUT" => (201, r#"{"ok":true}"#.to_string()),
+            _ => (405, "{}".to_string()),
+        };
+        (status, Vec::new(), body)
+    })
+}
+
+/// 已收到的发布请求
+fn publish_requests(registry: &MockRegistry) -> Vec<MockRequest> {
+    registry.requests().into_iter().filter(|request| request.method == "PUT").collect()
+}
+
+/// 打包和发布依赖 npm，没有 npm 的环境跳过相应测试
+fn npm_available() -> bool {
+    Command::new("npm").arg("--version").output().is_ok_and(|output| output.status.success())
+}
+
+/// 写入 JSON 文件
+fn write_json(path: &Path, value: serde_json::Value) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, serde_json::to_string_pretty(&value).unwrap()).unwrap();
+}
+
+/// 生成工作区：mono-utils 已发布，mono-core 和依赖它的 mono-app 待发布
+fn workspace(root: &Path, registry: &str) {
+    write_json(
+        &root.join("package.json"),
+        serde_json::json!({ "name": "root", "private": true, "workspaces": ["packages/*"] }),
+    );
+    write_json(
+        &root.join("packages/utils/package.json"),
+        serde_json::json!({ "name": "mono-utils", "version": "1.0.0" }),
+    );
+    write_json(
+        &root.join("packages/core/package.json"),
+        serde_json::json!({ "name": "mono-core", "version": "1.1.0" }),
+    );
+    write_json(
+        &root.join("packages/app/package.json"),
+        serde_json::json!({
+            "name": "mono-app",
+            "version": "2.0.0",
+            "dependencies": { "mono-core": "workspace:^" }
+        }),
+    );
+
+    let host = registry.trim_start_matches("http:");
+    fs::write(root.join(".npmrc"), format!("{}:_authToken=test-token\n", host)).unwrap();
+}
+
+/// 在工作区中运行 monox publish -f json
+fn publish(root: &Path, registry: &str, extra: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_monox"))
+        .args(["publish", "--registry", registry, "-f", "json"])
+        .args(extra)
+        .current_dir(root)
+        .env("HOME", root)
+        .env("npm_config_cache", root.join(".npm-cache"))
+        .env_remove("npm_config_registry")
+        .env_remove("NPM_CONFIG_REGISTRY")
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn publishes_pending_packages_through_registry_flag() {
+    if !npm_available() {
+        eprintln!("skipped: npm is not on PATH");
+        return;
+    }
+    let registry = start_registry(&[("mono-utils", "1.0.0")], false);
+    let root = tempfile::tempdir().unwrap();
+    workspace(root.path(), &registry.url);
+
+    let output = publish(root.path(), &registry.url, &["--yes"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let publishes = publish_requests(&registry);
+    let names: Vec<&str> = publishes.iter().map(|r| r.path.trim_start_matches('/')).collect();
+    assert_eq!(names, ["mono-core", "mono-app"]);
+    assert!(publishes.iter().all(|r| r.headers["authorization"] == "Bearer test-token"));
+
+    // workspace: 协议在上传的清单中被改写为具体版本，本地 package.json 保持不变
+    let app: serde_json::Value = serde_json::from_str(&publishes[1].body).unwrap();
+    assert_eq!(app["versions"]["2.0.0"]["dependencies"]["mono-core"], "^1.1.0");
+    let local = fs::read_to_string(root.path().join("packages/app/package.json")).unwrap();
+    assert!(local.contains("workspace:^"));
+}
+
+#[test]
+fn registry_errors_abort_before_publishing() {
+    let registry = start_registry(&[], true);
+    let root = tempfile::tempdir().unwrap();
+    workspace(root.path(), &registry.url);
+
+    let output = publish(root.path(), &registry.url, &["--yes"]);
+    assert!(!output.status.success());
+    assert!(publish_requests(&registry).is_empty());
+}
+
+#[test]
+fn json_output_requires_yes_before_publishing() {
+    let registry = start_registry(&[], false);
+    let root = tempfile::tempdir().unwrap();
+    workspace(root.path(), &registry.url);
+
+    let output = publish(root.path(), &registry.url, &[]);
+    assert!(!output.status.success());
+    assert!(publish_requests(&registry).is_empty());
+
+    let output = publish(root.path(), &registry.url, &["--dry-run"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(publish_requests(&registry).is_empty());
+}
@@ -0,0 +1,128 @@
+// ============================================================================
+// MonoX - 测试用模拟 registry
+// ============================================================================
+//
+// 文件: tests/support/mock_registry.rs
+// 职责: 在本地端口上模拟 npm registry，供 registry 单元测试和 publish 集成测试共用
+// 边界:
+//   - ✅ 每个连接处理一个请求（含请求体），记录收到的请求
+//   - ✅ 由调用方提供的处理函数决定响应
+//   - ❌ 不应包含具体测试场景
+//
+// ============================================================================
+
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+
+/// 模拟 registry 收到的请求
+#[derive(Debug, Clone)]
+pub struct MockRequest {
+    /// 请求方法
+    pub method: String,
+    /// 请求路径
+    pub path: String,
+    /// 请求头（名称为小写）
+    pub headers: HashMap<String, String>,
+    /// 请求体
+    pub body: String,
+}
+
+/// 模拟 registry 的响应（状态码、响应头、响应体）
+pub type MockResponse = (u16, Vec<(&'static str, String)>, String);
+
+/// 本地模拟 registry：每个连接处理一个请求，并记录收到的请求
+pub struct MockRegistry {
+    /// 地址（以 / 结尾）
+    pub url: String,
+    /// 已收到的请求
+    requests: Arc<Mutex<Vec<MockRequest>>>,
+}
+
+impl MockRegistry {
+    /// 启动模拟 registry，由 handler 生成每个请求的响应
+    pub fn start<F>(handler: F) -> Self
+    where
+        F: Fn(&MockRequest) -> MockResponse + Send + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&requests);
+
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else {
+                    continue;
+                };
+                let Some(request) = read_request(&mut stream) else {
+                    continue;
+                };
+
+                let (status, headers, body) = handler(&request);
+                recorded.lock().unwrap().push(request);
+
+                let mut response = format!(
+                    "HTTP/1.1 {} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n",
+                    status,
+                    body.len()
+                );
+                for (name, value) in headers {
+                    response.push_str(&format!("{}: {}\r\n", name, value));
+                }
+                response.push_str("\r\n");
+                response.push_str(&body);
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+
+        Self { url, requests }
+    }
+
+    /// 已收到的请求
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+/// 读取一个 HTTP 请求（包括按 Content-Length 读取的请求体）
+fn read_request(stream: &mut TcpStream) -> Option<MockRequest> {
+    let mut reader = BufReader::new(stream.try_clone().ok()?);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).ok()?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next()?.to_string();
+    let path = parts.next()?.to_string();
+
+    let mut headers = HashMap::new();
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).ok()?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.insert(name.trim().to_lowercase(), value.trim().to_string());
+        }
+    }
+
+    let length = headers.get("content-length").and_then(|v| v.parse().ok()).unwrap_or(0);
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).ok()?;
+
+    Some(MockRequest { method, path, headers, body: String::from_utf8_lossy(&body).into_owned() })
+}
+
+/// 最小的包元数据
+pub fn packument_body(name: &str, version: &str) -> String {
+    serde_json::json!({
+        "name": name,
+        "dist-tags": { "latest": version },
+        "versions": { version: { "name": name, "version": version } }
+    })
+    .to_string()
+}